@@ -0,0 +1,33 @@
+//! Generation of bindings to the structures defined in `input.h` and `uinput.h` using bindgen.
+//!
+//! bindgen requires libclang to be installed.
+
+use std::{error::Error, path::Path};
+
+/// Generates bindings to the headers.
+///
+/// `input.h` must be passed before `uinput.h`, so the include of `linux/input.h` by `uinput.h` does not include
+/// the header installed on the system.
+pub fn generate(headers: &[&Path]) -> Result<String, Box<dyn Error>> {
+    let mut builder = bindgen::Builder::default();
+
+    for header in headers {
+        builder = builder.header(header.to_str().ok_or("Header path is not valid UTF-8")?);
+    }
+
+    let bindings = builder
+        .use_core()
+        .ctypes_prefix("::core::ffi")
+        .allowlist_type("input_(id|absinfo|keymap_entry|mask)")
+        .allowlist_type("ff_.*")
+        .allowlist_type("uinput_(setup|abs_setup|ff_upload|ff_erase)")
+        .allowlist_var("UINPUT_(VERSION|MAX_NAME_SIZE)")
+        .derive_default(true)
+        .generate_comments(false)
+        // Layout assertions are part of the crate's tests instead.
+        .layout_tests(false)
+        .generate()
+        .map_err(|_| "Failed to generate bindings")?;
+
+    Ok(bindings.to_string())
+}
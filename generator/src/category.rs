@@ -1,4 +1,7 @@
-use std::{collections::BTreeMap, error::Error};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    error::Error,
+};
 
 use crate::parse::{Define, Expression};
 
@@ -8,6 +11,52 @@ pub struct Constant {
     pub alias_name: String,
     pub value: u32,
     pub comment: Option<String>,
+
+    /// Whether the constant is defined in terms of another constant, such as `#define BTN_A BTN_SOUTH`.
+    pub is_alias: bool,
+
+    /// Whether the header marks the constant as deprecated.
+    pub deprecated: bool,
+}
+
+/// A constant which describes the range of values in the category rather than an actual code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeMarker {
+    /// The largest value of a code, such as `KEY_MAX`.
+    Max,
+
+    /// The number of values a code may have, such as `KEY_CNT`.
+    Count,
+
+    /// `KEY_MIN_INTERESTING`
+    MinInteresting,
+
+    /// The first or last code of a group of codes, such as `FF_EFFECT_MIN`.
+    Bound,
+
+    /// `FF_MAX_EFFECTS`
+    MaxEffects,
+}
+
+impl Constant {
+    pub fn range_marker(&self) -> Option<RangeMarker> {
+        match self.name.as_str() {
+            // Device properties are prefixed with INPUT_PROP_
+            "MAX" | "PROP_MAX" => Some(RangeMarker::Max),
+            "CNT" | "PROP_CNT" => Some(RangeMarker::Count),
+            "MIN_INTERESTING" => Some(RangeMarker::MinInteresting),
+            // Force feedback effect types and waveforms
+            "EFFECT_MIN" | "EFFECT_MAX" | "WAVEFORM_MIN" | "WAVEFORM_MAX" => {
+                Some(RangeMarker::Bound)
+            }
+            "MAX_EFFECTS" => Some(RangeMarker::MaxEffects),
+            _ => None,
+        }
+    }
+
+    pub fn is_range_marker(&self) -> bool {
+        self.range_marker().is_some()
+    }
 }
 
 #[derive(Debug)]
@@ -16,7 +65,54 @@ pub struct Category<'a> {
     pub constants: Vec<Constant>,
 }
 
-pub fn create_categories(defines: Vec<Define>) -> Result<BTreeMap<&str, Category>, Box<dyn Error>> {
+impl Category<'_> {
+    /// The constants of the category which are actual codes.
+    pub fn codes(&self) -> impl Iterator<Item = &Constant> {
+        self.constants
+            .iter()
+            .filter(|constant| !constant.is_range_marker())
+    }
+
+    /// The constants of the category which describe the range of codes.
+    pub fn range_markers(&self) -> impl Iterator<Item = &Constant> {
+        self.constants
+            .iter()
+            .filter(|constant| constant.is_range_marker())
+    }
+
+    /// The prefix shared by the kernel names of every constant in the category, including the trailing
+    /// underscore.
+    ///
+    /// This is usually the category name followed by an underscore (`KEY_`), but may be longer if every
+    /// constant shares more of the name (`INPUT_PROP_`).
+    pub fn prefix(&self) -> String {
+        let mut prefix = format!("{}_", self.category_name);
+
+        'extend: loop {
+            let first = &self.constants[0].alias_name;
+
+            let next = match first[prefix.len()..].find('_') {
+                Some(index) => &first[..prefix.len() + index + 1],
+                None => break 'extend,
+            };
+
+            for constant in &self.constants {
+                if !constant.alias_name.starts_with(next) {
+                    break 'extend;
+                }
+            }
+
+            prefix = next.to_owned();
+        }
+
+        prefix
+    }
+}
+
+pub fn create_categories<'a>(
+    defines: Vec<Define<'a>>,
+    aliases: &BTreeSet<&str>,
+) -> Result<BTreeMap<&'a str, Category<'a>>, Box<dyn Error>> {
     let mut categories = BTreeMap::new();
 
     for define in defines {
@@ -46,11 +142,17 @@ pub fn create_categories(defines: Vec<Define>) -> Result<BTreeMap<&str, Category
 
         match define.expression {
             Expression::Constant(value) => {
+                let deprecated = define
+                    .comment
+                    .is_some_and(|comment| comment.trim() == "deprecated");
+
                 category.constants.push(Constant {
                     name,
                     alias_name: define.name.to_owned(),
                     value,
                     comment: define.comment.map(ToOwned::to_owned),
+                    is_alias: aliases.contains(define.name),
+                    deprecated,
                 });
             }
 
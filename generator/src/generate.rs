@@ -1,9 +1,15 @@
-use std::error::Error;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    error::Error,
+};
 
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::quote;
 
-use crate::{category::Category, renames};
+use crate::{
+    category::{Category, RangeMarker},
+    phf, renames,
+};
 
 pub fn category_to_tokens(
     category_enum_name: &str,
@@ -24,31 +30,252 @@ pub fn category_to_tokens(
 
     let enum_name = Ident::new(enum_name, Span::call_site());
 
-    let constant_tokens = category
-        .constants
+    // The type wrapped by the generated type, and a literal of a value of that type.
+    let is_u16 = renames::U16_CATEGORIES.contains(&category_enum_name);
+    let inner = Ident::new(if is_u16 { "u16" } else { "u32" }, Span::call_site());
+    let literal = |value: u32| {
+        if is_u16 {
+            Literal::u16_suffixed(u16::try_from(value).expect("Value does not fit in a u16"))
+        } else {
+            Literal::u32_suffixed(value)
+        }
+    };
+
+    // Choose the canonical constant of each code, which is used as the name of the code.
+    //
+    // Constants defined in terms of another constant are never canonical. Otherwise the last constant defined
+    // with the value is used, since the header defines the start of a block of buttons (`BTN_MOUSE`) before the
+    // first button of the block (`BTN_LEFT`).
+    let mut canonical = BTreeMap::new();
+
+    for constant in category.codes() {
+        if constant.is_alias {
+            canonical.entry(constant.value).or_insert(constant);
+        } else {
+            canonical.insert(constant.value, constant);
+        }
+    }
+
+    let names = canonical
         .iter()
+        .map(|(&value, constant)| (value, constant.alias_name.as_str()))
+        .collect::<BTreeMap<_, _>>();
+
+    // Every other name of a code, in the order the names are defined in the header.
+    let mut aliases = BTreeMap::<u32, Vec<&str>>::new();
+
+    for constant in category.codes() {
+        if canonical[&constant.value].alias_name != constant.alias_name {
+            aliases
+                .entry(constant.value)
+                .or_default()
+                .push(&constant.alias_name);
+        }
+    }
+
+    let aliases_body = if aliases.is_empty() {
+        quote! { &[] }
+    } else {
+        let alias_arms = aliases.iter().map(|(value, aliases)| {
+            let value = literal(*value);
+            quote! { #value => &[#(#aliases),*], }
+        });
+
+        quote! {
+            match self.0 {
+                #(#alias_arms)*
+                _ => &[],
+            }
+        }
+    };
+
+    let constant_tokens = category
+        .codes()
         .map(|constant| {
             let alias = &constant.alias_name;
             let constant_name = Ident::new(&constant.name, Span::call_site());
+            let value = literal(constant.value);
+
             let comment = constant
                 .comment
                 .as_ref()
+                .filter(|_| !constant.deprecated)
                 .map(|comment| quote! { #[doc = #comment] });
-            let value = constant.value;
+
+            let canonical_name = &canonical[&constant.value].name;
+            let canonical_ident = Ident::new(canonical_name, Span::call_site());
+
+            let alias_of = (*canonical_name != constant.name).then(|| {
+                let doc = format!("Alias of [`{0}`](Self::{0}).", canonical_name);
+
+                if comment.is_some() {
+                    quote! {
+                        #[doc = ""]
+                        #[doc = #doc]
+                    }
+                } else {
+                    quote! { #[doc = #doc] }
+                }
+            });
+
+            let deprecated = constant.deprecated.then(|| {
+                let note = format!("use `{}::{}` instead", enum_name, canonical_ident);
+                quote! { #[deprecated(note = #note)] }
+            });
 
             quote! {
                 #comment
+                #alias_of
                 #[doc(alias = #alias)]
+                #deprecated
                 pub const #constant_name: #enum_name = #enum_name(#value);
             }
         })
         .collect::<Vec<_>>();
 
+    // Range markers are not codes, so they are kept apart from the codes and are never used as names.
+    let range_marker_tokens = category
+        .range_markers()
+        .map(|constant| {
+            let alias = &constant.alias_name;
+            let value = constant.value;
+            let value_literal = literal(value);
+
+            match constant.range_marker().unwrap() {
+                RangeMarker::Max => quote! {
+                    /// The largest value a code of this type may have.
+                    ///
+                    /// This is a bound rather than a code, and may have the same value as a code.
+                    #[doc(alias = #alias)]
+                    pub const MAX: Self = Self(#value_literal);
+                },
+
+                RangeMarker::Count => {
+                    let count = value as usize;
+
+                    quote! {
+                        /// The number of values a code of this type may have.
+                        ///
+                        /// This is the size of the bitmaps the kernel uses to describe the supported codes.
+                        #[doc(alias = #alias)]
+                        pub const COUNT: usize = #count;
+                    }
+                }
+
+                RangeMarker::MinInteresting => quote! {
+                    /// The smallest code the kernel includes in module aliases.
+                    ///
+                    /// This is a bound rather than a code, and has the same value as a code.
+                    #[doc(alias = #alias)]
+                    pub const MIN_INTERESTING: Self = Self(#value_literal);
+                },
+
+                RangeMarker::Bound => {
+                    let name = Ident::new(&constant.name, Span::call_site());
+                    let (position, group) = constant
+                        .name
+                        .split_once('_')
+                        .map(|(group, position)| {
+                            let position = if position == "MIN" { "first" } else { "last" };
+                            (position, group.to_ascii_lowercase())
+                        })
+                        .unwrap();
+                    let doc = format!("The {} {} code.", position, group);
+
+                    quote! {
+                        #[doc = #doc]
+                        ///
+                        /// This is a bound rather than a code, and has the same value as a code.
+                        #[doc(alias = #alias)]
+                        pub const #name: Self = Self(#value_literal);
+                    }
+                }
+
+                RangeMarker::MaxEffects => {
+                    let count = value as usize;
+
+                    quote! {
+                        /// The largest number of effects a device may have uploaded at once.
+                        #[doc(alias = #alias)]
+                        pub const MAX_EFFECTS: usize = #count;
+                    }
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Every code in the category, with codes defined by multiple constants listed once.
+    let all_values = category
+        .codes()
+        .map(|constant| constant.value)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|value| {
+            let value = literal(value);
+            quote! { Self(#value), }
+        })
+        .collect::<Vec<_>>();
+
+    // Names are stored in a dense array indexed by the value of the code, offset by the smallest value.
+    let min_value = *names.keys().next().unwrap();
+    let max_value = *names.keys().next_back().unwrap();
+
+    let names_by_value = (min_value..=max_value)
+        .map(|value| match names.get(&value) {
+            Some(name) => quote! { Some(#name), },
+            None => quote! { None, },
+        })
+        .collect::<Vec<_>>();
+
+    // Both the kernel name and the name of the associated constant may be used to look up a code. Names of
+    // constants which start with a digit are escaped with an underscore, and may also be looked up without it.
+    let name_entries = category
+        .codes()
+        .flat_map(|constant| {
+            let unescaped = constant
+                .name
+                .strip_prefix('_')
+                .filter(|name| name.starts_with(|c: char| c.is_ascii_digit()));
+
+            [
+                Some(constant.alias_name.as_str()),
+                Some(constant.name.as_str()),
+                unescaped,
+            ]
+            .into_iter()
+            .flatten()
+            .map(move |name| (name, constant.value))
+        })
+        .collect::<Vec<_>>();
+
+    let name_map = phf::build(
+        &name_entries
+            .iter()
+            .map(|&(name, _)| name)
+            .collect::<Vec<_>>(),
+    );
+
+    let name_map_seeds = &name_map.seeds;
+    let name_map_entries = name_map
+        .slots
+        .iter()
+        .map(|&index| {
+            let (name, value) = name_entries[index];
+            let value = literal(value);
+            quote! { (#name, Self(#value)), }
+        })
+        .collect::<Vec<_>>();
+
+    let enum_name_str = enum_name.to_string();
+
+    let min_value = literal(min_value);
+
+    let unknown_format = format!("{}{{:#x}}", category.prefix());
+
     let tokens = quote! {
         #doc
         #[repr(transparent)]
         #[derive(
-            Debug,
             Clone,
             Copy,
             PartialEq,
@@ -58,44 +285,146 @@ pub fn category_to_tokens(
             Ord,
             Hash
         )]
-        pub struct #enum_name (u32);
+        pub struct #enum_name (#inner);
 
         impl #enum_name {
-            pub const fn new(value: u32) -> Self {
+            pub const fn new(value: #inner) -> Self {
                 Self(value)
             }
 
-            pub const fn into_inner(self) -> u32 {
+            pub const fn into_inner(self) -> #inner {
                 self.0
             }
         }
 
-        impl From<u32> for #enum_name {
-            fn from(value: u32) -> Self {
+        impl #enum_name {
+            /// Returns the name of the code as defined in the kernel headers.
+            ///
+            /// If the code has multiple names, the canonical name is returned. The other names of the code are
+            /// returned by [`aliases`](Self::aliases).
+            ///
+            /// Returns [`None`] if the value is not a known code.
+            pub const fn name(self) -> Option<&'static str> {
+                let index = self.0.wrapping_sub(#min_value) as usize;
+
+                if index < Self::NAMES_BY_VALUE.len() {
+                    Self::NAMES_BY_VALUE[index]
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl #enum_name {
+            /// Returns the other names of the code as defined in the kernel headers.
+            ///
+            /// The canonical name returned by [`name`](Self::name) is not included. Returns an empty slice if the
+            /// code has no other names or is not a known code.
+            pub const fn aliases(self) -> &'static [&'static str] {
+                #aliases_body
+            }
+        }
+
+        impl #enum_name {
+            const NAMES_BY_VALUE: &'static [Option<&'static str>] = &[
+                #(#names_by_value)*
+            ];
+
+            const NAME_MAP: crate::phf::NameMap<Self> = crate::phf::NameMap {
+                seeds: &[#(#name_map_seeds),*],
+                entries: &[
+                    #(#name_map_entries)*
+                ],
+            };
+
+            /// Looks up a code by name.
+            ///
+            /// Both the name defined in the kernel headers (`KEY_LEFTMETA`) and the name of the associated
+            /// constant (`LEFTMETA`) are accepted. The name of a constant which starts with a digit may be given
+            /// with or without its leading underscore (`_0` or `0`). The name is case sensitive, see
+            /// [`from_name_ignore_case`](Self::from_name_ignore_case) for a case insensitive lookup.
+            pub fn from_name(name: &str) -> Result<Self, crate::ParseCodeError> {
+                Self::NAME_MAP
+                    .get(name, false)
+                    .ok_or(crate::ParseCodeError::new(#enum_name_str))
+            }
+
+            /// Looks up a code by name, ignoring ASCII case.
+            ///
+            /// This accepts the same names as [`from_name`](Self::from_name).
+            pub fn from_name_ignore_case(name: &str) -> Result<Self, crate::ParseCodeError> {
+                Self::NAME_MAP
+                    .get(name, true)
+                    .ok_or(crate::ParseCodeError::new(#enum_name_str))
+            }
+        }
+
+        impl #enum_name {
+            /// Every known code, ordered by value.
+            ///
+            /// Codes with multiple names are only listed once, and values which only describe the range of codes
+            /// (such as `MAX`) are not included.
+            pub const ALL: &'static [Self] = &[
+                #(#all_values)*
+            ];
+
+            /// Returns an iterator over every known code, ordered by value.
+            ///
+            /// This iterates over [`ALL`](Self::ALL).
+            pub fn iter() -> ::core::iter::Copied<::core::slice::Iter<'static, Self>> {
+                Self::ALL.iter().copied()
+            }
+        }
+
+        impl ::core::str::FromStr for #enum_name {
+            type Err = crate::ParseCodeError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::from_name(s)
+            }
+        }
+
+        impl ::core::fmt::Debug for #enum_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(self, f)
+            }
+        }
+
+        impl ::core::fmt::Display for #enum_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self.name() {
+                    Some(name) => f.pad(name),
+                    None => write!(f, #unknown_format, self.0),
+                }
+            }
+        }
+
+        impl From<#inner> for #enum_name {
+            fn from(value: #inner) -> Self {
                 Self(value)
             }
         }
 
-        impl From<#enum_name> for u32 {
-            fn from(value: #enum_name) -> u32 {
+        impl From<#enum_name> for #inner {
+            fn from(value: #enum_name) -> #inner {
                 value.0
             }
         }
 
-        impl AsRef<u32> for #enum_name {
-            fn as_ref(&self) -> &u32 {
+        impl AsRef<#inner> for #enum_name {
+            fn as_ref(&self) -> &#inner {
                 &self.0
             }
         }
 
-        impl ::core::borrow::Borrow<u32> for #enum_name {
-            fn borrow(&self) -> &u32 {
+        impl ::core::borrow::Borrow<#inner> for #enum_name {
+            fn borrow(&self) -> &#inner {
                 &self.0
             }
         }
 
-        impl PartialEq<u32> for #enum_name {
-            fn eq(&self, other: &u32) -> bool {
+        impl PartialEq<#inner> for #enum_name {
+            fn eq(&self, other: &#inner) -> bool {
                 &self.0 == other
             }
         }
@@ -103,7 +432,77 @@ pub fn category_to_tokens(
         impl #enum_name {
             #(#constant_tokens)*
         }
+
+        impl #enum_name {
+            #(#range_marker_tokens)*
+        }
     };
 
     Ok(tokens)
 }
+
+#[cfg(test)]
+mod test {
+    use quote::quote;
+    use syn::{ImplItem, Item};
+
+    use super::category_to_tokens;
+    use crate::category::{Category, Constant};
+
+    fn constant(name: &str, value: u32, is_alias: bool, deprecated: bool) -> Constant {
+        Constant {
+            name: name.to_owned(),
+            alias_name: format!("SW_{}", name),
+            value,
+            comment: None,
+            is_alias,
+            deprecated,
+        }
+    }
+
+    /// Returns the attributes of the generated constant with the name.
+    fn attributes(file: &syn::File, name: &str) -> Vec<String> {
+        file.items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Impl(item) => Some(&item.items),
+                _ => None,
+            })
+            .flatten()
+            .find_map(|item| match item {
+                ImplItem::Const(item) if item.ident == name => Some(
+                    item.attrs
+                        .iter()
+                        .map(|attr| quote!(#attr).to_string())
+                        .collect(),
+                ),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn deprecated_alias() {
+        // `#define SW_RADIO SW_RFKILL_ALL /* deprecated */`
+        let category = Category {
+            category_name: "SW",
+            constants: vec![
+                constant("RFKILL_ALL", 3, false, false),
+                constant("RADIO", 3, true, true),
+            ],
+        };
+
+        let file = syn::parse2(category_to_tokens("Sw", &category).unwrap()).unwrap();
+
+        let canonical = attributes(&file, "RFKILL_ALL");
+        assert!(!canonical.iter().any(|attr| attr.contains("deprecated")));
+        assert!(!canonical.iter().any(|attr| attr.contains("Alias of")));
+
+        let alias = attributes(&file, "RADIO");
+        assert!(alias
+            .iter()
+            .any(|attr| attr.contains("Alias of [`RFKILL_ALL`](Self::RFKILL_ALL).")));
+        assert!(alias.iter().any(|attr| attr.starts_with("# [deprecated")
+            && attr.contains("use `SwitchEvent::RFKILL_ALL` instead")));
+    }
+}
@@ -0,0 +1,480 @@
+//! A parser for the ioctl requests defined in `input.h` and `uinput.h` and generation of the request codes.
+//!
+//! Only the `#define` lines of the requests are parsed, the rest of the headers are described by the bindings
+//! generated using bindgen.
+
+use std::{collections::BTreeMap, error::Error};
+
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_until, take_while1},
+    character::{
+        self,
+        complete::{anychar, char, space0, space1},
+    },
+    combinator::{map, opt, value},
+    multi::separated_list1,
+    sequence::{delimited, pair, preceded, terminated, tuple},
+    IResult,
+};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+use crate::parse;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    None,
+    Read,
+    Write,
+    ReadWrite,
+}
+
+/// The kind of a request, the second argument of the `_IOC` macro.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind<'a> {
+    /// A character literal, such as `'E'`.
+    Char(char),
+
+    /// A define of a character literal, such as `UINPUT_IOCTL_BASE`.
+    Define(&'a str),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Size<'a> {
+    /// The request has no argument.
+    None,
+
+    /// The size of a C type, such as `int` or `struct input_id`.
+    Type(&'a str),
+
+    /// The size is passed as a parameter of the request.
+    Parameter(&'a str),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request<'a> {
+    pub name: &'a str,
+    pub parameters: Vec<&'a str>,
+    pub direction: Direction,
+    pub kind: Kind<'a>,
+    pub number: u32,
+    /// A parameter which is added to the number, such as the event type of `EVIOCGBIT`.
+    pub number_parameter: Option<&'a str>,
+    pub size: Size<'a>,
+    pub comment: Option<&'a str>,
+}
+
+/// The arguments of an `_IOC` style macro: the direction, the kind, the number and the size.
+type Encoding<'a> = (Direction, Kind<'a>, (u32, Option<&'a str>), Size<'a>);
+
+fn identifier(input: &str) -> IResult<&str, &str> {
+    take_while1(|c: char| c.is_ascii_alphanumeric() || c == '_')(input)
+}
+
+fn separator(input: &str) -> IResult<&str, ()> {
+    value((), tuple((space0, char(','), space0)))(input)
+}
+
+fn parse_number(input: &str) -> IResult<&str, u32> {
+    alt((
+        map(preceded(tag("0x"), identifier), |digits| {
+            u32::from_str_radix(digits, 16).expect("Invalid hexadecimal number")
+        }),
+        character::complete::u32,
+    ))(input)
+}
+
+/// Parse the number of the request, such as `0x06`, `44` or `0x20 + (ev)`.
+fn parse_request_number(input: &str) -> IResult<&str, (u32, Option<&str>)> {
+    pair(
+        parse_number,
+        opt(preceded(
+            tuple((space0, char('+'), space0)),
+            delimited(char('('), identifier, char(')')),
+        )),
+    )(input)
+}
+
+fn parse_kind(input: &str) -> IResult<&str, Kind<'_>> {
+    alt((
+        map(delimited(char('\''), anychar, char('\'')), Kind::Char),
+        map(identifier, Kind::Define),
+    ))(input)
+}
+
+/// Parse `_IOC(_IOC_READ, 'E', 0x06, len)`.
+fn parse_ioc(input: &str) -> IResult<&str, Encoding<'_>> {
+    let direction = alt((
+        value(Direction::ReadWrite, tag("_IOC_READ|_IOC_WRITE")),
+        value(Direction::Read, tag("_IOC_READ")),
+        value(Direction::Write, tag("_IOC_WRITE")),
+        value(Direction::None, tag("_IOC_NONE")),
+    ));
+
+    delimited(
+        tag("_IOC("),
+        tuple((
+            terminated(direction, separator),
+            terminated(parse_kind, separator),
+            terminated(parse_request_number, separator),
+            map(identifier, Size::Parameter),
+        )),
+        char(')'),
+    )(input)
+}
+
+/// Parse `_IO('U', 1)`, a request without an argument.
+fn parse_io(input: &str) -> IResult<&str, Encoding<'_>> {
+    map(
+        delimited(
+            tag("_IO("),
+            pair(terminated(parse_kind, separator), parse_request_number),
+            char(')'),
+        ),
+        |(kind, number)| (Direction::None, kind, number, Size::None),
+    )(input)
+}
+
+/// Parse `_IOR('E', 0x01, int)` and the other macros which take the type of the argument.
+fn parse_io_typed(input: &str) -> IResult<&str, Encoding<'_>> {
+    let direction = alt((
+        value(Direction::ReadWrite, tag("_IOWR(")),
+        value(Direction::Read, tag("_IOR(")),
+        value(Direction::Write, tag("_IOW(")),
+    ));
+
+    map(
+        tuple((
+            direction,
+            terminated(parse_kind, separator),
+            terminated(parse_request_number, separator),
+            map(take_until(")"), Size::Type),
+            char(')'),
+        )),
+        |(direction, kind, number, size, _)| (direction, kind, number, size),
+    )(input)
+}
+
+fn parse_request(input: &str) -> IResult<&str, Request<'_>> {
+    let parameters = delimited(char('('), separated_list1(separator, identifier), char(')'));
+
+    map(
+        tuple((
+            preceded(pair(tag("#define"), space1), identifier),
+            opt(parameters),
+            preceded(space1, alt((parse_ioc, parse_io, parse_io_typed))),
+            opt(preceded(space1, parse::parse_comment)),
+        )),
+        |(name, parameters, (direction, kind, (number, number_parameter), size), comment)| {
+            Request {
+                name,
+                parameters: parameters.unwrap_or_default(),
+                direction,
+                kind,
+                number,
+                number_parameter,
+                size,
+                comment,
+            }
+        },
+    )(input)
+}
+
+/// Parse `#define UINPUT_IOCTL_BASE 'U'`.
+fn parse_kind_define(input: &str) -> IResult<&str, (&str, char)> {
+    preceded(
+        pair(tag("#define"), space1),
+        pair(
+            terminated(identifier, space1),
+            delimited(char('\''), anychar, char('\'')),
+        ),
+    )(input)
+}
+
+/// Parse every request defined in the header whose name starts with the prefix, such as `EVIOC`.
+///
+/// Kinds which are defines of a character literal are resolved using the defines of the header.
+pub fn parse_requests<'a>(
+    input: &'a str,
+    prefix: &str,
+) -> Result<Vec<Request<'a>>, Box<dyn Error>> {
+    let kinds = input
+        .lines()
+        .filter_map(|line| parse_kind_define(line).ok())
+        .map(|(_, kind)| kind)
+        .collect::<BTreeMap<_, _>>();
+
+    input
+        .lines()
+        .filter(|line| {
+            line.strip_prefix("#define ")
+                .is_some_and(|define| define.starts_with(prefix) && define.contains("_IO"))
+        })
+        .map(|line| match parse_request(line) {
+            Ok((_, mut request)) => {
+                if let Kind::Define(name) = request.kind {
+                    let kind = kinds
+                        .get(name)
+                        .ok_or_else(|| format!("Unknown request kind {}", name))?;
+                    request.kind = Kind::Char(*kind);
+                }
+
+                Ok(request)
+            }
+            Err(err) => Err(format!("Failed to parse request {:?}: {}", line, err).into()),
+        })
+        .collect()
+}
+
+/// The Rust type of a request parameter.
+fn parameter_type(parameter: &str) -> TokenStream {
+    match parameter {
+        "len" => quote! { usize },
+        "ev" => quote! { crate::EventType },
+        "abs" => quote! { crate::AbsoluteAxis },
+        _ => panic!("Unknown request parameter {}", parameter),
+    }
+}
+
+/// The Rust type of a C type used as the argument of a request.
+fn argument_type(ty: &str) -> TokenStream {
+    match ty {
+        "int" => quote! { ::core::ffi::c_int },
+        "unsigned int" => quote! { ::core::ffi::c_uint },
+        "unsigned int[2]" => quote! { [::core::ffi::c_uint; 2] },
+        "char*" => quote! { *const ::core::ffi::c_char },
+        // The structures with a typed equivalent in the crate have the same layout as the bindings.
+        "struct input_id" => quote! { crate::InputId },
+        "struct input_absinfo" => quote! { crate::InputAbsInfo },
+        "struct input_keymap_entry" => quote! { crate::InputKeymapEntry },
+        "struct input_mask" => quote! { crate::InputMask },
+        "struct uinput_setup" => quote! { crate::uinput::UinputSetup },
+        "struct uinput_abs_setup" => quote! { crate::uinput::UinputAbsSetup },
+        _ => match ty.strip_prefix("struct ") {
+            Some(name) => {
+                let name = Ident::new(name, Span::call_site());
+                quote! { crate::sys::#name }
+            }
+            None => panic!("Unknown request argument type {}", ty),
+        },
+    }
+}
+
+pub fn requests_to_tokens(requests: &[Request<'_>]) -> TokenStream {
+    let requests = requests.iter().map(|request| {
+        let alias = request.name;
+        let comment = request.comment.map(|comment| quote! { #[doc = #comment] });
+
+        let kind = match request.kind {
+            Kind::Char(kind) => kind as u8,
+            Kind::Define(name) => panic!("Unresolved request kind {}", name),
+        };
+        let number = request.number;
+        let number = match request.number_parameter {
+            Some(parameter) => {
+                let parameter = Ident::new(parameter, Span::call_site());
+                quote! { #number + #parameter.into_inner() }
+            }
+            None => quote! { #number },
+        };
+
+        let direction = match request.direction {
+            Direction::None => quote! { crate::ioctl::NONE },
+            Direction::Read => quote! { crate::ioctl::READ },
+            Direction::Write => quote! { crate::ioctl::WRITE },
+            Direction::ReadWrite => quote! { crate::ioctl::READ | crate::ioctl::WRITE },
+        };
+
+        let size = match request.size {
+            Size::None => quote! { 0 },
+            Size::Type(ty) => {
+                let ty = argument_type(ty);
+                quote! { ::core::mem::size_of::<#ty>() }
+            }
+            Size::Parameter(parameter) => {
+                let parameter = Ident::new(parameter, Span::call_site());
+                quote! { #parameter }
+            }
+        };
+
+        let value = quote! { crate::ioctl::ioc(#direction, #kind, #number, #size) };
+
+        if request.parameters.is_empty() {
+            let name = Ident::new(request.name, Span::call_site());
+
+            quote! {
+                #comment
+                pub const #name: u32 = #value;
+            }
+        } else {
+            let name = Ident::new(&request.name.to_ascii_lowercase(), Span::call_site());
+            let parameters = request.parameters.iter().map(|&parameter| {
+                let ty = parameter_type(parameter);
+                let parameter = Ident::new(parameter, Span::call_site());
+                quote! { #parameter: #ty }
+            });
+
+            let panics = matches!(request.size, Size::Parameter(_)).then(|| {
+                quote! {
+                    ///
+                    /// # Panics
+                    ///
+                    /// Panics if the length is larger than [`MAX_SIZE`](crate::ioctl::MAX_SIZE), the largest size
+                    /// the request code can hold.
+                }
+            });
+
+            quote! {
+                #comment
+                #panics
+                #[doc(alias = #alias)]
+                pub const fn #name(#(#parameters),*) -> u32 {
+                    #value
+                }
+            }
+        }
+    });
+
+    quote! {
+        #(#requests)*
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_request, parse_requests, Direction, Kind, Request, Size};
+
+    #[test]
+    fn parse_typed() {
+        assert_eq!(
+            parse_request(
+                "#define EVIOCGVERSION\t\t_IOR('E', 0x01, int)\t\t\t/* get driver version */"
+            ),
+            Ok((
+                "",
+                Request {
+                    name: "EVIOCGVERSION",
+                    parameters: vec![],
+                    direction: Direction::Read,
+                    kind: Kind::Char('E'),
+                    number: 0x01,
+                    number_parameter: None,
+                    size: Size::Type("int"),
+                    comment: Some(" get driver version "),
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_typed_array() {
+        assert_eq!(
+            parse_request("#define EVIOCSREP\t\t_IOW('E', 0x03, unsigned int[2])"),
+            Ok((
+                "",
+                Request {
+                    name: "EVIOCSREP",
+                    parameters: vec![],
+                    direction: Direction::Write,
+                    kind: Kind::Char('E'),
+                    number: 0x03,
+                    number_parameter: None,
+                    size: Size::Type("unsigned int[2]"),
+                    comment: None,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_length() {
+        assert_eq!(
+            parse_request("#define EVIOCGNAME(len)\t\t_IOC(_IOC_READ, 'E', 0x06, len)"),
+            Ok((
+                "",
+                Request {
+                    name: "EVIOCGNAME",
+                    parameters: vec!["len"],
+                    direction: Direction::Read,
+                    kind: Kind::Char('E'),
+                    number: 0x06,
+                    number_parameter: None,
+                    size: Size::Parameter("len"),
+                    comment: None,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_number_parameter() {
+        assert_eq!(
+            parse_request("#define EVIOCGBIT(ev,len)\t_IOC(_IOC_READ, 'E', 0x20 + (ev), len)"),
+            Ok((
+                "",
+                Request {
+                    name: "EVIOCGBIT",
+                    parameters: vec!["ev", "len"],
+                    direction: Direction::Read,
+                    kind: Kind::Char('E'),
+                    number: 0x20,
+                    number_parameter: Some("ev"),
+                    size: Size::Parameter("len"),
+                    comment: None,
+                }
+            ))
+        );
+
+        assert_eq!(
+            parse_request(
+                "#define EVIOCSABS(abs)\t\t_IOW('E', 0xc0 + (abs), struct input_absinfo)"
+            ),
+            Ok((
+                "",
+                Request {
+                    name: "EVIOCSABS",
+                    parameters: vec!["abs"],
+                    direction: Direction::Write,
+                    kind: Kind::Char('E'),
+                    number: 0xc0,
+                    number_parameter: Some("abs"),
+                    size: Size::Type("struct input_absinfo"),
+                    comment: None,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_io() {
+        assert_eq!(
+            parse_request("#define UI_DEV_CREATE\t\t_IO(UINPUT_IOCTL_BASE, 1)"),
+            Ok((
+                "",
+                Request {
+                    name: "UI_DEV_CREATE",
+                    parameters: vec![],
+                    direction: Direction::None,
+                    kind: Kind::Define("UINPUT_IOCTL_BASE"),
+                    number: 1,
+                    number_parameter: None,
+                    size: Size::None,
+                    comment: None,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn resolve_kind() {
+        const HEADER: &str = "#define UINPUT_IOCTL_BASE\t'U'\n\
+            #define UI_DEV_SETUP _IOW(UINPUT_IOCTL_BASE, 3, struct uinput_setup)\n\
+            #define UI_FF_UPLOAD\t\t1\n";
+
+        let requests = parse_requests(HEADER, "UI_").unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].kind, Kind::Char('U'));
+        assert_eq!(requests[0].size, Size::Type("struct uinput_setup"));
+    }
+}
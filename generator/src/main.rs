@@ -1,13 +1,17 @@
+mod bindings;
 mod category;
 mod generate;
+mod ioctl;
 mod parse;
+mod phf;
 mod renames;
 
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     error::Error,
     fs::{self, OpenOptions},
     io::{Read, Write},
+    path::Path,
 };
 
 use parse::{Define, Expression};
@@ -24,7 +28,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     {
         let mut open = OpenOptions::new()
             .read(true)
-            .open(&std::env::current_dir()?.join("input-event-codes.h"))?;
+            .open(std::env::current_dir()?.join("input-event-codes.h"))?;
 
         open.read_to_string(&mut content)?;
     }
@@ -35,6 +39,32 @@ fn main() -> Result<(), Box<dyn Error>> {
         panic!("Part of file is remaining: {}", remaining);
     }
 
+    let input_header_path = std::env::current_dir()?.join("input.h");
+    let input_header = fs::read_to_string(&input_header_path)?;
+
+    // Force feedback codes, bus types and input id fields are defined in input.h instead. The FF_STATUS_ values
+    // are the values of EV_FF_STATUS events rather than codes.
+    let is_code = |line: &&str| {
+        ["#define FF_", "#define BUS_", "#define ID_"]
+            .iter()
+            .any(|prefix| line.starts_with(prefix))
+            && !line.starts_with("#define FF_STATUS_")
+    };
+
+    for line in input_header.lines().filter(is_code) {
+        match parse::parse_define(line) {
+            Ok(("", define)) => defines.push(define),
+            _ => panic!("Failed to parse define {:?}", line),
+        }
+    }
+
+    // Defines written in terms of another define, such as `#define BTN_A BTN_SOUTH`, are aliases.
+    let aliases = defines
+        .iter()
+        .filter(|define| matches!(define.expression, Expression::Expression { add: None, .. }))
+        .map(|define| define.name)
+        .collect::<BTreeSet<_>>();
+
     let mut previous_unresolved = None;
 
     // Resolve all the deferred defines.
@@ -89,7 +119,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
     }
 
-    let categories = category::create_categories(defines)?;
+    let categories = category::create_categories(defines, &aliases)?;
 
     let mut category_tokens = BTreeMap::new();
 
@@ -114,27 +144,58 @@ fn main() -> Result<(), Box<dyn Error>> {
             .is_none());
     }
 
-    let out_path = &std::env::current_dir()?.join("src").join("generated.rs");
+    let src = std::env::current_dir()?.join("src");
+
+    write_file(
+        &src.join("generated.rs"),
+        category_tokens
+            .values()
+            .map(|tokens| format!("{}\n", tokens))
+            .collect(),
+    )?;
+
+    let requests = ioctl::parse_requests(&input_header, "EVIOC")?;
+    write_file(
+        &src.join("ioctl").join("generated.rs"),
+        format!("{}\n", ioctl::requests_to_tokens(&requests)),
+    )?;
+
+    let uinput_header_path = std::env::current_dir()?.join("uinput.h");
+    let uinput_header = fs::read_to_string(&uinput_header_path)?;
+
+    let requests = ioctl::parse_requests(&uinput_header, "UI_")?;
+    write_file(
+        &src.join("uinput").join("generated.rs"),
+        format!("{}\n", ioctl::requests_to_tokens(&requests)),
+    )?;
+
+    // bindgen requires libclang, pass --no-bindings to keep the existing bindings.
+    if !std::env::args().any(|arg| arg == "--no-bindings") {
+        let out_path = src.join("sys.rs");
+        println!("{}", out_path.display());
+        fs::write(
+            out_path,
+            bindings::generate(&[&input_header_path, &uinput_header_path])?,
+        )?;
+    }
 
+    Ok(())
+}
+
+fn write_file(out_path: &Path, contents: String) -> Result<(), Box<dyn Error>> {
     // Remove old generated file if it exists
     let _ = fs::remove_file(out_path);
 
-    {
-        println!("{}", out_path.display());
-        let mut file = OpenOptions::new()
-            .create(true)
-            .write(true)
-            .append(false)
-            .open(out_path)?;
-
-        writeln!(file, "{}", HEADER)?;
-
-        for tokens in category_tokens.values() {
-            writeln!(file, "{}", tokens.to_string())?;
-        }
+    println!("{}", out_path.display());
+    let mut file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(true)
+        .open(out_path)?;
 
-        file.flush()?;
-    }
+    writeln!(file, "{}", HEADER)?;
+    write!(file, "{}", contents)?;
+    file.flush()?;
 
     Ok(())
 }
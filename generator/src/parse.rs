@@ -61,7 +61,7 @@ where
 ///
 /// Note that this function parses the content between `/*` and the first `*/` including whitespace.
 /// For example, a comment `/* foo */` would have a content of `" foo "`.
-fn parse_comment(input: &str) -> IResult<&str, &str> {
+pub(crate) fn parse_comment(input: &str) -> IResult<&str, &str> {
     delimited(tag("/*"), take_until("*/"), tag("*/"))(input)
 }
 
@@ -70,7 +70,7 @@ fn consume_endif(input: &str) -> IResult<&str, ()> {
     value((), tag("#endif"))(input)
 }
 
-fn parse_define_expression(input: &str) -> IResult<&str, Expression> {
+fn parse_define_expression(input: &str) -> IResult<&str, Expression<'_>> {
     map(
         delimited(
             char('('),
@@ -96,22 +96,22 @@ fn parse_define_expression(input: &str) -> IResult<&str, Expression> {
     )(input)
 }
 
-fn parse_define_constant_u32(input: &str) -> IResult<&str, Expression> {
+fn parse_define_constant_u32(input: &str) -> IResult<&str, Expression<'_>> {
     map(character::complete::u32, Expression::Constant)(input)
 }
 
-fn parse_define_constant_hex_u32(input: &str) -> IResult<&str, Expression> {
+fn parse_define_constant_hex_u32(input: &str) -> IResult<&str, Expression<'_>> {
     map(preceded(tag("0x"), hex_u32), Expression::Constant)(input)
 }
 
-fn parse_define_deferred(input: &str) -> IResult<&str, Expression> {
+fn parse_define_deferred(input: &str) -> IResult<&str, Expression<'_>> {
     map(
         take_while(|c: char| c.is_alphanumeric() || c == '_'),
         |other| Expression::Expression { other, add: None },
     )(input)
 }
 
-fn parse_define_value(input: &str) -> IResult<&str, Expression> {
+fn parse_define_value(input: &str) -> IResult<&str, Expression<'_>> {
     alt((
         // Try to parse with hexadecimal first
         parse_define_constant_hex_u32,
@@ -127,7 +127,7 @@ fn parse_define_value(input: &str) -> IResult<&str, Expression> {
 /// Parse an `#define`, returning an expression representing the value of the define.
 ///
 /// This will not read the name of the define of the
-fn parse_define(input: &str) -> IResult<&str, Define> {
+pub(crate) fn parse_define(input: &str) -> IResult<&str, Define<'_>> {
     let define_name = delimited(
         // Consume the #define
         pair(tag("#define"), space1),
@@ -175,7 +175,7 @@ fn consume_ws_and_comments(input: &str) -> IResult<&str, ()> {
     )(input)
 }
 
-fn parse_defines(input: &str) -> IResult<&str, Vec<Define>> {
+fn parse_defines(input: &str) -> IResult<&str, Vec<Define<'_>>> {
     many0(preceded(opt(consume_ws_and_comments), parse_define))(input)
 }
 
@@ -183,7 +183,7 @@ fn consume_define_no_value(input: &str) -> IResult<&str, ()> {
     value((), delimited(tag("#define"), not_line_ending, line_ending))(input)
 }
 
-pub fn parse_file(input: &str) -> IResult<&str, Vec<Define>> {
+pub fn parse_file(input: &str) -> IResult<&str, Vec<Define<'_>>> {
     delimited(
         tuple((
             opt(consume_ws_and_comments),
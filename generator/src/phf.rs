@@ -0,0 +1,125 @@
+//! Construction of the perfect hash tables used to look up codes by name.
+//!
+//! The lookup side lives in `src/phf.rs` of the main crate. The hash function below must be kept identical to
+//! the one defined there.
+
+/// The average number of keys in each bucket.
+const KEYS_PER_BUCKET: usize = 4;
+
+/// A minimal perfect hash table.
+#[derive(Debug)]
+pub struct PerfectHash {
+    /// The seed used to hash the keys in each bucket.
+    pub seeds: Vec<u32>,
+
+    /// The index of the key stored in each slot of the table.
+    pub slots: Vec<usize>,
+}
+
+/// FNV-1a with the seed mixed into the offset basis, followed by the MurmurHash3 finalizer.
+///
+/// ASCII letters are hashed as if they were uppercase so the same table may be used for case insensitive
+/// lookups.
+pub fn hash(bytes: &[u8], seed: u32) -> u32 {
+    let mut hash = 0x811c_9dc5 ^ seed;
+
+    for byte in bytes {
+        hash ^= byte.to_ascii_uppercase() as u32;
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+
+    hash ^= hash >> 16;
+    hash = hash.wrapping_mul(0x85eb_ca6b);
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(0xc2b2_ae35);
+    hash ^= hash >> 16;
+    hash
+}
+
+/// Builds a minimal perfect hash over the keys using hash and displace.
+///
+/// Keys are first distributed into buckets using seed `0`. Starting with the largest bucket, a seed is then
+/// searched for which places every key of the bucket in an unoccupied slot.
+pub fn build(keys: &[&str]) -> PerfectHash {
+    assert!(!keys.is_empty(), "Cannot build a perfect hash without keys");
+
+    let bucket_count = keys.len().div_ceil(KEYS_PER_BUCKET);
+    let mut buckets = vec![Vec::new(); bucket_count];
+
+    for (index, key) in keys.iter().enumerate() {
+        buckets[hash(key.as_bytes(), 0) as usize % bucket_count].push(index);
+    }
+
+    let mut order = (0..bucket_count).collect::<Vec<_>>();
+    order.sort_by_key(|&bucket| std::cmp::Reverse(buckets[bucket].len()));
+
+    let mut seeds = vec![0; bucket_count];
+    let mut slots = vec![None; keys.len()];
+
+    for bucket in order {
+        let keys_in_bucket = &buckets[bucket];
+
+        if keys_in_bucket.is_empty() {
+            continue;
+        }
+
+        'seed: for seed in 1.. {
+            let mut placed = Vec::with_capacity(keys_in_bucket.len());
+
+            for &key in keys_in_bucket {
+                let slot = hash(keys[key].as_bytes(), seed) as usize % keys.len();
+
+                if slots[slot].is_some() || placed.contains(&slot) {
+                    continue 'seed;
+                }
+
+                placed.push(slot);
+            }
+
+            for (&key, slot) in keys_in_bucket.iter().zip(placed) {
+                slots[slot] = Some(key);
+            }
+
+            seeds[bucket] = seed;
+            break;
+        }
+    }
+
+    PerfectHash {
+        seeds,
+        slots: slots.into_iter().map(Option::unwrap).collect(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{build, hash, PerfectHash};
+
+    fn lookup(table: &PerfectHash, name: &str) -> usize {
+        let bucket = hash(name.as_bytes(), 0) as usize % table.seeds.len();
+        table.slots[hash(name.as_bytes(), table.seeds[bucket]) as usize % table.slots.len()]
+    }
+
+    #[test]
+    fn every_key_is_found() {
+        let keys = (0..1000).map(|i| format!("KEY_{}", i)).collect::<Vec<_>>();
+        let keys = keys.iter().map(String::as_str).collect::<Vec<_>>();
+
+        let table = build(&keys);
+
+        for (index, key) in keys.iter().enumerate() {
+            assert_eq!(lookup(&table, key), index);
+        }
+    }
+
+    #[test]
+    fn hash_ignores_case() {
+        assert_eq!(hash(b"KEY_LEFTMETA", 7), hash(b"key_leftmeta", 7));
+        assert_ne!(hash(b"KEY_LEFTMETA", 7), hash(b"KEY_LEFTMETA", 8));
+    }
+
+    #[test]
+    fn single_key() {
+        assert_eq!(lookup(&build(&["SYN_REPORT"]), "SYN_REPORT"), 0);
+    }
+}
@@ -1,6 +1,11 @@
 //! This module contains all data regarding renames of input code categories and documentation to attach
 //! to each category.
 
+/// Categories which the kernel stores in a `__u16`, such as the bus type of `struct input_id`.
+///
+/// The generated types of these categories wrap a `u16`, so they have the same layout as the kernel field.
+pub const U16_CATEGORIES: &[&str] = &["Bus"];
+
 pub const RENAMES: &[CategoryRename] = &[
     CategoryRename {
         name: "Input",
@@ -52,6 +57,23 @@ pub const RENAMES: &[CategoryRename] = &[
         rename_to: Some("AutoRepeat"),
         documentation: Some("Autorepeat values."),
     },
+    CategoryRename {
+        name: "Ff",
+        rename_to: Some("ForceFeedback"),
+        documentation: Some("Force feedback effect types, waveforms and device properties."),
+    },
+    CategoryRename {
+        name: "Bus",
+        rename_to: Some("BusType"),
+        documentation: Some("Buses an input device may be connected through."),
+    },
+    CategoryRename {
+        name: "Id",
+        rename_to: Some("InputIdField"),
+        documentation: Some(
+            "Indices of the fields of an input id, when read as an array of `u16`.",
+        ),
+    },
     CategoryRename {
         name: "Snd",
         rename_to: Some("Sound"),
@@ -0,0 +1,637 @@
+//! Fixed size sets of codes, matching the bitmaps used by the kernel to describe the capabilities and state of a
+//! device.
+//!
+//! Code `n` is stored in bit `n % 8` of byte `n / 8`. The kernel stores the bitmaps returned by `EVIOCGBIT`,
+//! `EVIOCGKEY` and the other bitmap ioctls as arrays of `unsigned long` instead, with code `n` in bit
+//! `n % BITS` of word `n / BITS`. The two layouts only match on little-endian targets, so bitmaps of the kernel
+//! are converted word by word with `from_kernel_words` and `to_kernel_words`.
+
+use core::{ffi::c_ulong, fmt, iter::FusedIterator, marker::PhantomData, mem::size_of};
+
+use crate::{
+    AbsoluteAxis, EventType, ForceFeedback, InputQuirk, Key, KeyCode, Led, MiscEvent, RelativeAxis,
+    Sound, SwitchEvent,
+};
+
+/// An iterator over the codes in a set, in ascending order.
+#[derive(Clone)]
+pub struct SetIter<'a, T> {
+    bytes: &'a [u8],
+    index: usize,
+    _code: PhantomData<fn() -> T>,
+}
+
+impl<T: From<u32>> Iterator for SetIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while let Some(&byte) = self.bytes.get(self.index / 8) {
+            // Skip the rest of the byte if no more bits are set in it.
+            let remaining = byte >> (self.index % 8);
+
+            if remaining == 0 {
+                self.index = (self.index / 8 + 1) * 8;
+                continue;
+            }
+
+            let index = self.index + remaining.trailing_zeros() as usize;
+            self.index = index + 1;
+            return Some(T::from(index as u32));
+        }
+
+        None
+    }
+}
+
+impl<T: From<u32>> FusedIterator for SetIter<'_, T> {}
+
+impl<T> fmt::Debug for SetIter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SetIter")
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+macro_rules! bitset {
+    ($(#[$meta:meta])* $name:ident($code:ty), $count:expr) => {
+        $(#[$meta])*
+        ///
+        /// Code `n` is stored in bit `n % 8` of byte `n / 8` of the bitmap. Codes which are too large to be stored
+        /// in the set are never contained in the set, and are ignored when added to the set.
+        #[repr(transparent)]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name([u8; Self::LEN]);
+
+        impl $name {
+            /// The number of bytes of the bitmap.
+            pub const LEN: usize = $count.div_ceil(8);
+
+            /// The number of `unsigned long` words of the bitmap used by the kernel.
+            pub const WORDS: usize = Self::LEN.div_ceil(size_of::<c_ulong>());
+
+            /// A set containing no codes.
+            pub const EMPTY: Self = Self([0; Self::LEN]);
+
+            /// Creates an empty set.
+            pub const fn new() -> Self {
+                Self::EMPTY
+            }
+
+            /// Creates a set from a bitmap.
+            pub const fn from_bytes(bytes: [u8; Self::LEN]) -> Self {
+                Self(bytes)
+            }
+
+            /// Views a bitmap as a set, without copying.
+            pub fn from_bytes_ref(bytes: &[u8; Self::LEN]) -> &Self {
+                // SAFETY: The set is a transparent wrapper of the bitmap.
+                unsafe { &*(bytes as *const [u8; Self::LEN] as *const Self) }
+            }
+
+            /// Views a bitmap as a mutable set, without copying.
+            pub fn from_bytes_mut(bytes: &mut [u8; Self::LEN]) -> &mut Self {
+                // SAFETY: The set is a transparent wrapper of the bitmap.
+                unsafe { &mut *(bytes as *mut [u8; Self::LEN] as *mut Self) }
+            }
+
+            /// Creates a set from the start of a bitmap.
+            ///
+            /// The kernel may write fewer bytes than the size of the bitmap, in which case the remaining codes
+            /// are not in the set. Bytes past the end of the bitmap are ignored.
+            pub fn from_slice(bytes: &[u8]) -> Self {
+                let mut set = Self::EMPTY;
+                let len = bytes.len().min(Self::LEN);
+                set.0[..len].copy_from_slice(&bytes[..len]);
+                set
+            }
+
+            /// Creates a set from a bitmap of the kernel, such as one filled in by `EVIOCGBIT`.
+            ///
+            /// Codes in missing words are not in the set, and words past the end of the set are ignored.
+            pub fn from_kernel_words(words: &[c_ulong]) -> Self {
+                let mut set = Self::EMPTY;
+
+                for (bytes, word) in set.0.chunks_mut(size_of::<c_ulong>()).zip(words) {
+                    bytes.copy_from_slice(&word.to_le_bytes()[..bytes.len()]);
+                }
+
+                set
+            }
+
+            /// Returns the set as a bitmap of the kernel, such as to pass to `EVIOCSMASK`.
+            pub fn to_kernel_words(&self) -> [c_ulong; Self::WORDS] {
+                let mut words = [0; Self::WORDS];
+
+                for (word, bytes) in words.iter_mut().zip(self.0.chunks(size_of::<c_ulong>())) {
+                    let mut word_bytes = [0; size_of::<c_ulong>()];
+                    word_bytes[..bytes.len()].copy_from_slice(bytes);
+                    *word = c_ulong::from_le_bytes(word_bytes);
+                }
+
+                words
+            }
+
+            /// Returns the bitmap of the set.
+            pub const fn as_bytes(&self) -> &[u8; Self::LEN] {
+                &self.0
+            }
+
+            /// Returns the mutable bitmap of the set.
+            pub fn as_bytes_mut(&mut self) -> &mut [u8; Self::LEN] {
+                &mut self.0
+            }
+
+            /// Returns the bitmap of the set.
+            pub const fn into_bytes(self) -> [u8; Self::LEN] {
+                self.0
+            }
+
+            /// Returns whether the code is in the set.
+            pub const fn contains(&self, code: $code) -> bool {
+                let index = code.into_inner() as usize;
+
+                index / 8 < Self::LEN && self.0[index / 8] & (1 << (index % 8)) != 0
+            }
+
+            /// Returns the set with the code added.
+            ///
+            /// The set is returned unchanged if the code is too large to be stored in the set.
+            pub const fn with(mut self, code: $code) -> Self {
+                let index = code.into_inner() as usize;
+
+                if index / 8 < Self::LEN {
+                    self.0[index / 8] |= 1 << (index % 8);
+                }
+
+                self
+            }
+
+            /// Returns the set with the code removed.
+            pub const fn without(mut self, code: $code) -> Self {
+                let index = code.into_inner() as usize;
+
+                if index / 8 < Self::LEN {
+                    self.0[index / 8] &= !(1 << (index % 8));
+                }
+
+                self
+            }
+
+            /// Adds the code to the set, returning whether the code was added.
+            ///
+            /// Returns `false` without changing the set if the code is already in the set, or if the code is too
+            /// large to be stored in the set.
+            pub fn insert(&mut self, code: $code) -> bool {
+                let previous = *self;
+                *self = self.with(code);
+                *self != previous
+            }
+
+            /// Removes the code from the set, returning whether the code was in the set.
+            pub fn remove(&mut self, code: $code) -> bool {
+                let removed = self.contains(code);
+                *self = self.without(code);
+                removed
+            }
+
+            /// Removes every code from the set.
+            pub fn clear(&mut self) {
+                *self = Self::EMPTY;
+            }
+
+            /// Returns whether the set contains no codes.
+            pub const fn is_empty(&self) -> bool {
+                let mut index = 0;
+
+                while index < Self::LEN {
+                    if self.0[index] != 0 {
+                        return false;
+                    }
+
+                    index += 1;
+                }
+
+                true
+            }
+
+            /// Returns the number of codes in the set.
+            pub const fn len(&self) -> usize {
+                let mut len = 0;
+                let mut index = 0;
+
+                while index < Self::LEN {
+                    len += self.0[index].count_ones() as usize;
+                    index += 1;
+                }
+
+                len
+            }
+
+            /// Returns the codes in either set.
+            pub const fn union(mut self, other: &Self) -> Self {
+                let mut index = 0;
+
+                while index < Self::LEN {
+                    self.0[index] |= other.0[index];
+                    index += 1;
+                }
+
+                self
+            }
+
+            /// Returns the codes in both sets.
+            pub const fn intersection(mut self, other: &Self) -> Self {
+                let mut index = 0;
+
+                while index < Self::LEN {
+                    self.0[index] &= other.0[index];
+                    index += 1;
+                }
+
+                self
+            }
+
+            /// Returns the codes in this set but not in the other set.
+            pub const fn difference(mut self, other: &Self) -> Self {
+                let mut index = 0;
+
+                while index < Self::LEN {
+                    self.0[index] &= !other.0[index];
+                    index += 1;
+                }
+
+                self
+            }
+
+            /// Returns the codes in exactly one of the sets.
+            pub const fn symmetric_difference(mut self, other: &Self) -> Self {
+                let mut index = 0;
+
+                while index < Self::LEN {
+                    self.0[index] ^= other.0[index];
+                    index += 1;
+                }
+
+                self
+            }
+
+            /// Returns whether every code in this set is in the other set.
+            pub const fn is_subset(&self, other: &Self) -> bool {
+                self.difference(other).is_empty()
+            }
+
+            /// Returns whether every code in the other set is in this set.
+            pub const fn is_superset(&self, other: &Self) -> bool {
+                other.is_subset(self)
+            }
+
+            /// Returns whether the sets have no codes in common.
+            pub const fn is_disjoint(&self, other: &Self) -> bool {
+                self.intersection(other).is_empty()
+            }
+
+            /// Returns an iterator over the codes in the set, in ascending order.
+            pub fn iter(&self) -> SetIter<'_, $code> {
+                SetIter {
+                    bytes: &self.0,
+                    index: 0,
+                    _code: PhantomData,
+                }
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::EMPTY
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_set().entries(self.iter()).finish()
+            }
+        }
+
+        impl<'a> IntoIterator for &'a $name {
+            type Item = $code;
+            type IntoIter = SetIter<'a, $code>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl Extend<$code> for $name {
+            fn extend<I: IntoIterator<Item = $code>>(&mut self, iter: I) {
+                for code in iter {
+                    self.insert(code);
+                }
+            }
+        }
+
+        impl core::iter::FromIterator<$code> for $name {
+            fn from_iter<I: IntoIterator<Item = $code>>(iter: I) -> Self {
+                let mut set = Self::EMPTY;
+                set.extend(iter);
+                set
+            }
+        }
+
+        impl From<[u8; $name::LEN]> for $name {
+            fn from(bytes: [u8; $name::LEN]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl AsMut<[u8]> for $name {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+
+        impl core::ops::BitOr for $name {
+            type Output = Self;
+
+            fn bitor(self, other: Self) -> Self {
+                self.union(&other)
+            }
+        }
+
+        impl core::ops::BitOrAssign for $name {
+            fn bitor_assign(&mut self, other: Self) {
+                *self = self.union(&other);
+            }
+        }
+
+        impl core::ops::BitAnd for $name {
+            type Output = Self;
+
+            fn bitand(self, other: Self) -> Self {
+                self.intersection(&other)
+            }
+        }
+
+        impl core::ops::BitAndAssign for $name {
+            fn bitand_assign(&mut self, other: Self) {
+                *self = self.intersection(&other);
+            }
+        }
+
+        impl core::ops::Sub for $name {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                self.difference(&other)
+            }
+        }
+
+        impl core::ops::SubAssign for $name {
+            fn sub_assign(&mut self, other: Self) {
+                *self = self.difference(&other);
+            }
+        }
+
+        impl core::ops::BitXor for $name {
+            type Output = Self;
+
+            fn bitxor(self, other: Self) -> Self {
+                self.symmetric_difference(&other)
+            }
+        }
+
+        impl core::ops::BitXorAssign for $name {
+            fn bitxor_assign(&mut self, other: Self) {
+                *self = self.symmetric_difference(&other);
+            }
+        }
+    };
+}
+
+bitset!(
+    /// A set of event types, such as the event types supported by a device.
+    EventTypeSet(EventType),
+    EventType::COUNT
+);
+
+bitset!(
+    /// A set of keys and buttons, such as the keys supported by or pressed on a device.
+    KeySet(KeyCode),
+    Key::COUNT
+);
+
+bitset!(
+    /// A set of relative axes.
+    RelSet(RelativeAxis),
+    RelativeAxis::COUNT
+);
+
+bitset!(
+    /// A set of absolute axes.
+    AbsSet(AbsoluteAxis),
+    AbsoluteAxis::COUNT
+);
+
+bitset!(
+    /// A set of miscellaneous events.
+    MiscSet(MiscEvent),
+    MiscEvent::COUNT
+);
+
+bitset!(
+    /// A set of switches, such as the switches supported by or set on a device.
+    SwitchSet(SwitchEvent),
+    SwitchEvent::COUNT
+);
+
+bitset!(
+    /// A set of LEDs, such as the LEDs supported by or lit on a device.
+    LedSet(Led),
+    Led::COUNT
+);
+
+bitset!(
+    /// A set of sounds, such as the sounds supported by or playing on a device.
+    SoundSet(Sound),
+    Sound::COUNT
+);
+
+bitset!(
+    /// A set of force feedback effect types and properties.
+    ForceFeedbackSet(ForceFeedback),
+    ForceFeedback::COUNT
+);
+
+bitset!(
+    /// A set of device properties.
+    PropertySet(InputQuirk),
+    InputQuirk::COUNT
+);
+
+/// Decodes a bitmap printed by the kernel as hexadecimal `unsigned long` words, most significant word first, into
+/// the bytes of a set.
+///
+/// This is the format of the `B:` lines of `/proc/bus/input/devices` and the capability files in sysfs. Bits which
+/// do not fit in the set are ignored.
+pub(crate) fn decode_hex_words(words: &str, word_bits: usize, bytes: &mut [u8]) -> Option<()> {
+    let word_bytes = word_bits / 8;
+
+    for (index, word) in words.split_whitespace().rev().enumerate() {
+        if word.len() > word_bits / 4 {
+            return None;
+        }
+
+        let word = u64::from_str_radix(word, 16).ok()?;
+
+        for byte in 0..word_bytes {
+            if let Some(target) = bytes.get_mut(index * word_bytes + byte) {
+                *target = (word >> (byte * 8)) as u8;
+            }
+        }
+    }
+
+    Some(())
+}
+
+#[cfg(test)]
+mod test {
+    use core::ffi::c_ulong;
+    use std::vec::Vec;
+
+    use crate::{
+        AbsSet, AbsoluteAxis, Button, EventType, EventTypeSet, Key, KeyCode, KeySet, Led, LedSet,
+        SwitchEvent, SwitchSet,
+    };
+
+    const KEYBOARD: EventTypeSet = EventTypeSet::new()
+        .with(EventType::SYN)
+        .with(EventType::KEY)
+        .with(EventType::LED)
+        .with(EventType::REP);
+
+    #[test]
+    fn sizes() {
+        assert_eq!(KeySet::LEN, 96);
+        assert_eq!(EventTypeSet::LEN, 4);
+        assert_eq!(SwitchSet::LEN, 3);
+        assert_eq!(core::mem::size_of::<KeySet>(), 96);
+    }
+
+    #[test]
+    fn const_construction() {
+        assert!(KEYBOARD.contains(EventType::KEY));
+        assert!(!KEYBOARD.contains(EventType::ABS));
+        assert_eq!(KEYBOARD.len(), 4);
+        assert_eq!(KEYBOARD.as_bytes(), &[0b0000_0011, 0, 0b0001_0010, 0]);
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut keys = KeySet::new();
+        assert!(keys.is_empty());
+        assert!(keys.insert(Key::A.into()));
+        assert!(!keys.insert(Key::A.into()));
+        assert!(keys.insert(Button::LEFT.into()));
+        assert!(keys.contains(KeyCode::Button(Button::LEFT)));
+        assert!(keys.remove(Key::A.into()));
+        assert!(!keys.remove(Key::A.into()));
+        assert_eq!(keys.len(), 1);
+
+        // Codes past the end of the set are never contained, and are ignored when added.
+        assert!(!keys.contains(KeyCode::new(0x1000)));
+        assert!(!keys.insert(KeyCode::new(0x1000)));
+        assert!(!keys.remove(KeyCode::new(0x1000)));
+        assert_eq!(keys.with(KeyCode::new(0x1000)), keys);
+
+        keys.extend([KeyCode::new(0x300), Key::B.into()].iter().copied());
+        assert_eq!(keys.len(), 2);
+    }
+
+    #[test]
+    fn iter() {
+        let keys = [
+            KeyCode::from(Key::ESC),
+            Key::A.into(),
+            Button::LEFT.into(),
+            Button::TRIGGER_HAPPY40.into(),
+        ];
+        let set = keys.iter().copied().collect::<KeySet>();
+
+        assert_eq!(set.iter().collect::<Vec<_>>(), keys);
+        assert_eq!(KeySet::new().iter().next(), None);
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = [AbsoluteAxis::X, AbsoluteAxis::Y, AbsoluteAxis::PRESSURE]
+            .iter()
+            .copied()
+            .collect::<AbsSet>();
+        let b = [AbsoluteAxis::Y, AbsoluteAxis::MT_SLOT]
+            .iter()
+            .copied()
+            .collect::<AbsSet>();
+
+        assert_eq!((a | b).len(), 4);
+        assert_eq!((a & b).iter().collect::<Vec<_>>(), [AbsoluteAxis::Y]);
+        assert_eq!(
+            (a - b).iter().collect::<Vec<_>>(),
+            [AbsoluteAxis::X, AbsoluteAxis::PRESSURE]
+        );
+        assert_eq!(
+            (a ^ b).iter().collect::<Vec<_>>(),
+            [
+                AbsoluteAxis::X,
+                AbsoluteAxis::PRESSURE,
+                AbsoluteAxis::MT_SLOT
+            ]
+        );
+        assert!((a & b).is_subset(&a));
+        assert!(a.is_superset(&(a - b)));
+        assert!((a - b).is_disjoint(&b));
+    }
+
+    #[test]
+    fn kernel_bytes() {
+        // The switches reported by a laptop with a lid, a tablet mode switch and a machine cover switch.
+        let bytes = [0b0000_0011, 0, 0b0000_0001];
+        let switches = SwitchSet::from_bytes_ref(&bytes);
+
+        assert_eq!(
+            switches.iter().collect::<Vec<_>>(),
+            [
+                SwitchEvent::LID,
+                SwitchEvent::TABLET_MODE,
+                SwitchEvent::MACHINE_COVER
+            ]
+        );
+
+        // A short read leaves the remaining codes unset.
+        let leds = LedSet::from_slice(&[0b0000_0010]);
+        assert_eq!(leds.iter().collect::<Vec<_>>(), [Led::CAPSL]);
+
+        let mut buffer = [0; LedSet::LEN];
+        LedSet::from_bytes_mut(&mut buffer).insert(Led::NUML);
+        assert_eq!(buffer, [1, 0]);
+    }
+
+    #[test]
+    fn kernel_words() {
+        // KEY_A is bit 30 of the first word on every target.
+        let keys = KeySet::from_kernel_words(&[1 << 30]);
+        assert_eq!(keys.iter().collect::<Vec<_>>(), [KeyCode::from(Key::A)]);
+        assert_eq!(keys.to_kernel_words()[0], 1 << 30);
+        assert_eq!(keys.to_kernel_words()[1..], [0; KeySet::WORDS - 1]);
+
+        // Words past the end of the set are ignored.
+        let event_types = EventTypeSet::from_kernel_words(&[0x0012_0003, c_ulong::MAX]);
+        assert_eq!(event_types, KEYBOARD);
+        assert_eq!(KEYBOARD.to_kernel_words(), [0x0012_0003]);
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{Button, Key};
+
+/// The blocks the kernel headers divide buttons into.
+///
+/// The group of a button may be used to find what kind of device reports the button, for example a device
+/// reporting [`ButtonGroup::Gamepad`] buttons is likely to be a gamepad.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ButtonGroup {
+    /// Miscellaneous buttons, starting at [`Button::MISC`].
+    Misc,
+
+    /// Mouse buttons, starting at [`Button::MOUSE`].
+    Mouse,
+
+    /// Joystick buttons, starting at [`Button::JOYSTICK`].
+    Joystick,
+
+    /// Gamepad buttons, starting at [`Button::GAMEPAD`].
+    Gamepad,
+
+    /// Digitizer tools and buttons, starting at [`Button::DIGI`].
+    Digi,
+
+    /// Wheel buttons, starting at [`Button::WHEEL`].
+    Wheel,
+
+    /// Directional pad buttons, [`Button::DPAD_UP`] to [`Button::DPAD_RIGHT`].
+    Dpad,
+
+    /// Extra buttons of joysticks and gamepads, starting at [`Button::TRIGGER_HAPPY`].
+    TriggerHappy,
+}
+
+impl ButtonGroup {
+    /// Every button group, ordered by the values of the buttons in the group.
+    pub const ALL: &'static [Self] = &[
+        Self::Misc,
+        Self::Mouse,
+        Self::Joystick,
+        Self::Gamepad,
+        Self::Digi,
+        Self::Wheel,
+        Self::Dpad,
+        Self::TriggerHappy,
+    ];
+
+    /// Returns the known buttons in the group, ordered by value.
+    pub fn buttons(self) -> &'static [Button] {
+        let (start, end) = self.bounds();
+        let first = Button::ALL.partition_point(|button| button.into_inner() < start);
+        let last = Button::ALL.partition_point(|button| button.into_inner() < end);
+
+        &Button::ALL[first..last]
+    }
+
+    /// Whether the button is in the group.
+    pub const fn contains(self, button: Button) -> bool {
+        let (start, end) = self.bounds();
+        button.into_inner() >= start && button.into_inner() < end
+    }
+
+    /// The range of values of the group, with an exclusive end.
+    const fn bounds(self) -> (u32, u32) {
+        match self {
+            Self::Misc => (Button::MISC.into_inner(), Button::MOUSE.into_inner()),
+            Self::Mouse => (Button::MOUSE.into_inner(), Button::JOYSTICK.into_inner()),
+            Self::Joystick => (Button::JOYSTICK.into_inner(), Button::GAMEPAD.into_inner()),
+            Self::Gamepad => (Button::GAMEPAD.into_inner(), Button::DIGI.into_inner()),
+            Self::Digi => (Button::DIGI.into_inner(), Button::WHEEL.into_inner()),
+            Self::Wheel => (Button::WHEEL.into_inner(), Key::OK.into_inner()),
+            Self::Dpad => (
+                Button::DPAD_UP.into_inner(),
+                Button::DPAD_RIGHT.into_inner() + 1,
+            ),
+            Self::TriggerHappy => (
+                Button::TRIGGER_HAPPY.into_inner(),
+                Button::TRIGGER_HAPPY40.into_inner() + 1,
+            ),
+        }
+    }
+}
+
+impl Button {
+    /// Returns the group the button belongs to.
+    ///
+    /// Returns [`None`] if the value is outside of the ranges reserved for buttons.
+    pub const fn group(self) -> Option<ButtonGroup> {
+        let mut index = 0;
+
+        while index < ButtonGroup::ALL.len() {
+            let group = ButtonGroup::ALL[index];
+
+            if group.contains(self) {
+                return Some(group);
+            }
+
+            index += 1;
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Button, ButtonGroup};
+
+    #[test]
+    fn group() {
+        assert_eq!(Button::_0.group(), Some(ButtonGroup::Misc));
+        assert_eq!(Button::TASK.group(), Some(ButtonGroup::Mouse));
+        assert_eq!(Button::DEAD.group(), Some(ButtonGroup::Joystick));
+        assert_eq!(Button::SOUTH.group(), Some(ButtonGroup::Gamepad));
+        assert_eq!(Button::TOOL_QUADTAP.group(), Some(ButtonGroup::Digi));
+        assert_eq!(Button::GEAR_UP.group(), Some(ButtonGroup::Wheel));
+        assert_eq!(Button::DPAD_LEFT.group(), Some(ButtonGroup::Dpad));
+        assert_eq!(
+            Button::TRIGGER_HAPPY40.group(),
+            Some(ButtonGroup::TriggerHappy)
+        );
+        assert_eq!(Button::new(0x30).group(), None);
+        assert_eq!(Button::new(0x224).group(), None);
+    }
+
+    #[test]
+    fn every_button_has_a_group() {
+        for &button in Button::ALL {
+            let group = button.group().unwrap();
+            assert!(group.buttons().contains(&button));
+        }
+
+        let total = ButtonGroup::ALL
+            .iter()
+            .map(|group| group.buttons().len())
+            .sum::<usize>();
+        assert_eq!(total, Button::ALL.len());
+    }
+
+    #[test]
+    fn buttons() {
+        assert_eq!(
+            ButtonGroup::Dpad.buttons(),
+            &[
+                Button::DPAD_UP,
+                Button::DPAD_DOWN,
+                Button::DPAD_LEFT,
+                Button::DPAD_RIGHT
+            ]
+        );
+        assert_eq!(ButtonGroup::Mouse.buttons().first(), Some(&Button::LEFT));
+    }
+}
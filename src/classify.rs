@@ -0,0 +1,507 @@
+//! Classification of input devices from their capabilities, using the heuristics of the `input_id` builtin of
+//! udev.
+//!
+//! udev sets properties such as `ID_INPUT_KEYBOARD` and `ID_INPUT_TOUCHPAD` on input devices, which compositors
+//! and libraries such as libinput use to decide how to handle a device. [`classify`] derives the same
+//! classes from the capabilities of a device, for environments without udev.
+
+use core::ops::RangeInclusive;
+
+use crate::{
+    AbsSet, AbsoluteAxis, BusType, Button, EventType, EventTypeSet, InputQuirk, Key, KeyCode,
+    KeySet, PropertySet, RelSet, RelativeAxis,
+};
+
+/// The capabilities of a device used to classify it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceCapabilities {
+    /// The bus the device is connected to.
+    pub bustype: BusType,
+
+    /// The properties of the device.
+    pub properties: PropertySet,
+
+    /// The event types the device supports.
+    pub event_types: EventTypeSet,
+
+    /// The keys and buttons the device supports.
+    pub key_codes: KeySet,
+
+    /// The relative axes the device supports.
+    pub relative_axes: RelSet,
+
+    /// The absolute axes the device supports.
+    pub absolute_axes: AbsSet,
+}
+
+impl Default for DeviceCapabilities {
+    fn default() -> Self {
+        Self {
+            bustype: BusType::new(0),
+            properties: PropertySet::EMPTY,
+            event_types: EventTypeSet::EMPTY,
+            key_codes: KeySet::EMPTY,
+            relative_axes: RelSet::EMPTY,
+            absolute_axes: AbsSet::EMPTY,
+        }
+    }
+}
+
+impl From<&crate::ProcDevice<'_>> for DeviceCapabilities {
+    fn from(device: &crate::ProcDevice<'_>) -> Self {
+        Self {
+            bustype: device.id.bustype,
+            properties: device.properties,
+            event_types: device.event_types,
+            key_codes: device.key_codes,
+            relative_axes: device.relative_axes,
+            absolute_axes: device.absolute_axes,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<&crate::sysfs::SysfsDevice> for DeviceCapabilities {
+    fn from(device: &crate::sysfs::SysfsDevice) -> Self {
+        Self {
+            bustype: device.id.bustype,
+            properties: device.properties,
+            event_types: device.event_types,
+            key_codes: device.key_codes,
+            relative_axes: device.relative_axes,
+            absolute_axes: device.absolute_axes,
+        }
+    }
+}
+
+/// The classes of a device, matching the `ID_INPUT_*` properties set by udev.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DeviceClasses {
+    /// The device has keys, `ID_INPUT_KEY`.
+    pub key: bool,
+
+    /// The device is a full keyboard, `ID_INPUT_KEYBOARD`.
+    pub keyboard: bool,
+
+    /// The device is a mouse, `ID_INPUT_MOUSE`.
+    pub mouse: bool,
+
+    /// The device is a touchpad, `ID_INPUT_TOUCHPAD`.
+    pub touchpad: bool,
+
+    /// The device is a touchscreen, `ID_INPUT_TOUCHSCREEN`.
+    pub touchscreen: bool,
+
+    /// The device is a graphics tablet, `ID_INPUT_TABLET`.
+    pub tablet: bool,
+
+    /// The device is the button pad of a graphics tablet, `ID_INPUT_TABLET_PAD`.
+    pub tablet_pad: bool,
+
+    /// The device is a joystick or gamepad, `ID_INPUT_JOYSTICK`.
+    pub joystick: bool,
+
+    /// The device is an accelerometer, `ID_INPUT_ACCELEROMETER`.
+    pub accelerometer: bool,
+
+    /// The device is a pointing stick, `ID_INPUT_POINTINGSTICK`.
+    pub pointing_stick: bool,
+
+    /// The device has switches, `ID_INPUT_SWITCH`.
+    pub switch: bool,
+}
+
+impl DeviceClasses {
+    /// Returns an iterator over the names of the udev properties of the classes, such as `ID_INPUT_KEYBOARD`.
+    pub fn properties(&self) -> impl Iterator<Item = &'static str> {
+        IntoIterator::into_iter([
+            (self.key, "ID_INPUT_KEY"),
+            (self.keyboard, "ID_INPUT_KEYBOARD"),
+            (self.mouse, "ID_INPUT_MOUSE"),
+            (self.touchpad, "ID_INPUT_TOUCHPAD"),
+            (self.touchscreen, "ID_INPUT_TOUCHSCREEN"),
+            (self.tablet, "ID_INPUT_TABLET"),
+            (self.tablet_pad, "ID_INPUT_TABLET_PAD"),
+            (self.joystick, "ID_INPUT_JOYSTICK"),
+            (self.accelerometer, "ID_INPUT_ACCELEROMETER"),
+            (self.pointing_stick, "ID_INPUT_POINTINGSTICK"),
+            (self.switch, "ID_INPUT_SWITCH"),
+        ])
+        .filter(|(class, _)| *class)
+        .map(|(_, property)| property)
+    }
+}
+
+/// Classifies a device from its capabilities, using the heuristics of udev.
+pub fn classify(capabilities: &DeviceCapabilities) -> DeviceClasses {
+    let mut classes = DeviceClasses::default();
+    let is_pointer = classify_pointer(capabilities, &mut classes);
+    let is_key = classify_key(capabilities, &mut classes);
+
+    // Some devices only have a scroll wheel.
+    if !is_pointer && !is_key && has_wheel(capabilities) {
+        classes.key = true;
+    }
+
+    classes.switch = capabilities.event_types.contains(EventType::SW);
+    classes
+}
+
+fn has_button(keys: &KeySet, button: Button) -> bool {
+    keys.contains(KeyCode::Button(button))
+}
+
+fn count_codes(keys: &KeySet, codes: RangeInclusive<u32>) -> usize {
+    codes
+        .filter(|&code| keys.contains(KeyCode::new(code)))
+        .count()
+}
+
+fn has_wheel(capabilities: &DeviceCapabilities) -> bool {
+    capabilities.event_types.contains(EventType::REL)
+        && (capabilities.relative_axes.contains(RelativeAxis::WHEEL)
+            || capabilities.relative_axes.contains(RelativeAxis::HWHEEL))
+}
+
+fn classify_pointer(capabilities: &DeviceCapabilities, classes: &mut DeviceClasses) -> bool {
+    let DeviceCapabilities {
+        bustype,
+        properties,
+        event_types,
+        key_codes: keys,
+        relative_axes: rel,
+        absolute_axes: abs,
+    } = capabilities;
+
+    let has_keys = event_types.contains(EventType::KEY);
+    let has_abs_coordinates = abs.contains(AbsoluteAxis::X) && abs.contains(AbsoluteAxis::Y);
+    let has_3d_coordinates = has_abs_coordinates && abs.contains(AbsoluteAxis::Z);
+
+    if properties.contains(InputQuirk::PROP_ACCELEROMETER) || (!has_keys && has_3d_coordinates) {
+        classes.accelerometer = true;
+        return true;
+    }
+
+    let mut is_pointing_stick = properties.contains(InputQuirk::PROP_POINTING_STICK);
+    let is_stylus = has_button(keys, Button::STYLUS);
+    let is_pen = has_button(keys, Button::TOOL_PEN);
+    let finger_but_no_pen = has_button(keys, Button::TOOL_FINGER) && !is_pen;
+    let has_mouse_button = count_codes(
+        keys,
+        Button::MOUSE.into_inner()..=Button::JOYSTICK.into_inner() - 1,
+    ) > 0;
+    let has_rel_coordinates = event_types.contains(EventType::REL)
+        && rel.contains(RelativeAxis::X)
+        && rel.contains(RelativeAxis::Y);
+
+    // Devices which claim to have every absolute axis do not really have multitouch coordinates.
+    let has_mt_coordinates = abs.contains(AbsoluteAxis::MT_POSITION_X)
+        && abs.contains(AbsoluteAxis::MT_POSITION_Y)
+        && !(abs.contains(AbsoluteAxis::MT_SLOT) && abs.contains(AbsoluteAxis::RESERVED));
+
+    let is_direct = properties.contains(InputQuirk::PROP_DIRECT);
+    let has_touch = has_button(keys, Button::TOUCH);
+    let has_pad_buttons =
+        has_button(keys, Button::_0) && has_button(keys, Button::_1) && !has_rel_coordinates;
+    let has_wheel = has_wheel(capabilities);
+
+    // Joysticks may have buttons but no axes, or axes but no buttons. The joystick buttons follow the mouse
+    // buttons, so a mouse with more than 16 buttons is not counted as a joystick.
+    let mut joystick_buttons = 0;
+
+    if !has_button(keys, Button::new(Button::JOYSTICK.into_inner() - 1)) {
+        joystick_buttons += count_codes(
+            keys,
+            Button::JOYSTICK.into_inner()..=Button::DIGI.into_inner() - 1,
+        );
+        joystick_buttons += count_codes(
+            keys,
+            Button::TRIGGER_HAPPY1.into_inner()..=Button::TRIGGER_HAPPY40.into_inner(),
+        );
+        joystick_buttons += count_codes(
+            keys,
+            Button::DPAD_UP.into_inner()..=Button::DPAD_RIGHT.into_inner(),
+        );
+    }
+
+    let joystick_axes = (AbsoluteAxis::RX.into_inner()..AbsoluteAxis::PRESSURE.into_inner())
+        .filter(|&axis| abs.contains(AbsoluteAxis::new(axis)))
+        .count();
+    let has_joystick_axes_or_buttons = joystick_buttons + joystick_axes > 0;
+
+    let mut is_tablet = false;
+    let mut is_tablet_pad = false;
+    let mut is_touchpad = false;
+    let mut is_touchscreen = false;
+    let mut is_joystick = false;
+    let mut is_abs_mouse = false;
+
+    if has_abs_coordinates {
+        if is_stylus || is_pen {
+            is_tablet = true;
+        } else if finger_but_no_pen && !is_direct {
+            is_touchpad = true;
+        } else if has_mouse_button {
+            // Such as the mice of virtual machines, which have absolute axes but no touch button.
+            is_abs_mouse = true;
+        } else if has_touch || is_direct {
+            is_touchscreen = true;
+        } else if has_joystick_axes_or_buttons {
+            is_joystick = true;
+        }
+    } else if has_joystick_axes_or_buttons {
+        is_joystick = true;
+    }
+
+    if has_mt_coordinates {
+        if is_stylus || is_pen {
+            is_tablet = true;
+        } else if finger_but_no_pen && !is_direct {
+            is_touchpad = true;
+        } else if has_touch || is_direct {
+            is_touchscreen = true;
+        }
+    }
+
+    if is_tablet && has_pad_buttons {
+        is_tablet_pad = true;
+    }
+
+    if has_pad_buttons && has_wheel && !has_rel_coordinates {
+        is_tablet = true;
+        is_tablet_pad = true;
+    }
+
+    let is_mouse = !is_tablet
+        && !is_touchpad
+        && !is_joystick
+        && has_mouse_button
+        && (has_rel_coordinates || !has_abs_coordinates);
+
+    // There are no I2C mice.
+    if is_mouse && *bustype == BusType::I2C {
+        is_pointing_stick = true;
+    }
+
+    // Some keyboards have joystick buttons. A joystick may have a few keyboard keys, but not keys from several of
+    // these groups, and needs at least two buttons or axes.
+    if is_joystick {
+        const KEYBOARD_KEYS: [Key; 10] = [
+            Key::LEFTCTRL,
+            Key::CAPSLOCK,
+            Key::NUMLOCK,
+            Key::INSERT,
+            Key::MUTE,
+            Key::CALC,
+            Key::FILE,
+            Key::MAIL,
+            Key::PLAYPAUSE,
+            Key::BRIGHTNESSDOWN,
+        ];
+
+        let keyboard_keys = KEYBOARD_KEYS
+            .iter()
+            .filter(|&&key| has_keys && keys.contains(KeyCode::Key(key)))
+            .count();
+
+        if keyboard_keys >= 4 || joystick_buttons + joystick_axes < 2 {
+            is_joystick = false;
+        }
+
+        if has_wheel && has_pad_buttons {
+            is_joystick = false;
+        }
+    }
+
+    classes.pointing_stick = is_pointing_stick;
+    classes.mouse = is_mouse || is_abs_mouse;
+    classes.touchpad = is_touchpad;
+    classes.touchscreen = is_touchscreen;
+    classes.joystick = is_joystick;
+    classes.tablet = is_tablet;
+    classes.tablet_pad = is_tablet_pad;
+
+    is_tablet
+        || is_mouse
+        || is_abs_mouse
+        || is_touchpad
+        || is_touchscreen
+        || is_joystick
+        || is_pointing_stick
+}
+
+fn classify_key(capabilities: &DeviceCapabilities, classes: &mut DeviceClasses) -> bool {
+    let keys = &capabilities.key_codes;
+
+    if !capabilities.event_types.contains(EventType::KEY) {
+        return false;
+    }
+
+    // Only keys count here, not buttons. The keys above the buttons are in two blocks.
+    let found = count_codes(keys, 0..=Button::MISC.into_inner() - 1) > 0
+        || count_codes(
+            keys,
+            Key::OK.into_inner()..=Button::DPAD_UP.into_inner() - 1,
+        ) > 0
+        || count_codes(
+            keys,
+            Key::ALS_TOGGLE.into_inner()..=Button::TRIGGER_HAPPY.into_inner() - 1,
+        ) > 0;
+
+    classes.key = found;
+
+    // A device with escape, the numbers and the letters from Q to D is a full keyboard.
+    classes.keyboard = (1..32).all(|code| keys.contains(KeyCode::new(code)));
+
+    found
+}
+
+#[cfg(test)]
+mod test {
+    use std::vec::Vec;
+
+    use crate::{
+        classify, AbsoluteAxis, BusType, Button, DeviceCapabilities, DeviceClasses, EventType,
+        InputQuirk, Key, KeyCode, ProcDevices, RelativeAxis,
+    };
+
+    const FIXTURE: &str = include_str!("../fixtures/proc_bus_input_devices.txt");
+
+    #[test]
+    fn fixture() {
+        let classes = ProcDevices::with_word_bits(FIXTURE, 64)
+            .map(|device| classify(&DeviceCapabilities::from(&device.unwrap())))
+            .map(|classes| classes.properties().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            classes,
+            [
+                &["ID_INPUT_SWITCH"][..],
+                &["ID_INPUT_KEY", "ID_INPUT_KEYBOARD"],
+                &["ID_INPUT_TOUCHPAD"],
+                &["ID_INPUT_MOUSE"],
+            ]
+        );
+    }
+
+    fn capabilities(
+        event_types: &[EventType],
+        keys: &[KeyCode],
+        absolute_axes: &[AbsoluteAxis],
+    ) -> DeviceCapabilities {
+        DeviceCapabilities {
+            event_types: event_types.iter().copied().collect(),
+            key_codes: keys.iter().copied().collect(),
+            absolute_axes: absolute_axes.iter().copied().collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn pointers() {
+        use AbsoluteAxis as Abs;
+
+        let touchscreen = capabilities(
+            &[EventType::KEY, EventType::ABS],
+            &[Button::TOUCH.into()],
+            &[
+                Abs::X,
+                Abs::Y,
+                Abs::MT_SLOT,
+                Abs::MT_POSITION_X,
+                Abs::MT_POSITION_Y,
+            ],
+        );
+        assert_eq!(
+            classify(&touchscreen),
+            DeviceClasses {
+                touchscreen: true,
+                ..Default::default()
+            }
+        );
+
+        let tablet = capabilities(
+            &[EventType::KEY, EventType::ABS],
+            &[
+                Button::TOOL_PEN.into(),
+                Button::STYLUS.into(),
+                Button::TOUCH.into(),
+            ],
+            &[Abs::X, Abs::Y, Abs::PRESSURE],
+        );
+        assert!(classify(&tablet).tablet);
+
+        let gamepad = capabilities(
+            &[EventType::KEY, EventType::ABS],
+            &[
+                Button::SOUTH.into(),
+                Button::EAST.into(),
+                Button::START.into(),
+            ],
+            &[Abs::X, Abs::Y, Abs::RX, Abs::RY],
+        );
+        assert_eq!(
+            classify(&gamepad).properties().collect::<Vec<_>>(),
+            ["ID_INPUT_JOYSTICK"]
+        );
+
+        let accelerometer = capabilities(&[EventType::ABS], &[], &[Abs::X, Abs::Y, Abs::Z]);
+        assert!(classify(&accelerometer).accelerometer);
+
+        let mut pointing_stick = capabilities(
+            &[EventType::KEY, EventType::REL],
+            &[Button::LEFT.into(), Button::RIGHT.into()],
+            &[],
+        );
+        pointing_stick.relative_axes = [RelativeAxis::X, RelativeAxis::Y].iter().copied().collect();
+        assert!(classify(&pointing_stick).mouse);
+        assert!(!classify(&pointing_stick).pointing_stick);
+
+        pointing_stick.bustype = BusType::I2C;
+        assert!(classify(&pointing_stick).pointing_stick);
+
+        pointing_stick.bustype = BusType::I8042;
+        pointing_stick
+            .properties
+            .insert(InputQuirk::PROP_POINTING_STICK);
+        assert!(classify(&pointing_stick).pointing_stick);
+    }
+
+    #[test]
+    fn keyboards() {
+        // A keyboard with a few joystick buttons is not a joystick.
+        let mut keys = (1..32).map(KeyCode::new).collect::<Vec<_>>();
+        keys.extend([
+            KeyCode::Key(Key::LEFTCTRL),
+            KeyCode::Key(Key::CAPSLOCK),
+            KeyCode::Key(Key::NUMLOCK),
+            KeyCode::Key(Key::MUTE),
+            KeyCode::Button(Button::TRIGGER),
+            KeyCode::Button(Button::THUMB),
+        ]);
+
+        let keyboard = capabilities(&[EventType::KEY], &keys, &[]);
+        assert_eq!(
+            classify(&keyboard).properties().collect::<Vec<_>>(),
+            ["ID_INPUT_KEY", "ID_INPUT_KEYBOARD"]
+        );
+
+        let media_keys = capabilities(
+            &[EventType::KEY],
+            &[Key::VOLUMEUP.into(), Key::VOLUMEDOWN.into()],
+            &[],
+        );
+        assert_eq!(
+            classify(&media_keys),
+            DeviceClasses {
+                key: true,
+                ..Default::default()
+            }
+        );
+
+        let mut wheel = capabilities(&[EventType::REL], &[], &[]);
+        wheel.relative_axes.insert(RelativeAxis::WHEEL);
+        assert!(classify(&wheel).key);
+    }
+}
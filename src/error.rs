@@ -0,0 +1,132 @@
+use core::fmt;
+
+use crate::{InputEvent, KeyCode, KeyValue};
+
+/// An error returned when a code could not be found from its name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseCodeError {
+    category: &'static str,
+}
+
+impl ParseCodeError {
+    pub(crate) const fn new(category: &'static str) -> Self {
+        Self { category }
+    }
+
+    /// The name of the type the name was parsed as, for example `Key`.
+    pub const fn category(&self) -> &'static str {
+        self.category
+    }
+}
+
+impl fmt::Display for ParseCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} name", self.category)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCodeError {}
+
+/// An error returned when a device block of `/proc/bus/input/devices` could not be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseDevicesError {
+    line: usize,
+    reason: &'static str,
+}
+
+impl ParseDevicesError {
+    pub(crate) const fn new(line: usize, reason: &'static str) -> Self {
+        Self { line, reason }
+    }
+
+    /// The number of the line which could not be parsed, starting from 1.
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// A description of what was wrong with the line.
+    pub const fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl fmt::Display for ParseDevicesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseDevicesError {}
+
+/// An error returned when decoding or encoding a stream of input events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventStreamError {
+    /// The number of bytes is not a multiple of the size of an input event.
+    InvalidLength(usize),
+
+    /// A frame had more events than the capacity of the frame buffer, so the frame was discarded.
+    FrameOverflow(usize),
+
+    /// The buffer has no space for another event.
+    BufferFull,
+}
+
+impl fmt::Display for EventStreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(len) => write!(
+                f,
+                "{} bytes is not a multiple of the input event size ({} bytes)",
+                len,
+                InputEvent::SIZE
+            ),
+            Self::FrameOverflow(capacity) => {
+                write!(
+                    f,
+                    "frame is larger than the capacity of {} events",
+                    capacity
+                )
+            }
+            Self::BufferFull => f.write_str("buffer is full"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EventStreamError {}
+
+/// An error returned when a key event does not match the keys known to be pressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyStateError {
+    /// The code is larger than `KEY_MAX`.
+    InvalidCode(KeyCode),
+
+    /// The value of the event is not 0, 1 or 2.
+    InvalidValue(KeyCode, i32),
+
+    /// The key was released or repeated while not pressed, or pressed while already pressed.
+    Inconsistent(KeyCode, KeyValue),
+}
+
+impl fmt::Display for KeyStateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCode(code) => write!(f, "invalid key code {}", code),
+            Self::InvalidValue(code, value) => write!(f, "invalid value {} for {}", value, code),
+            Self::Inconsistent(code, KeyValue::Pressed) => {
+                write!(f, "{} pressed while already pressed", code)
+            }
+            Self::Inconsistent(code, KeyValue::Released) => {
+                write!(f, "{} released while not pressed", code)
+            }
+            Self::Inconsistent(code, KeyValue::Repeat) => {
+                write!(f, "{} repeated while not pressed", code)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KeyStateError {}
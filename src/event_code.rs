@@ -0,0 +1,329 @@
+use core::fmt;
+
+use crate::{
+    AbsoluteAxis, AutoRepeat, Button, EventType, ForceFeedback, Key, KeyCode, Led, MiscEvent,
+    RelativeAxis, Sound, SwitchEvent, SynchronizationEvent,
+};
+
+/// An event code tagged by the type of the event.
+///
+/// The meaning of the code of an event depends on the type of the event. This type pairs the two so the code
+/// can be matched on without dispatching on the event type first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventCode {
+    /// [`EventType::SYN`]
+    Syn(SynchronizationEvent),
+
+    /// [`EventType::KEY`]
+    Key(KeyCode),
+
+    /// [`EventType::REL`]
+    Rel(RelativeAxis),
+
+    /// [`EventType::ABS`]
+    Abs(AbsoluteAxis),
+
+    /// [`EventType::MSC`]
+    Msc(MiscEvent),
+
+    /// [`EventType::SW`]
+    Sw(SwitchEvent),
+
+    /// [`EventType::LED`]
+    Led(Led),
+
+    /// [`EventType::SND`]
+    Snd(Sound),
+
+    /// [`EventType::REP`]
+    Rep(AutoRepeat),
+
+    /// [`EventType::FF`]
+    Ff(ForceFeedback),
+
+    /// An event type which has no code type.
+    Unknown {
+        /// The type of the event.
+        event_type: EventType,
+
+        /// The code of the event.
+        code: u16,
+    },
+}
+
+impl EventCode {
+    /// Creates an event code from the `type` and `code` fields of an input event.
+    pub const fn from_raw(event_type: u16, code: u16) -> Self {
+        let value = code as u32;
+
+        match EventType::new(event_type as u32) {
+            EventType::SYN => Self::Syn(SynchronizationEvent::new(value)),
+            EventType::KEY => Self::Key(KeyCode::new(value)),
+            EventType::REL => Self::Rel(RelativeAxis::new(value)),
+            EventType::ABS => Self::Abs(AbsoluteAxis::new(value)),
+            EventType::MSC => Self::Msc(MiscEvent::new(value)),
+            EventType::SW => Self::Sw(SwitchEvent::new(value)),
+            EventType::LED => Self::Led(Led::new(value)),
+            EventType::SND => Self::Snd(Sound::new(value)),
+            EventType::REP => Self::Rep(AutoRepeat::new(value)),
+            EventType::FF => Self::Ff(ForceFeedback::new(value)),
+            event_type => Self::Unknown { event_type, code },
+        }
+    }
+
+    /// Returns the `type` and `code` fields of an input event with this code.
+    ///
+    /// Codes are truncated to 16 bits, since that is the size of the code field of an input event.
+    pub const fn into_raw(self) -> (u16, u16) {
+        (self.event_type().into_inner() as u16, self.code())
+    }
+
+    /// Returns the type of event the code belongs to.
+    pub const fn event_type(self) -> EventType {
+        match self {
+            Self::Syn(_) => EventType::SYN,
+            Self::Key(_) => EventType::KEY,
+            Self::Rel(_) => EventType::REL,
+            Self::Abs(_) => EventType::ABS,
+            Self::Msc(_) => EventType::MSC,
+            Self::Sw(_) => EventType::SW,
+            Self::Led(_) => EventType::LED,
+            Self::Snd(_) => EventType::SND,
+            Self::Rep(_) => EventType::REP,
+            Self::Ff(_) => EventType::FF,
+            Self::Unknown { event_type, .. } => event_type,
+        }
+    }
+
+    /// Returns the raw value of the code.
+    pub const fn code(self) -> u16 {
+        let value = match self {
+            Self::Syn(code) => code.into_inner(),
+            Self::Key(code) => code.into_inner(),
+            Self::Rel(code) => code.into_inner(),
+            Self::Abs(code) => code.into_inner(),
+            Self::Msc(code) => code.into_inner(),
+            Self::Sw(code) => code.into_inner(),
+            Self::Led(code) => code.into_inner(),
+            Self::Snd(code) => code.into_inner(),
+            Self::Rep(code) => code.into_inner(),
+            Self::Ff(code) => code.into_inner(),
+            Self::Unknown { code, .. } => return code,
+        };
+
+        value as u16
+    }
+}
+
+impl From<SynchronizationEvent> for EventCode {
+    fn from(code: SynchronizationEvent) -> Self {
+        Self::Syn(code)
+    }
+}
+
+impl From<KeyCode> for EventCode {
+    fn from(code: KeyCode) -> Self {
+        Self::Key(code)
+    }
+}
+
+impl From<Key> for EventCode {
+    fn from(code: Key) -> Self {
+        Self::Key(code.into())
+    }
+}
+
+impl From<Button> for EventCode {
+    fn from(code: Button) -> Self {
+        Self::Key(code.into())
+    }
+}
+
+impl From<RelativeAxis> for EventCode {
+    fn from(code: RelativeAxis) -> Self {
+        Self::Rel(code)
+    }
+}
+
+impl From<AbsoluteAxis> for EventCode {
+    fn from(code: AbsoluteAxis) -> Self {
+        Self::Abs(code)
+    }
+}
+
+impl From<MiscEvent> for EventCode {
+    fn from(code: MiscEvent) -> Self {
+        Self::Msc(code)
+    }
+}
+
+impl From<SwitchEvent> for EventCode {
+    fn from(code: SwitchEvent) -> Self {
+        Self::Sw(code)
+    }
+}
+
+impl From<Led> for EventCode {
+    fn from(code: Led) -> Self {
+        Self::Led(code)
+    }
+}
+
+impl From<Sound> for EventCode {
+    fn from(code: Sound) -> Self {
+        Self::Snd(code)
+    }
+}
+
+impl From<AutoRepeat> for EventCode {
+    fn from(code: AutoRepeat) -> Self {
+        Self::Rep(code)
+    }
+}
+
+impl From<ForceFeedback> for EventCode {
+    fn from(code: ForceFeedback) -> Self {
+        Self::Ff(code)
+    }
+}
+
+impl EventCode {
+    fn write(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        write!(f, "{} / ", self.event_type())?;
+
+        match self {
+            Self::Syn(code) => write!(f, "{}", code),
+            Self::Key(code) => write!(f, "{}", code),
+            Self::Rel(code) => write!(f, "{}", code),
+            Self::Abs(code) => write!(f, "{}", code),
+            Self::Msc(code) => write!(f, "{}", code),
+            Self::Sw(code) => write!(f, "{}", code),
+            Self::Led(code) => write!(f, "{}", code),
+            Self::Snd(code) => write!(f, "{}", code),
+            Self::Rep(code) => write!(f, "{}", code),
+            Self::Ff(code) => write!(f, "{}", code),
+            Self::Unknown { code, .. } => write!(f, "{:#x}", code),
+        }
+    }
+}
+
+impl fmt::Display for EventCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.width().is_none() && f.precision().is_none() {
+            return self.write(f);
+        }
+
+        // The width and alignment apply to the whole string, so it is formatted into a buffer first.
+        let mut buffer = Buffer {
+            bytes: [0; 64],
+            len: 0,
+        };
+
+        match self.write(&mut buffer) {
+            Ok(()) => f.pad(buffer.as_str()),
+            Err(_) => self.write(f),
+        }
+    }
+}
+
+/// A buffer for formatting a short string without allocating.
+struct Buffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Buffer {
+    fn as_str(&self) -> &str {
+        // Only whole strings are written to the buffer.
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl fmt::Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{format, string::ToString};
+
+    use crate::{
+        AbsoluteAxis, Button, EventCode, EventType, ForceFeedback, Key, KeyCode,
+        SynchronizationEvent,
+    };
+
+    #[test]
+    fn from_raw() {
+        assert_eq!(
+            EventCode::from_raw(0x01, 30),
+            EventCode::Key(KeyCode::Key(Key::A))
+        );
+        assert_eq!(
+            EventCode::from_raw(0x01, 0x110),
+            EventCode::Key(KeyCode::Button(Button::LEFT))
+        );
+        assert_eq!(
+            EventCode::from_raw(0x03, 0x35),
+            EventCode::Abs(AbsoluteAxis::MT_POSITION_X)
+        );
+        assert_eq!(
+            EventCode::from_raw(0x15, 0x50),
+            EventCode::Ff(ForceFeedback::RUMBLE)
+        );
+        assert_eq!(
+            EventCode::from_raw(0x16, 0x01),
+            EventCode::Unknown {
+                event_type: EventType::PWR,
+                code: 0x01
+            }
+        );
+    }
+
+    #[test]
+    fn raw_round_trip() {
+        for event_type in 0..EventType::COUNT as u16 {
+            for code in [0, 1, 0x35, 0x110, 0x2ff] {
+                let event_code = EventCode::from_raw(event_type, code);
+                assert_eq!(event_code.into_raw(), (event_type, code));
+                assert_eq!(event_code.event_type(), EventType::new(event_type as u32));
+            }
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(EventCode::from(Key::A).to_string(), "EV_KEY / KEY_A");
+        assert_eq!(
+            EventCode::from_raw(0x01, 0x110).to_string(),
+            "EV_KEY / BTN_LEFT"
+        );
+        assert_eq!(
+            EventCode::from(SynchronizationEvent::REPORT).to_string(),
+            "EV_SYN / SYN_REPORT"
+        );
+        assert_eq!(
+            EventCode::from_raw(0x15, 0x50).to_string(),
+            "EV_FF / FF_RUMBLE"
+        );
+        assert_eq!(EventCode::from_raw(0x16, 0x01).to_string(), "EV_PWR / 0x1");
+
+        // The width and alignment apply to the whole code.
+        assert_eq!(
+            format!("[{:<16}]", EventCode::from(Key::A)),
+            "[EV_KEY / KEY_A  ]"
+        );
+        assert_eq!(
+            format!("[{:>14}]", EventCode::from_raw(0x16, 0x01)),
+            "[  EV_PWR / 0x1]"
+        );
+        assert_eq!(format!("{:.6}", EventCode::from(Key::A)), "EV_KEY");
+    }
+}
@@ -0,0 +1,480 @@
+//! Decoding and encoding of the stream of input events read from evdev devices and written to uinput devices.
+//!
+//! A read from an evdev device returns whole `struct input_event` records, which are decoded with
+//! [`decode_events`]. When the bytes come from another source, such as a pipe or a recording, a read may end in
+//! the middle of an event, and [`EventDecoder`] keeps the partial event until the rest of it is read.
+//!
+//! The kernel groups events into frames which end with a `SYN_REPORT` event, and a frame describes the state of
+//! the device at one point in time. [`FrameBuffer`] collects the events of a frame.
+
+use core::{fmt, slice::ChunksExact};
+
+use crate::{EventCode, EventStreamError, EventTime, InputEvent, SynchronizationEvent};
+
+/// Decodes the events read from an evdev device.
+///
+/// Returns an error if the bytes do not contain a whole number of events.
+pub fn decode_events(bytes: &[u8]) -> Result<Events<'_>, EventStreamError> {
+    if bytes.len() % InputEvent::SIZE != 0 {
+        return Err(EventStreamError::InvalidLength(bytes.len()));
+    }
+
+    Ok(Events(bytes.chunks_exact(InputEvent::SIZE)))
+}
+
+/// An iterator over the events decoded from bytes, returned by [`decode_events`].
+#[derive(Debug, Clone)]
+pub struct Events<'a>(ChunksExact<'a, u8>);
+
+impl Iterator for Events<'_> {
+    type Item = InputEvent;
+
+    fn next(&mut self) -> Option<InputEvent> {
+        self.0.next().map(read_event)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Events<'_> {
+    fn next_back(&mut self) -> Option<InputEvent> {
+        self.0.next_back().map(read_event)
+    }
+}
+
+impl ExactSizeIterator for Events<'_> {}
+
+/// Decodes a stream of events which may be split at any byte.
+///
+/// The bytes of an event which is split between two reads are kept by the decoder until the rest of the event is
+/// decoded.
+#[derive(Clone)]
+pub struct EventDecoder {
+    partial: [u8; InputEvent::SIZE],
+    partial_len: usize,
+}
+
+impl EventDecoder {
+    /// Creates a decoder.
+    pub const fn new() -> Self {
+        Self {
+            partial: [0; InputEvent::SIZE],
+            partial_len: 0,
+        }
+    }
+
+    /// Decodes the bytes which follow the bytes previously decoded.
+    ///
+    /// The bytes at the end which do not form a whole event are kept by the decoder when the returned iterator is
+    /// dropped. Any whole events which were not taken from the iterator are discarded, so the iterator should be
+    /// consumed before decoding the next bytes.
+    #[must_use = "the events which are not taken from the iterator are discarded"]
+    pub fn decode<'a>(&'a mut self, bytes: &'a [u8]) -> DecodeEvents<'a> {
+        DecodeEvents {
+            decoder: self,
+            bytes,
+        }
+    }
+
+    /// The number of bytes of a partial event kept by the decoder.
+    pub const fn pending(&self) -> usize {
+        self.partial_len
+    }
+
+    /// Ends the stream, returning an error if the stream ended in the middle of an event.
+    ///
+    /// The decoder may be used for another stream afterwards.
+    pub fn finish(&mut self) -> Result<(), EventStreamError> {
+        let pending = self.partial_len;
+        self.partial_len = 0;
+
+        match pending {
+            0 => Ok(()),
+            len => Err(EventStreamError::InvalidLength(len)),
+        }
+    }
+}
+
+impl Default for EventDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for EventDecoder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventDecoder")
+            .field("pending", &self.partial_len)
+            .finish()
+    }
+}
+
+/// An iterator over the events decoded by an [`EventDecoder`].
+///
+/// Dropping the iterator keeps the start of a split event in the decoder, and discards the whole events which
+/// were not taken from the iterator.
+#[derive(Debug)]
+#[must_use = "the events which are not taken from the iterator are discarded"]
+pub struct DecodeEvents<'a> {
+    decoder: &'a mut EventDecoder,
+    bytes: &'a [u8],
+}
+
+impl Iterator for DecodeEvents<'_> {
+    type Item = InputEvent;
+
+    fn next(&mut self) -> Option<InputEvent> {
+        let decoder = &mut *self.decoder;
+
+        if decoder.partial_len > 0 {
+            // Complete the event split by the previous read first.
+            let start = decoder.partial_len;
+            let len = (InputEvent::SIZE - start).min(self.bytes.len());
+            let (head, rest) = self.bytes.split_at(len);
+
+            decoder.partial[start..start + len].copy_from_slice(head);
+            decoder.partial_len += len;
+            self.bytes = rest;
+
+            if decoder.partial_len < InputEvent::SIZE {
+                return None;
+            }
+
+            decoder.partial_len = 0;
+            return Some(InputEvent::from_bytes(decoder.partial));
+        }
+
+        if self.bytes.len() < InputEvent::SIZE {
+            return None;
+        }
+
+        let (head, rest) = self.bytes.split_at(InputEvent::SIZE);
+        self.bytes = rest;
+        Some(read_event(head))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.decoder.partial_len + self.bytes.len()) / InputEvent::SIZE;
+        (len, Some(len))
+    }
+}
+
+impl Drop for DecodeEvents<'_> {
+    fn drop(&mut self) {
+        let decoder = &mut *self.decoder;
+
+        // Complete the event split by the previous read, so the bytes kept afterwards start at an event. The
+        // completed event was not taken, so it is discarded like the other whole events.
+        if decoder.partial_len > 0 {
+            let start = decoder.partial_len;
+            let len = (InputEvent::SIZE - start).min(self.bytes.len());
+            let (head, rest) = self.bytes.split_at(len);
+
+            decoder.partial[start..start + len].copy_from_slice(head);
+            decoder.partial_len += len;
+            self.bytes = rest;
+
+            if decoder.partial_len < InputEvent::SIZE {
+                return;
+            }
+        }
+
+        // Keep the start of a split event.
+        let tail = &self.bytes[self.bytes.len() - self.bytes.len() % InputEvent::SIZE..];
+
+        decoder.partial[..tail.len()].copy_from_slice(tail);
+        decoder.partial_len = tail.len();
+    }
+}
+
+/// Collects events into frames which end with a `SYN_REPORT` event.
+///
+/// The buffer holds up to `N` events, including the `SYN_REPORT` event.
+#[derive(Clone)]
+pub struct FrameBuffer<const N: usize> {
+    events: [InputEvent; N],
+    len: usize,
+    overflowed: bool,
+    complete: bool,
+}
+
+impl<const N: usize> FrameBuffer<N> {
+    /// Creates an empty frame buffer.
+    pub const fn new() -> Self {
+        Self {
+            events: [InputEvent::from_bytes([0; InputEvent::SIZE]); N],
+            len: 0,
+            overflowed: false,
+            complete: false,
+        }
+    }
+
+    /// Adds an event to the current frame.
+    ///
+    /// Returns the events of the frame, ending with the `SYN_REPORT` event, when the event ends the frame. If the
+    /// frame had more than `N` events, the frame is discarded and an error is returned instead.
+    pub fn push(&mut self, event: InputEvent) -> Option<Result<&[InputEvent], EventStreamError>> {
+        if self.complete {
+            self.clear();
+        }
+
+        match self.events.get_mut(self.len) {
+            Some(slot) => {
+                *slot = event;
+                self.len += 1;
+            }
+
+            None => self.overflowed = true,
+        }
+
+        if !event.is_report() {
+            return None;
+        }
+
+        self.complete = true;
+
+        if self.overflowed {
+            self.len = 0;
+            return Some(Err(EventStreamError::FrameOverflow(N)));
+        }
+
+        Some(Ok(&self.events[..self.len]))
+    }
+
+    /// The events of the current frame, or of the last frame if it was just returned by [`FrameBuffer::push`].
+    pub fn events(&self) -> &[InputEvent] {
+        &self.events[..self.len]
+    }
+
+    /// Discards the events of the current frame.
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.overflowed = false;
+        self.complete = false;
+    }
+}
+
+impl<const N: usize> Default for FrameBuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> fmt::Debug for FrameBuffer<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrameBuffer")
+            .field("events", &self.events())
+            .field("overflowed", &self.overflowed)
+            .finish()
+    }
+}
+
+/// Encodes events into a buffer, to be written to a uinput device.
+#[derive(Debug)]
+pub struct EventEncoder<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> EventEncoder<'a> {
+    /// Creates an encoder which writes to the buffer.
+    pub fn new(buffer: &'a mut [u8]) -> Self {
+        Self { buffer, len: 0 }
+    }
+
+    /// Writes an event to the buffer.
+    ///
+    /// Returns an error if the buffer has no space for the event.
+    pub fn push(&mut self, event: InputEvent) -> Result<(), EventStreamError> {
+        let target = self
+            .buffer
+            .get_mut(self.len..self.len + InputEvent::SIZE)
+            .ok_or(EventStreamError::BufferFull)?;
+
+        target.copy_from_slice(&event.to_bytes());
+        self.len += InputEvent::SIZE;
+        Ok(())
+    }
+
+    /// Writes a `SYN_REPORT` event, which ends the frame of the events written before it.
+    pub fn report(&mut self, time: EventTime) -> Result<(), EventStreamError> {
+        self.push(InputEvent::new(
+            time,
+            EventCode::Syn(SynchronizationEvent::REPORT),
+            0,
+        ))
+    }
+
+    /// The bytes of the events written so far.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer[..self.len]
+    }
+
+    /// The number of bytes written so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether no events have been written.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Discards the events written so far, so the buffer may be reused.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+fn read_event(bytes: &[u8]) -> InputEvent {
+    let mut event = [0; InputEvent::SIZE];
+    event.copy_from_slice(bytes);
+    InputEvent::from_bytes(event)
+}
+
+#[cfg(test)]
+mod test {
+    use std::vec::Vec;
+
+    use crate::{
+        decode_events, EventCode, EventDecoder, EventEncoder, EventStreamError, EventTime,
+        FrameBuffer, InputEvent, Key, RelativeAxis, SynchronizationEvent,
+    };
+
+    fn events() -> [InputEvent; 5] {
+        let time = EventTime::new(100, 250);
+
+        [
+            InputEvent::new(time, RelativeAxis::X.into(), 3),
+            InputEvent::new(time, RelativeAxis::Y.into(), -2),
+            InputEvent::new(time, EventCode::Syn(SynchronizationEvent::REPORT), 0),
+            InputEvent::new(time, Key::A.into(), 1),
+            InputEvent::new(time, EventCode::Syn(SynchronizationEvent::REPORT), 0),
+        ]
+    }
+
+    fn encode(events: &[InputEvent]) -> Vec<u8> {
+        let mut buffer = [0; 8 * InputEvent::SIZE];
+        let mut encoder = EventEncoder::new(&mut buffer);
+
+        for event in events {
+            encoder.push(*event).unwrap();
+        }
+
+        encoder.as_bytes().to_vec()
+    }
+
+    #[test]
+    fn round_trip() {
+        let events = events();
+        let bytes = encode(&events);
+        assert_eq!(bytes.len(), 5 * InputEvent::SIZE);
+
+        let decoded = decode_events(&bytes).unwrap();
+        assert_eq!(decoded.len(), 5);
+        assert_eq!(decoded.collect::<Vec<_>>(), events);
+
+        assert_eq!(
+            decode_events(&bytes[1..]).unwrap_err(),
+            EventStreamError::InvalidLength(5 * InputEvent::SIZE - 1)
+        );
+    }
+
+    #[test]
+    fn partial_reads() {
+        let events = events();
+        let bytes = encode(&events);
+
+        // Every way of splitting the stream in three reads decodes the same events.
+        for first in 0..bytes.len() {
+            for second in first..bytes.len() {
+                let mut decoder = EventDecoder::new();
+                let mut decoded = Vec::new();
+
+                for chunk in [&bytes[..first], &bytes[first..second], &bytes[second..]] {
+                    decoded.extend(decoder.decode(chunk));
+                }
+
+                assert_eq!(decoded, events, "split at {} and {}", first, second);
+                assert_eq!(decoder.finish(), Ok(()));
+            }
+        }
+
+        let mut decoder = EventDecoder::new();
+        assert_eq!(decoder.decode(&bytes[..InputEvent::SIZE + 3]).count(), 1);
+        assert_eq!(decoder.pending(), 3);
+        assert_eq!(decoder.finish(), Err(EventStreamError::InvalidLength(3)));
+        assert_eq!(decoder.pending(), 0);
+
+        // Dropping the iterator before completing a split event keeps the stream aligned.
+        let mut decoder = EventDecoder::new();
+        assert_eq!(decoder.decode(&bytes[..10]).count(), 0);
+        drop(decoder.decode(&bytes[10..InputEvent::SIZE + 30]));
+        assert_eq!(
+            decoder.pending(),
+            (InputEvent::SIZE + 30) % InputEvent::SIZE
+        );
+        assert_eq!(
+            decoder
+                .decode(&bytes[InputEvent::SIZE + 30..])
+                .collect::<Vec<_>>(),
+            events[2..]
+        );
+        assert_eq!(decoder.finish(), Ok(()));
+
+        // The whole events which are not taken from the iterator are discarded.
+        let mut decoder = EventDecoder::new();
+        let mut decoded = decoder.decode(&bytes[..2 * InputEvent::SIZE + 3]);
+        assert_eq!(decoded.next(), Some(events[0]));
+        drop(decoded);
+        assert_eq!(decoder.pending(), 3);
+    }
+
+    #[test]
+    fn frames() {
+        let events = events();
+        let mut frames = FrameBuffer::<4>::new();
+
+        assert!(frames.push(events[0]).is_none());
+        assert!(frames.push(events[1]).is_none());
+        assert_eq!(frames.push(events[2]), Some(Ok(&events[..3])));
+        assert_eq!(frames.push(events[3]), None);
+        assert_eq!(frames.events(), &events[3..4]);
+        assert_eq!(frames.push(events[4]), Some(Ok(&events[3..])));
+
+        // A frame which does not fit is discarded, and the next frame is collected as usual.
+        let mut frames = FrameBuffer::<2>::new();
+        assert!(frames.push(events[0]).is_none());
+        assert!(frames.push(events[1]).is_none());
+        assert_eq!(
+            frames.push(events[2]),
+            Some(Err(EventStreamError::FrameOverflow(2)))
+        );
+        assert!(frames.push(events[3]).is_none());
+        assert_eq!(frames.push(events[4]), Some(Ok(&events[3..])));
+    }
+
+    #[test]
+    fn encoder() {
+        let mut buffer = [0; 2 * InputEvent::SIZE];
+        let mut encoder = EventEncoder::new(&mut buffer);
+        let time = EventTime::default();
+
+        encoder
+            .push(InputEvent::new(time, Key::A.into(), 1))
+            .unwrap();
+        encoder.report(time).unwrap();
+        assert_eq!(encoder.push(events()[0]), Err(EventStreamError::BufferFull));
+        assert_eq!(encoder.len(), 2 * InputEvent::SIZE);
+
+        let decoded = decode_events(encoder.as_bytes())
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert!(decoded[1].is_report());
+
+        encoder.clear();
+        assert!(encoder.is_empty());
+    }
+}
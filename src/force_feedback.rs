@@ -0,0 +1,535 @@
+//! Force feedback effects, matching `struct ff_effect` of the kernel.
+//!
+//! Durations are in milliseconds. Values above 32767 ms should not be used and have unspecified results.
+
+use core::ptr;
+
+use crate::{sys, ForceFeedback};
+
+/// The scheduling of a force feedback effect, matching `struct ff_replay` of the kernel.
+#[doc(alias = "ff_replay")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FfReplay {
+    /// The duration of the effect.
+    pub length: u16,
+
+    /// The delay before the effect starts playing.
+    pub delay: u16,
+}
+
+/// What triggers a force feedback effect, matching `struct ff_trigger` of the kernel.
+#[doc(alias = "ff_trigger")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FfTrigger {
+    /// The code of the button which triggers the effect.
+    pub button: u16,
+
+    /// How soon the effect may be triggered again.
+    pub interval: u16,
+}
+
+/// The envelope of a force feedback effect, matching `struct ff_envelope` of the kernel.
+///
+/// The levels are absolute values from `0x0000` to `0x7fff`, which the kernel applies in the direction of the
+/// level of the effect.
+#[doc(alias = "ff_envelope")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FfEnvelope {
+    /// The duration of the attack.
+    pub attack_length: u16,
+
+    /// The level at the beginning of the attack.
+    pub attack_level: u16,
+
+    /// The duration of the fade.
+    pub fade_length: u16,
+
+    /// The level at the end of the fade.
+    pub fade_level: u16,
+}
+
+/// The parameters of a condition effect along one axis, matching `struct ff_condition_effect` of the kernel.
+#[doc(alias = "ff_condition_effect")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FfCondition {
+    /// The largest level when the axis is moved all the way to the right.
+    pub right_saturation: u16,
+
+    /// The largest level when the axis is moved all the way to the left.
+    pub left_saturation: u16,
+
+    /// How fast the force grows when the axis is moved to the right.
+    pub right_coeff: i16,
+
+    /// How fast the force grows when the axis is moved to the left.
+    pub left_coeff: i16,
+
+    /// The size of the dead zone, where no force is produced.
+    pub deadband: u16,
+
+    /// The position of the dead zone.
+    pub center: i16,
+}
+
+/// The parameters of a periodic effect, matching `struct ff_periodic_effect` of the kernel.
+///
+/// Custom waveforms are not supported by any driver, so the samples of [`ForceFeedback::CUSTOM`] are not
+/// included.
+#[doc(alias = "ff_periodic_effect")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FfPeriodic {
+    /// The waveform of the effect, such as [`ForceFeedback::SINE`].
+    pub waveform: ForceFeedback,
+
+    /// The period of the wave.
+    pub period: u16,
+
+    /// The peak value of the wave.
+    pub magnitude: i16,
+
+    /// The mean value of the wave.
+    pub offset: i16,
+
+    /// The horizontal shift of the wave.
+    pub phase: u16,
+
+    /// The envelope of the effect.
+    pub envelope: FfEnvelope,
+}
+
+/// The kind of a force feedback effect and its parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FfEffectKind {
+    /// [`ForceFeedback::RUMBLE`]
+    Rumble {
+        /// The magnitude of the heavy motor.
+        strong_magnitude: u16,
+
+        /// The magnitude of the light motor.
+        weak_magnitude: u16,
+    },
+
+    /// [`ForceFeedback::PERIODIC`]
+    Periodic(FfPeriodic),
+
+    /// [`ForceFeedback::CONSTANT`]
+    Constant {
+        /// The strength of the effect, which may be negative.
+        level: i16,
+
+        /// The envelope of the effect.
+        envelope: FfEnvelope,
+    },
+
+    /// [`ForceFeedback::SPRING`], with the condition of each axis.
+    Spring([FfCondition; 2]),
+
+    /// [`ForceFeedback::FRICTION`], with the condition of each axis.
+    Friction([FfCondition; 2]),
+
+    /// [`ForceFeedback::DAMPER`], with the condition of each axis.
+    Damper([FfCondition; 2]),
+
+    /// [`ForceFeedback::INERTIA`], with the condition of each axis.
+    Inertia([FfCondition; 2]),
+
+    /// [`ForceFeedback::RAMP`]
+    Ramp {
+        /// The strength at the beginning of the effect, which may be negative.
+        start_level: i16,
+
+        /// The strength at the end of the effect, which may be negative.
+        end_level: i16,
+
+        /// The envelope of the effect.
+        envelope: FfEnvelope,
+    },
+}
+
+impl FfEffectKind {
+    /// Returns the type of the effect, such as [`ForceFeedback::RUMBLE`].
+    pub const fn effect_type(&self) -> ForceFeedback {
+        match self {
+            Self::Rumble { .. } => ForceFeedback::RUMBLE,
+            Self::Periodic(_) => ForceFeedback::PERIODIC,
+            Self::Constant { .. } => ForceFeedback::CONSTANT,
+            Self::Spring(_) => ForceFeedback::SPRING,
+            Self::Friction(_) => ForceFeedback::FRICTION,
+            Self::Damper(_) => ForceFeedback::DAMPER,
+            Self::Inertia(_) => ForceFeedback::INERTIA,
+            Self::Ramp { .. } => ForceFeedback::RAMP,
+        }
+    }
+}
+
+/// A force feedback effect, matching `struct ff_effect` of the kernel.
+///
+/// This is converted to [`sys::ff_effect`] to upload the effect to a device, and from the effects filled in by the
+/// kernel with [`FfEffect::from_raw`].
+#[doc(alias = "ff_effect")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FfEffect {
+    /// The id of the effect, which the kernel assigns when the effect is uploaded.
+    ///
+    /// This is [`NEW_ID`](Self::NEW_ID) for an effect which has not been uploaded yet.
+    pub id: i16,
+
+    /// The direction of the effect.
+    ///
+    /// `0x0000` is down, `0x4000` is left, `0x8000` is up and `0xc000` is right.
+    pub direction: u16,
+
+    /// What triggers the effect.
+    pub trigger: FfTrigger,
+
+    /// The scheduling of the effect.
+    pub replay: FfReplay,
+
+    /// The kind of the effect and its parameters.
+    pub kind: FfEffectKind,
+}
+
+impl FfEffect {
+    /// The id of an effect which has not been uploaded yet.
+    pub const NEW_ID: i16 = -1;
+
+    /// Creates an effect which has not been uploaded yet.
+    pub const fn new(kind: FfEffectKind) -> Self {
+        Self {
+            id: Self::NEW_ID,
+            direction: 0,
+            trigger: FfTrigger {
+                button: 0,
+                interval: 0,
+            },
+            replay: FfReplay {
+                length: 0,
+                delay: 0,
+            },
+            kind,
+        }
+    }
+
+    /// Returns the type of the effect, such as [`ForceFeedback::RUMBLE`].
+    pub const fn effect_type(&self) -> ForceFeedback {
+        self.kind.effect_type()
+    }
+}
+
+impl From<FfReplay> for sys::ff_replay {
+    fn from(replay: FfReplay) -> Self {
+        Self {
+            length: replay.length,
+            delay: replay.delay,
+        }
+    }
+}
+
+impl From<sys::ff_replay> for FfReplay {
+    fn from(replay: sys::ff_replay) -> Self {
+        Self {
+            length: replay.length,
+            delay: replay.delay,
+        }
+    }
+}
+
+impl From<FfTrigger> for sys::ff_trigger {
+    fn from(trigger: FfTrigger) -> Self {
+        Self {
+            button: trigger.button,
+            interval: trigger.interval,
+        }
+    }
+}
+
+impl From<sys::ff_trigger> for FfTrigger {
+    fn from(trigger: sys::ff_trigger) -> Self {
+        Self {
+            button: trigger.button,
+            interval: trigger.interval,
+        }
+    }
+}
+
+impl From<FfEnvelope> for sys::ff_envelope {
+    fn from(envelope: FfEnvelope) -> Self {
+        Self {
+            attack_length: envelope.attack_length,
+            attack_level: envelope.attack_level,
+            fade_length: envelope.fade_length,
+            fade_level: envelope.fade_level,
+        }
+    }
+}
+
+impl From<sys::ff_envelope> for FfEnvelope {
+    fn from(envelope: sys::ff_envelope) -> Self {
+        Self {
+            attack_length: envelope.attack_length,
+            attack_level: envelope.attack_level,
+            fade_length: envelope.fade_length,
+            fade_level: envelope.fade_level,
+        }
+    }
+}
+
+impl From<FfCondition> for sys::ff_condition_effect {
+    fn from(condition: FfCondition) -> Self {
+        Self {
+            right_saturation: condition.right_saturation,
+            left_saturation: condition.left_saturation,
+            right_coeff: condition.right_coeff,
+            left_coeff: condition.left_coeff,
+            deadband: condition.deadband,
+            center: condition.center,
+        }
+    }
+}
+
+impl From<sys::ff_condition_effect> for FfCondition {
+    fn from(condition: sys::ff_condition_effect) -> Self {
+        Self {
+            right_saturation: condition.right_saturation,
+            left_saturation: condition.left_saturation,
+            right_coeff: condition.right_coeff,
+            left_coeff: condition.left_coeff,
+            deadband: condition.deadband,
+            center: condition.center,
+        }
+    }
+}
+
+impl From<FfEffect> for sys::ff_effect {
+    fn from(effect: FfEffect) -> Self {
+        let mut raw = sys::ff_effect {
+            type_: effect.effect_type().into_inner() as u16,
+            id: effect.id,
+            direction: effect.direction,
+            trigger: effect.trigger.into(),
+            replay: effect.replay.into(),
+            ..Default::default()
+        };
+
+        match effect.kind {
+            FfEffectKind::Rumble {
+                strong_magnitude,
+                weak_magnitude,
+            } => {
+                raw.u.rumble = sys::ff_rumble_effect {
+                    strong_magnitude,
+                    weak_magnitude,
+                }
+            }
+
+            FfEffectKind::Periodic(periodic) => {
+                raw.u.periodic = sys::ff_periodic_effect {
+                    waveform: periodic.waveform.into_inner() as u16,
+                    period: periodic.period,
+                    magnitude: periodic.magnitude,
+                    offset: periodic.offset,
+                    phase: periodic.phase,
+                    envelope: periodic.envelope.into(),
+                    custom_len: 0,
+                    custom_data: ptr::null_mut(),
+                }
+            }
+
+            FfEffectKind::Constant { level, envelope } => {
+                raw.u.constant = sys::ff_constant_effect {
+                    level,
+                    envelope: envelope.into(),
+                }
+            }
+
+            FfEffectKind::Spring(conditions)
+            | FfEffectKind::Friction(conditions)
+            | FfEffectKind::Damper(conditions)
+            | FfEffectKind::Inertia(conditions) => {
+                raw.u.condition = [conditions[0].into(), conditions[1].into()]
+            }
+
+            FfEffectKind::Ramp {
+                start_level,
+                end_level,
+                envelope,
+            } => {
+                raw.u.ramp = sys::ff_ramp_effect {
+                    start_level,
+                    end_level,
+                    envelope: envelope.into(),
+                }
+            }
+        }
+
+        raw
+    }
+}
+
+impl FfEffect {
+    /// Converts an effect filled in by the kernel, such as an effect uploaded to a virtual device.
+    ///
+    /// Returns the type of the effect as an error if it is not a known effect type.
+    ///
+    /// # Safety
+    ///
+    /// The member of the union `u` selected by the type of the effect must be initialized, as it is in an effect
+    /// filled in by the kernel or converted from an [`FfEffect`]. An effect built with a smaller member of the
+    /// union leaves the rest of the union uninitialized.
+    pub unsafe fn from_raw(raw: &sys::ff_effect) -> Result<Self, ForceFeedback> {
+        let effect_type = ForceFeedback::new(raw.type_.into());
+
+        // SAFETY: The caller guarantees the member selected by the type is initialized. Every member of the union
+        // is plain integers, except for the custom data pointer of periodic effects which is never read.
+        let kind = unsafe {
+            match effect_type {
+                ForceFeedback::RUMBLE => FfEffectKind::Rumble {
+                    strong_magnitude: raw.u.rumble.strong_magnitude,
+                    weak_magnitude: raw.u.rumble.weak_magnitude,
+                },
+
+                ForceFeedback::PERIODIC => FfEffectKind::Periodic(FfPeriodic {
+                    waveform: ForceFeedback::new(raw.u.periodic.waveform.into()),
+                    period: raw.u.periodic.period,
+                    magnitude: raw.u.periodic.magnitude,
+                    offset: raw.u.periodic.offset,
+                    phase: raw.u.periodic.phase,
+                    envelope: raw.u.periodic.envelope.into(),
+                }),
+
+                ForceFeedback::CONSTANT => FfEffectKind::Constant {
+                    level: raw.u.constant.level,
+                    envelope: raw.u.constant.envelope.into(),
+                },
+
+                ForceFeedback::SPRING => FfEffectKind::Spring(conditions(raw.u.condition)),
+                ForceFeedback::FRICTION => FfEffectKind::Friction(conditions(raw.u.condition)),
+                ForceFeedback::DAMPER => FfEffectKind::Damper(conditions(raw.u.condition)),
+                ForceFeedback::INERTIA => FfEffectKind::Inertia(conditions(raw.u.condition)),
+
+                ForceFeedback::RAMP => FfEffectKind::Ramp {
+                    start_level: raw.u.ramp.start_level,
+                    end_level: raw.u.ramp.end_level,
+                    envelope: raw.u.ramp.envelope.into(),
+                },
+
+                _ => return Err(effect_type),
+            }
+        };
+
+        Ok(Self {
+            id: raw.id,
+            direction: raw.direction,
+            trigger: raw.trigger.into(),
+            replay: raw.replay.into(),
+            kind,
+        })
+    }
+}
+
+fn conditions(raw: [sys::ff_condition_effect; 2]) -> [FfCondition; 2] {
+    [raw[0].into(), raw[1].into()]
+}
+
+#[cfg(test)]
+mod test {
+    use core::mem::{align_of, offset_of, size_of};
+
+    use crate::{
+        sys, FfCondition, FfEffect, FfEffectKind, FfEnvelope, FfPeriodic, FfReplay, FfTrigger,
+        ForceFeedback,
+    };
+
+    #[test]
+    fn layout() {
+        assert_eq!(size_of::<FfReplay>(), size_of::<sys::ff_replay>());
+        assert_eq!(size_of::<FfTrigger>(), size_of::<sys::ff_trigger>());
+        assert_eq!(size_of::<FfEnvelope>(), size_of::<sys::ff_envelope>());
+        assert_eq!(
+            size_of::<FfCondition>(),
+            size_of::<sys::ff_condition_effect>()
+        );
+        assert_eq!(align_of::<FfEnvelope>(), align_of::<sys::ff_envelope>());
+        assert_eq!(
+            offset_of!(FfEnvelope, fade_level),
+            offset_of!(sys::ff_envelope, fade_level)
+        );
+        assert_eq!(
+            offset_of!(FfCondition, center),
+            offset_of!(sys::ff_condition_effect, center)
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let envelope = FfEnvelope {
+            attack_length: 10,
+            attack_level: 0x7fff,
+            fade_length: 20,
+            fade_level: 0,
+        };
+
+        let kinds = [
+            FfEffectKind::Rumble {
+                strong_magnitude: 0xc000,
+                weak_magnitude: 0x4000,
+            },
+            FfEffectKind::Periodic(FfPeriodic {
+                waveform: ForceFeedback::SINE,
+                period: 100,
+                magnitude: 0x2000,
+                offset: -5,
+                phase: 0,
+                envelope,
+            }),
+            FfEffectKind::Constant {
+                level: -0x1000,
+                envelope,
+            },
+            FfEffectKind::Damper([
+                FfCondition {
+                    right_saturation: 1,
+                    left_saturation: 2,
+                    right_coeff: -3,
+                    left_coeff: 4,
+                    deadband: 5,
+                    center: -6,
+                },
+                FfCondition::default(),
+            ]),
+            FfEffectKind::Ramp {
+                start_level: -100,
+                end_level: 100,
+                envelope,
+            },
+        ];
+
+        for kind in kinds {
+            let mut effect = FfEffect::new(kind);
+            effect.direction = 0x4000;
+            effect.replay.length = 500;
+
+            let raw = sys::ff_effect::from(effect);
+            assert_eq!(raw.type_ as u32, kind.effect_type().into_inner());
+            assert_eq!(raw.id, -1);
+            assert_eq!(unsafe { FfEffect::from_raw(&raw) }, Ok(effect));
+        }
+    }
+
+    #[test]
+    fn unknown_type() {
+        let raw = sys::ff_effect {
+            type_: ForceFeedback::GAIN.into_inner() as u16,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            unsafe { FfEffect::from_raw(&raw) },
+            Err(ForceFeedback::GAIN)
+        );
+    }
+}
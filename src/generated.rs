@@ -4,7 +4,7 @@
 
 #[doc = "Absolute axes."]
 #[repr(transparent)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct AbsoluteAxis(u32);
 impl AbsoluteAxis {
     pub const fn new(value: u32) -> Self {
@@ -14,6 +14,283 @@ impl AbsoluteAxis {
         self.0
     }
 }
+impl AbsoluteAxis {
+    #[doc = r" Returns the name of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" If the code has multiple names, the canonical name is returned. The other names of the code are"]
+    #[doc = r" returned by [`aliases`](Self::aliases)."]
+    #[doc = r""]
+    #[doc = r" Returns [`None`] if the value is not a known code."]
+    pub const fn name(self) -> Option<&'static str> {
+        let index = self.0.wrapping_sub(0u32) as usize;
+        if index < Self::NAMES_BY_VALUE.len() {
+            Self::NAMES_BY_VALUE[index]
+        } else {
+            None
+        }
+    }
+}
+impl AbsoluteAxis {
+    #[doc = r" Returns the other names of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" The canonical name returned by [`name`](Self::name) is not included. Returns an empty slice if the"]
+    #[doc = r" code has no other names or is not a known code."]
+    pub const fn aliases(self) -> &'static [&'static str] {
+        &[]
+    }
+}
+impl AbsoluteAxis {
+    const NAMES_BY_VALUE: &'static [Option<&'static str>] = &[
+        Some("ABS_X"),
+        Some("ABS_Y"),
+        Some("ABS_Z"),
+        Some("ABS_RX"),
+        Some("ABS_RY"),
+        Some("ABS_RZ"),
+        Some("ABS_THROTTLE"),
+        Some("ABS_RUDDER"),
+        Some("ABS_WHEEL"),
+        Some("ABS_GAS"),
+        Some("ABS_BRAKE"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("ABS_HAT0X"),
+        Some("ABS_HAT0Y"),
+        Some("ABS_HAT1X"),
+        Some("ABS_HAT1Y"),
+        Some("ABS_HAT2X"),
+        Some("ABS_HAT2Y"),
+        Some("ABS_HAT3X"),
+        Some("ABS_HAT3Y"),
+        Some("ABS_PRESSURE"),
+        Some("ABS_DISTANCE"),
+        Some("ABS_TILT_X"),
+        Some("ABS_TILT_Y"),
+        Some("ABS_TOOL_WIDTH"),
+        None,
+        None,
+        None,
+        Some("ABS_VOLUME"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("ABS_MISC"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("ABS_RESERVED"),
+        Some("ABS_MT_SLOT"),
+        Some("ABS_MT_TOUCH_MAJOR"),
+        Some("ABS_MT_TOUCH_MINOR"),
+        Some("ABS_MT_WIDTH_MAJOR"),
+        Some("ABS_MT_WIDTH_MINOR"),
+        Some("ABS_MT_ORIENTATION"),
+        Some("ABS_MT_POSITION_X"),
+        Some("ABS_MT_POSITION_Y"),
+        Some("ABS_MT_TOOL_TYPE"),
+        Some("ABS_MT_BLOB_ID"),
+        Some("ABS_MT_TRACKING_ID"),
+        Some("ABS_MT_PRESSURE"),
+        Some("ABS_MT_DISTANCE"),
+        Some("ABS_MT_TOOL_X"),
+        Some("ABS_MT_TOOL_Y"),
+    ];
+    const NAME_MAP: crate::phf::NameMap<Self> = crate::phf::NameMap {
+        seeds: &[
+            1u32, 47u32, 5u32, 0u32, 113u32, 18u32, 5u32, 659u32, 70u32, 34u32, 111u32, 2u32, 5u32,
+            5u32, 1u32, 1051u32, 49u32, 65u32, 1u32, 5211u32, 1093u32,
+        ],
+        entries: &[
+            ("ABS_MT_BLOB_ID", Self(56u32)),
+            ("ABS_MT_WIDTH_MINOR", Self(51u32)),
+            ("HAT1Y", Self(19u32)),
+            ("ABS_X", Self(0u32)),
+            ("RESERVED", Self(46u32)),
+            ("ABS_TILT_Y", Self(27u32)),
+            ("RY", Self(4u32)),
+            ("MT_DISTANCE", Self(59u32)),
+            ("RX", Self(3u32)),
+            ("ABS_MT_TOUCH_MAJOR", Self(48u32)),
+            ("MT_TRACKING_ID", Self(57u32)),
+            ("MT_TOOL_TYPE", Self(55u32)),
+            ("MT_POSITION_X", Self(53u32)),
+            ("ABS_MT_TOOL_TYPE", Self(55u32)),
+            ("ABS_Y", Self(1u32)),
+            ("ABS_MT_SLOT", Self(47u32)),
+            ("ABS_MISC", Self(40u32)),
+            ("MT_BLOB_ID", Self(56u32)),
+            ("WHEEL", Self(8u32)),
+            ("TILT_Y", Self(27u32)),
+            ("ABS_DISTANCE", Self(25u32)),
+            ("ABS_MT_WIDTH_MAJOR", Self(50u32)),
+            ("ABS_HAT2Y", Self(21u32)),
+            ("MT_TOOL_X", Self(60u32)),
+            ("TOOL_WIDTH", Self(28u32)),
+            ("ABS_TOOL_WIDTH", Self(28u32)),
+            ("HAT0X", Self(16u32)),
+            ("HAT3X", Self(22u32)),
+            ("HAT2Y", Self(21u32)),
+            ("ABS_MT_POSITION_X", Self(53u32)),
+            ("ABS_MT_POSITION_Y", Self(54u32)),
+            ("ABS_RESERVED", Self(46u32)),
+            ("MT_ORIENTATION", Self(52u32)),
+            ("ABS_VOLUME", Self(32u32)),
+            ("ABS_MT_TOUCH_MINOR", Self(49u32)),
+            ("ABS_MT_TOOL_X", Self(60u32)),
+            ("ABS_BRAKE", Self(10u32)),
+            ("THROTTLE", Self(6u32)),
+            ("ABS_HAT1X", Self(18u32)),
+            ("HAT3Y", Self(23u32)),
+            ("ABS_HAT0X", Self(16u32)),
+            ("PRESSURE", Self(24u32)),
+            ("VOLUME", Self(32u32)),
+            ("ABS_MT_TRACKING_ID", Self(57u32)),
+            ("MT_POSITION_Y", Self(54u32)),
+            ("ABS_THROTTLE", Self(6u32)),
+            ("MT_WIDTH_MAJOR", Self(50u32)),
+            ("ABS_GAS", Self(9u32)),
+            ("ABS_HAT2X", Self(20u32)),
+            ("MT_TOUCH_MINOR", Self(49u32)),
+            ("DISTANCE", Self(25u32)),
+            ("MT_TOUCH_MAJOR", Self(48u32)),
+            ("MT_SLOT", Self(47u32)),
+            ("ABS_Z", Self(2u32)),
+            ("HAT0Y", Self(17u32)),
+            ("Y", Self(1u32)),
+            ("Z", Self(2u32)),
+            ("ABS_RY", Self(4u32)),
+            ("ABS_RZ", Self(5u32)),
+            ("ABS_MT_DISTANCE", Self(59u32)),
+            ("GAS", Self(9u32)),
+            ("HAT2X", Self(20u32)),
+            ("ABS_HAT3Y", Self(23u32)),
+            ("TILT_X", Self(26u32)),
+            ("MT_PRESSURE", Self(58u32)),
+            ("ABS_RUDDER", Self(7u32)),
+            ("ABS_WHEEL", Self(8u32)),
+            ("RZ", Self(5u32)),
+            ("ABS_HAT1Y", Self(19u32)),
+            ("ABS_RX", Self(3u32)),
+            ("X", Self(0u32)),
+            ("ABS_MT_PRESSURE", Self(58u32)),
+            ("ABS_HAT0Y", Self(17u32)),
+            ("ABS_MT_ORIENTATION", Self(52u32)),
+            ("MISC", Self(40u32)),
+            ("ABS_PRESSURE", Self(24u32)),
+            ("HAT1X", Self(18u32)),
+            ("MT_TOOL_Y", Self(61u32)),
+            ("ABS_TILT_X", Self(26u32)),
+            ("BRAKE", Self(10u32)),
+            ("ABS_HAT3X", Self(22u32)),
+            ("MT_WIDTH_MINOR", Self(51u32)),
+            ("ABS_MT_TOOL_Y", Self(61u32)),
+            ("RUDDER", Self(7u32)),
+        ],
+    };
+    #[doc = r" Looks up a code by name."]
+    #[doc = r""]
+    #[doc = r" Both the name defined in the kernel headers (`KEY_LEFTMETA`) and the name of the associated"]
+    #[doc = r" constant (`LEFTMETA`) are accepted. The name of a constant which starts with a digit may be given"]
+    #[doc = r" with or without its leading underscore (`_0` or `0`). The name is case sensitive, see"]
+    #[doc = r" [`from_name_ignore_case`](Self::from_name_ignore_case) for a case insensitive lookup."]
+    pub fn from_name(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, false)
+            .ok_or(crate::ParseCodeError::new("AbsoluteAxis"))
+    }
+    #[doc = r" Looks up a code by name, ignoring ASCII case."]
+    #[doc = r""]
+    #[doc = r" This accepts the same names as [`from_name`](Self::from_name)."]
+    pub fn from_name_ignore_case(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, true)
+            .ok_or(crate::ParseCodeError::new("AbsoluteAxis"))
+    }
+}
+impl AbsoluteAxis {
+    #[doc = r" Every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" Codes with multiple names are only listed once, and values which only describe the range of codes"]
+    #[doc = r" (such as `MAX`) are not included."]
+    pub const ALL: &'static [Self] = &[
+        Self(0u32),
+        Self(1u32),
+        Self(2u32),
+        Self(3u32),
+        Self(4u32),
+        Self(5u32),
+        Self(6u32),
+        Self(7u32),
+        Self(8u32),
+        Self(9u32),
+        Self(10u32),
+        Self(16u32),
+        Self(17u32),
+        Self(18u32),
+        Self(19u32),
+        Self(20u32),
+        Self(21u32),
+        Self(22u32),
+        Self(23u32),
+        Self(24u32),
+        Self(25u32),
+        Self(26u32),
+        Self(27u32),
+        Self(28u32),
+        Self(32u32),
+        Self(40u32),
+        Self(46u32),
+        Self(47u32),
+        Self(48u32),
+        Self(49u32),
+        Self(50u32),
+        Self(51u32),
+        Self(52u32),
+        Self(53u32),
+        Self(54u32),
+        Self(55u32),
+        Self(56u32),
+        Self(57u32),
+        Self(58u32),
+        Self(59u32),
+        Self(60u32),
+        Self(61u32),
+    ];
+    #[doc = r" Returns an iterator over every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" This iterates over [`ALL`](Self::ALL)."]
+    pub fn iter() -> ::core::iter::Copied<::core::slice::Iter<'static, Self>> {
+        Self::ALL.iter().copied()
+    }
+}
+impl ::core::str::FromStr for AbsoluteAxis {
+    type Err = crate::ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s)
+    }
+}
+impl ::core::fmt::Debug for AbsoluteAxis {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(self, f)
+    }
+}
+impl ::core::fmt::Display for AbsoluteAxis {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.name() {
+            Some(name) => f.pad(name),
+            None => write!(f, "ABS_{:#x}", self.0),
+        }
+    }
+}
 impl From<u32> for AbsoluteAxis {
     fn from(value: u32) -> Self {
         Self(value)
@@ -139,13 +416,21 @@ impl AbsoluteAxis {
     #[doc = " Center Y tool position "]
     #[doc(alias = "ABS_MT_TOOL_Y")]
     pub const MT_TOOL_Y: AbsoluteAxis = AbsoluteAxis(61u32);
+}
+impl AbsoluteAxis {
+    #[doc = r" The largest value a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is a bound rather than a code, and may have the same value as a code."]
     #[doc(alias = "ABS_MAX")]
-    pub const MAX: AbsoluteAxis = AbsoluteAxis(63u32);
+    pub const MAX: Self = Self(63u32);
+    #[doc = r" The number of values a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is the size of the bitmaps the kernel uses to describe the supported codes."]
     #[doc(alias = "ABS_CNT")]
-    pub const CNT: AbsoluteAxis = AbsoluteAxis(64u32);
+    pub const COUNT: usize = 64usize;
 }
 #[repr(transparent)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Button(u32);
 impl Button {
     pub const fn new(value: u32) -> Self {
@@ -155,6 +440,955 @@ impl Button {
         self.0
     }
 }
+impl Button {
+    #[doc = r" Returns the name of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" If the code has multiple names, the canonical name is returned. The other names of the code are"]
+    #[doc = r" returned by [`aliases`](Self::aliases)."]
+    #[doc = r""]
+    #[doc = r" Returns [`None`] if the value is not a known code."]
+    pub const fn name(self) -> Option<&'static str> {
+        let index = self.0.wrapping_sub(256u32) as usize;
+        if index < Self::NAMES_BY_VALUE.len() {
+            Self::NAMES_BY_VALUE[index]
+        } else {
+            None
+        }
+    }
+}
+impl Button {
+    #[doc = r" Returns the other names of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" The canonical name returned by [`name`](Self::name) is not included. Returns an empty slice if the"]
+    #[doc = r" code has no other names or is not a known code."]
+    pub const fn aliases(self) -> &'static [&'static str] {
+        match self.0 {
+            256u32 => &["BTN_MISC"],
+            272u32 => &["BTN_MOUSE"],
+            288u32 => &["BTN_JOYSTICK"],
+            304u32 => &["BTN_GAMEPAD", "BTN_A"],
+            305u32 => &["BTN_B"],
+            307u32 => &["BTN_X"],
+            308u32 => &["BTN_Y"],
+            320u32 => &["BTN_DIGI"],
+            336u32 => &["BTN_WHEEL"],
+            704u32 => &["BTN_TRIGGER_HAPPY"],
+            _ => &[],
+        }
+    }
+}
+impl Button {
+    const NAMES_BY_VALUE: &'static [Option<&'static str>] = &[
+        Some("BTN_0"),
+        Some("BTN_1"),
+        Some("BTN_2"),
+        Some("BTN_3"),
+        Some("BTN_4"),
+        Some("BTN_5"),
+        Some("BTN_6"),
+        Some("BTN_7"),
+        Some("BTN_8"),
+        Some("BTN_9"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("BTN_LEFT"),
+        Some("BTN_RIGHT"),
+        Some("BTN_MIDDLE"),
+        Some("BTN_SIDE"),
+        Some("BTN_EXTRA"),
+        Some("BTN_FORWARD"),
+        Some("BTN_BACK"),
+        Some("BTN_TASK"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("BTN_TRIGGER"),
+        Some("BTN_THUMB"),
+        Some("BTN_THUMB2"),
+        Some("BTN_TOP"),
+        Some("BTN_TOP2"),
+        Some("BTN_PINKIE"),
+        Some("BTN_BASE"),
+        Some("BTN_BASE2"),
+        Some("BTN_BASE3"),
+        Some("BTN_BASE4"),
+        Some("BTN_BASE5"),
+        Some("BTN_BASE6"),
+        None,
+        None,
+        None,
+        Some("BTN_DEAD"),
+        Some("BTN_SOUTH"),
+        Some("BTN_EAST"),
+        Some("BTN_C"),
+        Some("BTN_NORTH"),
+        Some("BTN_WEST"),
+        Some("BTN_Z"),
+        Some("BTN_TL"),
+        Some("BTN_TR"),
+        Some("BTN_TL2"),
+        Some("BTN_TR2"),
+        Some("BTN_SELECT"),
+        Some("BTN_START"),
+        Some("BTN_MODE"),
+        Some("BTN_THUMBL"),
+        Some("BTN_THUMBR"),
+        None,
+        Some("BTN_TOOL_PEN"),
+        Some("BTN_TOOL_RUBBER"),
+        Some("BTN_TOOL_BRUSH"),
+        Some("BTN_TOOL_PENCIL"),
+        Some("BTN_TOOL_AIRBRUSH"),
+        Some("BTN_TOOL_FINGER"),
+        Some("BTN_TOOL_MOUSE"),
+        Some("BTN_TOOL_LENS"),
+        Some("BTN_TOOL_QUINTTAP"),
+        Some("BTN_STYLUS3"),
+        Some("BTN_TOUCH"),
+        Some("BTN_STYLUS"),
+        Some("BTN_STYLUS2"),
+        Some("BTN_TOOL_DOUBLETAP"),
+        Some("BTN_TOOL_TRIPLETAP"),
+        Some("BTN_TOOL_QUADTAP"),
+        Some("BTN_GEAR_DOWN"),
+        Some("BTN_GEAR_UP"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("BTN_DPAD_UP"),
+        Some("BTN_DPAD_DOWN"),
+        Some("BTN_DPAD_LEFT"),
+        Some("BTN_DPAD_RIGHT"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("BTN_TRIGGER_HAPPY1"),
+        Some("BTN_TRIGGER_HAPPY2"),
+        Some("BTN_TRIGGER_HAPPY3"),
+        Some("BTN_TRIGGER_HAPPY4"),
+        Some("BTN_TRIGGER_HAPPY5"),
+        Some("BTN_TRIGGER_HAPPY6"),
+        Some("BTN_TRIGGER_HAPPY7"),
+        Some("BTN_TRIGGER_HAPPY8"),
+        Some("BTN_TRIGGER_HAPPY9"),
+        Some("BTN_TRIGGER_HAPPY10"),
+        Some("BTN_TRIGGER_HAPPY11"),
+        Some("BTN_TRIGGER_HAPPY12"),
+        Some("BTN_TRIGGER_HAPPY13"),
+        Some("BTN_TRIGGER_HAPPY14"),
+        Some("BTN_TRIGGER_HAPPY15"),
+        Some("BTN_TRIGGER_HAPPY16"),
+        Some("BTN_TRIGGER_HAPPY17"),
+        Some("BTN_TRIGGER_HAPPY18"),
+        Some("BTN_TRIGGER_HAPPY19"),
+        Some("BTN_TRIGGER_HAPPY20"),
+        Some("BTN_TRIGGER_HAPPY21"),
+        Some("BTN_TRIGGER_HAPPY22"),
+        Some("BTN_TRIGGER_HAPPY23"),
+        Some("BTN_TRIGGER_HAPPY24"),
+        Some("BTN_TRIGGER_HAPPY25"),
+        Some("BTN_TRIGGER_HAPPY26"),
+        Some("BTN_TRIGGER_HAPPY27"),
+        Some("BTN_TRIGGER_HAPPY28"),
+        Some("BTN_TRIGGER_HAPPY29"),
+        Some("BTN_TRIGGER_HAPPY30"),
+        Some("BTN_TRIGGER_HAPPY31"),
+        Some("BTN_TRIGGER_HAPPY32"),
+        Some("BTN_TRIGGER_HAPPY33"),
+        Some("BTN_TRIGGER_HAPPY34"),
+        Some("BTN_TRIGGER_HAPPY35"),
+        Some("BTN_TRIGGER_HAPPY36"),
+        Some("BTN_TRIGGER_HAPPY37"),
+        Some("BTN_TRIGGER_HAPPY38"),
+        Some("BTN_TRIGGER_HAPPY39"),
+        Some("BTN_TRIGGER_HAPPY40"),
+    ];
+    const NAME_MAP: crate::phf::NameMap<Self> = crate::phf::NameMap {
+        seeds: &[
+            3u32, 1u32, 41u32, 1u32, 31u32, 86u32, 86u32, 3u32, 7u32, 0u32, 57u32, 1u32, 25u32,
+            223u32, 30u32, 43u32, 13u32, 150u32, 195u32, 13u32, 25u32, 1u32, 5u32, 82u32, 57u32,
+            541u32, 33u32, 67u32, 6u32, 15u32, 47u32, 373u32, 84u32, 2u32, 644u32, 44u32, 39u32,
+            385u32, 231u32, 33u32, 3u32, 1u32, 121u32, 2u32, 0u32, 250u32, 71u32, 108u32, 3u32,
+            1005u32, 1u32, 235u32, 392u32, 366u32, 736u32, 512u32, 468u32, 14u32, 54u32, 14u32,
+            106u32, 5650u32,
+        ],
+        entries: &[
+            ("_5", Self(261u32)),
+            ("TRIGGER_HAPPY7", Self(710u32)),
+            ("BTN_DPAD_UP", Self(544u32)),
+            ("DEAD", Self(303u32)),
+            ("BTN_THUMBR", Self(318u32)),
+            ("BTN_TOOL_QUADTAP", Self(335u32)),
+            ("BTN_TL2", Self(312u32)),
+            ("BTN_1", Self(257u32)),
+            ("BTN_TOP", Self(291u32)),
+            ("BTN_DIGI", Self(320u32)),
+            ("BTN_MOUSE", Self(272u32)),
+            ("START", Self(315u32)),
+            ("GEAR_DOWN", Self(336u32)),
+            ("BTN_MISC", Self(256u32)),
+            ("BTN_TRIGGER_HAPPY36", Self(739u32)),
+            ("BTN_6", Self(262u32)),
+            ("BTN_A", Self(304u32)),
+            ("BTN_5", Self(261u32)),
+            ("BTN_Z", Self(309u32)),
+            ("BTN_TRIGGER_HAPPY4", Self(707u32)),
+            ("TOOL_TRIPLETAP", Self(334u32)),
+            ("TOOL_PENCIL", Self(323u32)),
+            ("TOOL_DOUBLETAP", Self(333u32)),
+            ("PINKIE", Self(293u32)),
+            ("BTN_TRIGGER_HAPPY3", Self(706u32)),
+            ("BTN_PINKIE", Self(293u32)),
+            ("LEFT", Self(272u32)),
+            ("BTN_BACK", Self(278u32)),
+            ("BTN_WEST", Self(308u32)),
+            ("TRIGGER_HAPPY1", Self(704u32)),
+            ("BTN_TRIGGER_HAPPY", Self(704u32)),
+            ("MOUSE", Self(272u32)),
+            ("TRIGGER_HAPPY23", Self(726u32)),
+            ("BTN_TOUCH", Self(330u32)),
+            ("TRIGGER_HAPPY20", Self(723u32)),
+            ("BTN_FORWARD", Self(277u32)),
+            ("BTN_MIDDLE", Self(274u32)),
+            ("TL", Self(310u32)),
+            ("TRIGGER_HAPPY21", Self(724u32)),
+            ("BTN_TASK", Self(279u32)),
+            ("BTN_9", Self(265u32)),
+            ("BACK", Self(278u32)),
+            ("BTN_SOUTH", Self(304u32)),
+            ("THUMB2", Self(290u32)),
+            ("TRIGGER_HAPPY31", Self(734u32)),
+            ("TRIGGER_HAPPY30", Self(733u32)),
+            ("WHEEL", Self(336u32)),
+            ("BTN_0", Self(256u32)),
+            ("BTN_TRIGGER_HAPPY11", Self(714u32)),
+            ("TRIGGER_HAPPY", Self(704u32)),
+            ("_9", Self(265u32)),
+            ("BASE2", Self(295u32)),
+            ("TOOL_PEN", Self(320u32)),
+            ("SIDE", Self(275u32)),
+            ("STYLUS3", Self(329u32)),
+            ("DPAD_RIGHT", Self(547u32)),
+            ("TRIGGER_HAPPY26", Self(729u32)),
+            ("STYLUS", Self(331u32)),
+            ("BTN_TRIGGER_HAPPY28", Self(731u32)),
+            ("BTN_B", Self(305u32)),
+            ("TRIGGER", Self(288u32)),
+            ("BTN_TRIGGER_HAPPY34", Self(737u32)),
+            ("BTN_TRIGGER_HAPPY35", Self(738u32)),
+            ("BTN_WHEEL", Self(336u32)),
+            ("MIDDLE", Self(274u32)),
+            ("BTN_GEAR_UP", Self(337u32)),
+            ("TOOL_LENS", Self(327u32)),
+            ("BTN_BASE", Self(294u32)),
+            ("BTN_EAST", Self(305u32)),
+            ("BTN_TOOL_QUINTTAP", Self(328u32)),
+            ("BTN_TRIGGER_HAPPY39", Self(742u32)),
+            ("BTN_2", Self(258u32)),
+            ("TRIGGER_HAPPY16", Self(719u32)),
+            ("GAMEPAD", Self(304u32)),
+            ("BTN_C", Self(306u32)),
+            ("BTN_TRIGGER_HAPPY27", Self(730u32)),
+            ("BTN_DPAD_LEFT", Self(546u32)),
+            ("BTN_GAMEPAD", Self(304u32)),
+            ("BTN_TOOL_RUBBER", Self(321u32)),
+            ("8", Self(264u32)),
+            ("TRIGGER_HAPPY19", Self(722u32)),
+            ("BTN_TRIGGER_HAPPY24", Self(727u32)),
+            ("TRIGGER_HAPPY38", Self(741u32)),
+            ("BTN_7", Self(263u32)),
+            ("BTN_TR", Self(311u32)),
+            ("BTN_BASE6", Self(299u32)),
+            ("BTN_TOOL_LENS", Self(327u32)),
+            ("BTN_SELECT", Self(314u32)),
+            ("TRIGGER_HAPPY29", Self(732u32)),
+            ("EXTRA", Self(276u32)),
+            ("BTN_TRIGGER_HAPPY33", Self(736u32)),
+            ("_1", Self(257u32)),
+            ("SELECT", Self(314u32)),
+            ("TRIGGER_HAPPY39", Self(742u32)),
+            ("9", Self(265u32)),
+            ("BTN_TRIGGER_HAPPY6", Self(709u32)),
+            ("TRIGGER_HAPPY15", Self(718u32)),
+            ("1", Self(257u32)),
+            ("BTN_TRIGGER_HAPPY5", Self(708u32)),
+            ("TRIGGER_HAPPY14", Self(717u32)),
+            ("BTN_TRIGGER_HAPPY21", Self(724u32)),
+            ("BTN_STYLUS", Self(331u32)),
+            ("TRIGGER_HAPPY18", Self(721u32)),
+            ("BASE6", Self(299u32)),
+            ("BTN_TRIGGER_HAPPY30", Self(733u32)),
+            ("BTN_TRIGGER_HAPPY23", Self(726u32)),
+            ("TOOL_FINGER", Self(325u32)),
+            ("DPAD_DOWN", Self(545u32)),
+            ("BTN_TRIGGER", Self(288u32)),
+            ("BTN_TRIGGER_HAPPY13", Self(716u32)),
+            ("TRIGGER_HAPPY33", Self(736u32)),
+            ("BTN_TRIGGER_HAPPY40", Self(743u32)),
+            ("RIGHT", Self(273u32)),
+            ("TRIGGER_HAPPY17", Self(720u32)),
+            ("BTN_TRIGGER_HAPPY9", Self(712u32)),
+            ("MODE", Self(316u32)),
+            ("TRIGGER_HAPPY27", Self(730u32)),
+            ("6", Self(262u32)),
+            ("BTN_MODE", Self(316u32)),
+            ("BTN_GEAR_DOWN", Self(336u32)),
+            ("BTN_TRIGGER_HAPPY2", Self(705u32)),
+            ("BTN_TRIGGER_HAPPY19", Self(722u32)),
+            ("TRIGGER_HAPPY32", Self(735u32)),
+            ("BTN_NORTH", Self(307u32)),
+            ("FORWARD", Self(277u32)),
+            ("_2", Self(258u32)),
+            ("BTN_TOOL_TRIPLETAP", Self(334u32)),
+            ("_8", Self(264u32)),
+            ("MISC", Self(256u32)),
+            ("BTN_TOOL_AIRBRUSH", Self(324u32)),
+            ("TOUCH", Self(330u32)),
+            ("JOYSTICK", Self(288u32)),
+            ("3", Self(259u32)),
+            ("DPAD_LEFT", Self(546u32)),
+            ("BTN_TRIGGER_HAPPY1", Self(704u32)),
+            ("TRIGGER_HAPPY35", Self(738u32)),
+            ("Z", Self(309u32)),
+            ("BTN_TOOL_PENCIL", Self(323u32)),
+            ("TRIGGER_HAPPY8", Self(711u32)),
+            ("TRIGGER_HAPPY36", Self(739u32)),
+            ("BTN_BASE2", Self(295u32)),
+            ("BTN_TRIGGER_HAPPY37", Self(740u32)),
+            ("BTN_JOYSTICK", Self(288u32)),
+            ("BTN_RIGHT", Self(273u32)),
+            ("BTN_BASE3", Self(296u32)),
+            ("BTN_TRIGGER_HAPPY8", Self(711u32)),
+            ("THUMB", Self(289u32)),
+            ("TRIGGER_HAPPY12", Self(715u32)),
+            ("BTN_TOOL_BRUSH", Self(322u32)),
+            ("BTN_TRIGGER_HAPPY15", Self(718u32)),
+            ("BTN_DPAD_DOWN", Self(545u32)),
+            ("BTN_TR2", Self(313u32)),
+            ("BTN_TRIGGER_HAPPY25", Self(728u32)),
+            ("BTN_DPAD_RIGHT", Self(547u32)),
+            ("Y", Self(308u32)),
+            ("C", Self(306u32)),
+            ("BTN_TRIGGER_HAPPY17", Self(720u32)),
+            ("THUMBL", Self(317u32)),
+            ("BASE4", Self(297u32)),
+            ("B", Self(305u32)),
+            ("TRIGGER_HAPPY24", Self(727u32)),
+            ("BTN_BASE5", Self(298u32)),
+            ("BTN_TOOL_DOUBLETAP", Self(333u32)),
+            ("TOOL_QUINTTAP", Self(328u32)),
+            ("BTN_LEFT", Self(272u32)),
+            ("TL2", Self(312u32)),
+            ("TOOL_RUBBER", Self(321u32)),
+            ("TRIGGER_HAPPY2", Self(705u32)),
+            ("TOOL_QUADTAP", Self(335u32)),
+            ("BTN_4", Self(260u32)),
+            ("BTN_TRIGGER_HAPPY20", Self(723u32)),
+            ("BTN_TRIGGER_HAPPY32", Self(735u32)),
+            ("BTN_TRIGGER_HAPPY7", Self(710u32)),
+            ("BTN_THUMB", Self(289u32)),
+            ("TRIGGER_HAPPY3", Self(706u32)),
+            ("TOOL_MOUSE", Self(326u32)),
+            ("TRIGGER_HAPPY6", Self(709u32)),
+            ("NORTH", Self(307u32)),
+            ("TRIGGER_HAPPY28", Self(731u32)),
+            ("5", Self(261u32)),
+            ("EAST", Self(305u32)),
+            ("TOOL_AIRBRUSH", Self(324u32)),
+            ("DPAD_UP", Self(544u32)),
+            ("_0", Self(256u32)),
+            ("BTN_TRIGGER_HAPPY12", Self(715u32)),
+            ("BTN_TL", Self(310u32)),
+            ("_3", Self(259u32)),
+            ("BTN_SIDE", Self(275u32)),
+            ("_7", Self(263u32)),
+            ("BTN_TOOL_FINGER", Self(325u32)),
+            ("BTN_TRIGGER_HAPPY10", Self(713u32)),
+            ("BTN_TOOL_MOUSE", Self(326u32)),
+            ("TRIGGER_HAPPY37", Self(740u32)),
+            ("BASE", Self(294u32)),
+            ("TRIGGER_HAPPY25", Self(728u32)),
+            ("GEAR_UP", Self(337u32)),
+            ("BTN_8", Self(264u32)),
+            ("BTN_EXTRA", Self(276u32)),
+            ("BTN_3", Self(259u32)),
+            ("BTN_STYLUS3", Self(329u32)),
+            ("0", Self(256u32)),
+            ("2", Self(258u32)),
+            ("THUMBR", Self(318u32)),
+            ("TRIGGER_HAPPY22", Self(725u32)),
+            ("X", Self(307u32)),
+            ("4", Self(260u32)),
+            ("BTN_TOP2", Self(292u32)),
+            ("TRIGGER_HAPPY34", Self(737u32)),
+            ("TRIGGER_HAPPY5", Self(708u32)),
+            ("BTN_X", Self(307u32)),
+            ("BTN_STYLUS2", Self(332u32)),
+            ("BTN_TRIGGER_HAPPY22", Self(725u32)),
+            ("_4", Self(260u32)),
+            ("BTN_Y", Self(308u32)),
+            ("TRIGGER_HAPPY11", Self(714u32)),
+            ("BTN_TRIGGER_HAPPY14", Self(717u32)),
+            ("BTN_BASE4", Self(297u32)),
+            ("DIGI", Self(320u32)),
+            ("STYLUS2", Self(332u32)),
+            ("BTN_TRIGGER_HAPPY16", Self(719u32)),
+            ("TR2", Self(313u32)),
+            ("TRIGGER_HAPPY10", Self(713u32)),
+            ("BTN_TRIGGER_HAPPY18", Self(721u32)),
+            ("_6", Self(262u32)),
+            ("BASE3", Self(296u32)),
+            ("BASE5", Self(298u32)),
+            ("BTN_THUMBL", Self(317u32)),
+            ("SOUTH", Self(304u32)),
+            ("TOP", Self(291u32)),
+            ("TRIGGER_HAPPY13", Self(716u32)),
+            ("BTN_TRIGGER_HAPPY38", Self(741u32)),
+            ("BTN_START", Self(315u32)),
+            ("TASK", Self(279u32)),
+            ("WEST", Self(308u32)),
+            ("TOP2", Self(292u32)),
+            ("A", Self(304u32)),
+            ("BTN_TOOL_PEN", Self(320u32)),
+            ("TRIGGER_HAPPY40", Self(743u32)),
+            ("TRIGGER_HAPPY4", Self(707u32)),
+            ("BTN_THUMB2", Self(290u32)),
+            ("TOOL_BRUSH", Self(322u32)),
+            ("BTN_TRIGGER_HAPPY31", Self(734u32)),
+            ("BTN_DEAD", Self(303u32)),
+            ("TRIGGER_HAPPY9", Self(712u32)),
+            ("BTN_TRIGGER_HAPPY29", Self(732u32)),
+            ("7", Self(263u32)),
+            ("TR", Self(311u32)),
+            ("BTN_TRIGGER_HAPPY26", Self(729u32)),
+        ],
+    };
+    #[doc = r" Looks up a code by name."]
+    #[doc = r""]
+    #[doc = r" Both the name defined in the kernel headers (`KEY_LEFTMETA`) and the name of the associated"]
+    #[doc = r" constant (`LEFTMETA`) are accepted. The name of a constant which starts with a digit may be given"]
+    #[doc = r" with or without its leading underscore (`_0` or `0`). The name is case sensitive, see"]
+    #[doc = r" [`from_name_ignore_case`](Self::from_name_ignore_case) for a case insensitive lookup."]
+    pub fn from_name(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, false)
+            .ok_or(crate::ParseCodeError::new("Button"))
+    }
+    #[doc = r" Looks up a code by name, ignoring ASCII case."]
+    #[doc = r""]
+    #[doc = r" This accepts the same names as [`from_name`](Self::from_name)."]
+    pub fn from_name_ignore_case(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, true)
+            .ok_or(crate::ParseCodeError::new("Button"))
+    }
+}
+impl Button {
+    #[doc = r" Every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" Codes with multiple names are only listed once, and values which only describe the range of codes"]
+    #[doc = r" (such as `MAX`) are not included."]
+    pub const ALL: &'static [Self] = &[
+        Self(256u32),
+        Self(257u32),
+        Self(258u32),
+        Self(259u32),
+        Self(260u32),
+        Self(261u32),
+        Self(262u32),
+        Self(263u32),
+        Self(264u32),
+        Self(265u32),
+        Self(272u32),
+        Self(273u32),
+        Self(274u32),
+        Self(275u32),
+        Self(276u32),
+        Self(277u32),
+        Self(278u32),
+        Self(279u32),
+        Self(288u32),
+        Self(289u32),
+        Self(290u32),
+        Self(291u32),
+        Self(292u32),
+        Self(293u32),
+        Self(294u32),
+        Self(295u32),
+        Self(296u32),
+        Self(297u32),
+        Self(298u32),
+        Self(299u32),
+        Self(303u32),
+        Self(304u32),
+        Self(305u32),
+        Self(306u32),
+        Self(307u32),
+        Self(308u32),
+        Self(309u32),
+        Self(310u32),
+        Self(311u32),
+        Self(312u32),
+        Self(313u32),
+        Self(314u32),
+        Self(315u32),
+        Self(316u32),
+        Self(317u32),
+        Self(318u32),
+        Self(320u32),
+        Self(321u32),
+        Self(322u32),
+        Self(323u32),
+        Self(324u32),
+        Self(325u32),
+        Self(326u32),
+        Self(327u32),
+        Self(328u32),
+        Self(329u32),
+        Self(330u32),
+        Self(331u32),
+        Self(332u32),
+        Self(333u32),
+        Self(334u32),
+        Self(335u32),
+        Self(336u32),
+        Self(337u32),
+        Self(544u32),
+        Self(545u32),
+        Self(546u32),
+        Self(547u32),
+        Self(704u32),
+        Self(705u32),
+        Self(706u32),
+        Self(707u32),
+        Self(708u32),
+        Self(709u32),
+        Self(710u32),
+        Self(711u32),
+        Self(712u32),
+        Self(713u32),
+        Self(714u32),
+        Self(715u32),
+        Self(716u32),
+        Self(717u32),
+        Self(718u32),
+        Self(719u32),
+        Self(720u32),
+        Self(721u32),
+        Self(722u32),
+        Self(723u32),
+        Self(724u32),
+        Self(725u32),
+        Self(726u32),
+        Self(727u32),
+        Self(728u32),
+        Self(729u32),
+        Self(730u32),
+        Self(731u32),
+        Self(732u32),
+        Self(733u32),
+        Self(734u32),
+        Self(735u32),
+        Self(736u32),
+        Self(737u32),
+        Self(738u32),
+        Self(739u32),
+        Self(740u32),
+        Self(741u32),
+        Self(742u32),
+        Self(743u32),
+    ];
+    #[doc = r" Returns an iterator over every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" This iterates over [`ALL`](Self::ALL)."]
+    pub fn iter() -> ::core::iter::Copied<::core::slice::Iter<'static, Self>> {
+        Self::ALL.iter().copied()
+    }
+}
+impl ::core::str::FromStr for Button {
+    type Err = crate::ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s)
+    }
+}
+impl ::core::fmt::Debug for Button {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(self, f)
+    }
+}
+impl ::core::fmt::Display for Button {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.name() {
+            Some(name) => f.pad(name),
+            None => write!(f, "BTN_{:#x}", self.0),
+        }
+    }
+}
 impl From<u32> for Button {
     fn from(value: u32) -> Self {
         Self(value)
@@ -181,6 +1415,7 @@ impl PartialEq<u32> for Button {
     }
 }
 impl Button {
+    #[doc = "Alias of [`_0`](Self::_0)."]
     #[doc(alias = "BTN_MISC")]
     pub const MISC: Button = Button(256u32);
     #[doc(alias = "BTN_0")]
@@ -203,6 +1438,7 @@ impl Button {
     pub const _8: Button = Button(264u32);
     #[doc(alias = "BTN_9")]
     pub const _9: Button = Button(265u32);
+    #[doc = "Alias of [`LEFT`](Self::LEFT)."]
     #[doc(alias = "BTN_MOUSE")]
     pub const MOUSE: Button = Button(272u32);
     #[doc(alias = "BTN_LEFT")]
@@ -221,6 +1457,7 @@ impl Button {
     pub const BACK: Button = Button(278u32);
     #[doc(alias = "BTN_TASK")]
     pub const TASK: Button = Button(279u32);
+    #[doc = "Alias of [`TRIGGER`](Self::TRIGGER)."]
     #[doc(alias = "BTN_JOYSTICK")]
     pub const JOYSTICK: Button = Button(288u32);
     #[doc(alias = "BTN_TRIGGER")]
@@ -249,24 +1486,29 @@ impl Button {
     pub const BASE6: Button = Button(299u32);
     #[doc(alias = "BTN_DEAD")]
     pub const DEAD: Button = Button(303u32);
+    #[doc = "Alias of [`SOUTH`](Self::SOUTH)."]
     #[doc(alias = "BTN_GAMEPAD")]
     pub const GAMEPAD: Button = Button(304u32);
     #[doc(alias = "BTN_SOUTH")]
     pub const SOUTH: Button = Button(304u32);
+    #[doc = "Alias of [`SOUTH`](Self::SOUTH)."]
     #[doc(alias = "BTN_A")]
     pub const A: Button = Button(304u32);
     #[doc(alias = "BTN_EAST")]
     pub const EAST: Button = Button(305u32);
+    #[doc = "Alias of [`EAST`](Self::EAST)."]
     #[doc(alias = "BTN_B")]
     pub const B: Button = Button(305u32);
     #[doc(alias = "BTN_C")]
     pub const C: Button = Button(306u32);
     #[doc(alias = "BTN_NORTH")]
     pub const NORTH: Button = Button(307u32);
+    #[doc = "Alias of [`NORTH`](Self::NORTH)."]
     #[doc(alias = "BTN_X")]
     pub const X: Button = Button(307u32);
     #[doc(alias = "BTN_WEST")]
     pub const WEST: Button = Button(308u32);
+    #[doc = "Alias of [`WEST`](Self::WEST)."]
     #[doc(alias = "BTN_Y")]
     pub const Y: Button = Button(308u32);
     #[doc(alias = "BTN_Z")]
@@ -289,6 +1531,7 @@ impl Button {
     pub const THUMBL: Button = Button(317u32);
     #[doc(alias = "BTN_THUMBR")]
     pub const THUMBR: Button = Button(318u32);
+    #[doc = "Alias of [`TOOL_PEN`](Self::TOOL_PEN)."]
     #[doc(alias = "BTN_DIGI")]
     pub const DIGI: Button = Button(320u32);
     #[doc(alias = "BTN_TOOL_PEN")]
@@ -325,6 +1568,7 @@ impl Button {
     #[doc = " Four fingers on trackpad "]
     #[doc(alias = "BTN_TOOL_QUADTAP")]
     pub const TOOL_QUADTAP: Button = Button(335u32);
+    #[doc = "Alias of [`GEAR_DOWN`](Self::GEAR_DOWN)."]
     #[doc(alias = "BTN_WHEEL")]
     pub const WHEEL: Button = Button(336u32);
     #[doc(alias = "BTN_GEAR_DOWN")]
@@ -339,6 +1583,7 @@ impl Button {
     pub const DPAD_LEFT: Button = Button(546u32);
     #[doc(alias = "BTN_DPAD_RIGHT")]
     pub const DPAD_RIGHT: Button = Button(547u32);
+    #[doc = "Alias of [`TRIGGER_HAPPY1`](Self::TRIGGER_HAPPY1)."]
     #[doc(alias = "BTN_TRIGGER_HAPPY")]
     pub const TRIGGER_HAPPY: Button = Button(704u32);
     #[doc(alias = "BTN_TRIGGER_HAPPY1")]
@@ -422,9 +1667,285 @@ impl Button {
     #[doc(alias = "BTN_TRIGGER_HAPPY40")]
     pub const TRIGGER_HAPPY40: Button = Button(743u32);
 }
+impl Button {}
+#[doc = "Buses an input device may be connected through."]
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BusType(u16);
+impl BusType {
+    pub const fn new(value: u16) -> Self {
+        Self(value)
+    }
+    pub const fn into_inner(self) -> u16 {
+        self.0
+    }
+}
+impl BusType {
+    #[doc = r" Returns the name of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" If the code has multiple names, the canonical name is returned. The other names of the code are"]
+    #[doc = r" returned by [`aliases`](Self::aliases)."]
+    #[doc = r""]
+    #[doc = r" Returns [`None`] if the value is not a known code."]
+    pub const fn name(self) -> Option<&'static str> {
+        let index = self.0.wrapping_sub(1u16) as usize;
+        if index < Self::NAMES_BY_VALUE.len() {
+            Self::NAMES_BY_VALUE[index]
+        } else {
+            None
+        }
+    }
+}
+impl BusType {
+    #[doc = r" Returns the other names of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" The canonical name returned by [`name`](Self::name) is not included. Returns an empty slice if the"]
+    #[doc = r" code has no other names or is not a known code."]
+    pub const fn aliases(self) -> &'static [&'static str] {
+        &[]
+    }
+}
+impl BusType {
+    const NAMES_BY_VALUE: &'static [Option<&'static str>] = &[
+        Some("BUS_PCI"),
+        Some("BUS_ISAPNP"),
+        Some("BUS_USB"),
+        Some("BUS_HIL"),
+        Some("BUS_BLUETOOTH"),
+        Some("BUS_VIRTUAL"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("BUS_ISA"),
+        Some("BUS_I8042"),
+        Some("BUS_XTKBD"),
+        Some("BUS_RS232"),
+        Some("BUS_GAMEPORT"),
+        Some("BUS_PARPORT"),
+        Some("BUS_AMIGA"),
+        Some("BUS_ADB"),
+        Some("BUS_I2C"),
+        Some("BUS_HOST"),
+        Some("BUS_GSC"),
+        Some("BUS_ATARI"),
+        Some("BUS_SPI"),
+        Some("BUS_RMI"),
+        Some("BUS_CEC"),
+        Some("BUS_INTEL_ISHTP"),
+        Some("BUS_AMD_SFH"),
+    ];
+    const NAME_MAP: crate::phf::NameMap<Self> = crate::phf::NameMap {
+        seeds: &[
+            62u32, 31u32, 1u32, 41u32, 4u32, 35u32, 20u32, 59u32, 132u32, 11u32, 1122u32, 14u32,
+        ],
+        entries: &[
+            ("BUS_RS232", Self(19u16)),
+            ("BUS_ISA", Self(16u16)),
+            ("ATARI", Self(27u16)),
+            ("CEC", Self(30u16)),
+            ("BUS_SPI", Self(28u16)),
+            ("BUS_GAMEPORT", Self(20u16)),
+            ("AMD_SFH", Self(32u16)),
+            ("ISA", Self(16u16)),
+            ("USB", Self(3u16)),
+            ("PCI", Self(1u16)),
+            ("BUS_ISAPNP", Self(2u16)),
+            ("GAMEPORT", Self(20u16)),
+            ("RMI", Self(29u16)),
+            ("ADB", Self(23u16)),
+            ("BUS_ADB", Self(23u16)),
+            ("BUS_GSC", Self(26u16)),
+            ("PARPORT", Self(21u16)),
+            ("BUS_USB", Self(3u16)),
+            ("BUS_XTKBD", Self(18u16)),
+            ("BUS_HOST", Self(25u16)),
+            ("BUS_VIRTUAL", Self(6u16)),
+            ("BUS_CEC", Self(30u16)),
+            ("XTKBD", Self(18u16)),
+            ("BUS_I8042", Self(17u16)),
+            ("I2C", Self(24u16)),
+            ("BUS_HIL", Self(4u16)),
+            ("BUS_RMI", Self(29u16)),
+            ("BLUETOOTH", Self(5u16)),
+            ("RS232", Self(19u16)),
+            ("HIL", Self(4u16)),
+            ("BUS_BLUETOOTH", Self(5u16)),
+            ("BUS_AMIGA", Self(22u16)),
+            ("I8042", Self(17u16)),
+            ("BUS_ATARI", Self(27u16)),
+            ("BUS_PCI", Self(1u16)),
+            ("BUS_I2C", Self(24u16)),
+            ("AMIGA", Self(22u16)),
+            ("BUS_INTEL_ISHTP", Self(31u16)),
+            ("VIRTUAL", Self(6u16)),
+            ("INTEL_ISHTP", Self(31u16)),
+            ("BUS_PARPORT", Self(21u16)),
+            ("GSC", Self(26u16)),
+            ("HOST", Self(25u16)),
+            ("SPI", Self(28u16)),
+            ("ISAPNP", Self(2u16)),
+            ("BUS_AMD_SFH", Self(32u16)),
+        ],
+    };
+    #[doc = r" Looks up a code by name."]
+    #[doc = r""]
+    #[doc = r" Both the name defined in the kernel headers (`KEY_LEFTMETA`) and the name of the associated"]
+    #[doc = r" constant (`LEFTMETA`) are accepted. The name of a constant which starts with a digit may be given"]
+    #[doc = r" with or without its leading underscore (`_0` or `0`). The name is case sensitive, see"]
+    #[doc = r" [`from_name_ignore_case`](Self::from_name_ignore_case) for a case insensitive lookup."]
+    pub fn from_name(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, false)
+            .ok_or(crate::ParseCodeError::new("BusType"))
+    }
+    #[doc = r" Looks up a code by name, ignoring ASCII case."]
+    #[doc = r""]
+    #[doc = r" This accepts the same names as [`from_name`](Self::from_name)."]
+    pub fn from_name_ignore_case(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, true)
+            .ok_or(crate::ParseCodeError::new("BusType"))
+    }
+}
+impl BusType {
+    #[doc = r" Every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" Codes with multiple names are only listed once, and values which only describe the range of codes"]
+    #[doc = r" (such as `MAX`) are not included."]
+    pub const ALL: &'static [Self] = &[
+        Self(1u16),
+        Self(2u16),
+        Self(3u16),
+        Self(4u16),
+        Self(5u16),
+        Self(6u16),
+        Self(16u16),
+        Self(17u16),
+        Self(18u16),
+        Self(19u16),
+        Self(20u16),
+        Self(21u16),
+        Self(22u16),
+        Self(23u16),
+        Self(24u16),
+        Self(25u16),
+        Self(26u16),
+        Self(27u16),
+        Self(28u16),
+        Self(29u16),
+        Self(30u16),
+        Self(31u16),
+        Self(32u16),
+    ];
+    #[doc = r" Returns an iterator over every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" This iterates over [`ALL`](Self::ALL)."]
+    pub fn iter() -> ::core::iter::Copied<::core::slice::Iter<'static, Self>> {
+        Self::ALL.iter().copied()
+    }
+}
+impl ::core::str::FromStr for BusType {
+    type Err = crate::ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s)
+    }
+}
+impl ::core::fmt::Debug for BusType {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(self, f)
+    }
+}
+impl ::core::fmt::Display for BusType {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.name() {
+            Some(name) => f.pad(name),
+            None => write!(f, "BUS_{:#x}", self.0),
+        }
+    }
+}
+impl From<u16> for BusType {
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+impl From<BusType> for u16 {
+    fn from(value: BusType) -> u16 {
+        value.0
+    }
+}
+impl AsRef<u16> for BusType {
+    fn as_ref(&self) -> &u16 {
+        &self.0
+    }
+}
+impl ::core::borrow::Borrow<u16> for BusType {
+    fn borrow(&self) -> &u16 {
+        &self.0
+    }
+}
+impl PartialEq<u16> for BusType {
+    fn eq(&self, other: &u16) -> bool {
+        &self.0 == other
+    }
+}
+impl BusType {
+    #[doc(alias = "BUS_PCI")]
+    pub const PCI: BusType = BusType(1u16);
+    #[doc(alias = "BUS_ISAPNP")]
+    pub const ISAPNP: BusType = BusType(2u16);
+    #[doc(alias = "BUS_USB")]
+    pub const USB: BusType = BusType(3u16);
+    #[doc(alias = "BUS_HIL")]
+    pub const HIL: BusType = BusType(4u16);
+    #[doc(alias = "BUS_BLUETOOTH")]
+    pub const BLUETOOTH: BusType = BusType(5u16);
+    #[doc(alias = "BUS_VIRTUAL")]
+    pub const VIRTUAL: BusType = BusType(6u16);
+    #[doc(alias = "BUS_ISA")]
+    pub const ISA: BusType = BusType(16u16);
+    #[doc(alias = "BUS_I8042")]
+    pub const I8042: BusType = BusType(17u16);
+    #[doc(alias = "BUS_XTKBD")]
+    pub const XTKBD: BusType = BusType(18u16);
+    #[doc(alias = "BUS_RS232")]
+    pub const RS232: BusType = BusType(19u16);
+    #[doc(alias = "BUS_GAMEPORT")]
+    pub const GAMEPORT: BusType = BusType(20u16);
+    #[doc(alias = "BUS_PARPORT")]
+    pub const PARPORT: BusType = BusType(21u16);
+    #[doc(alias = "BUS_AMIGA")]
+    pub const AMIGA: BusType = BusType(22u16);
+    #[doc(alias = "BUS_ADB")]
+    pub const ADB: BusType = BusType(23u16);
+    #[doc(alias = "BUS_I2C")]
+    pub const I2C: BusType = BusType(24u16);
+    #[doc(alias = "BUS_HOST")]
+    pub const HOST: BusType = BusType(25u16);
+    #[doc(alias = "BUS_GSC")]
+    pub const GSC: BusType = BusType(26u16);
+    #[doc(alias = "BUS_ATARI")]
+    pub const ATARI: BusType = BusType(27u16);
+    #[doc(alias = "BUS_SPI")]
+    pub const SPI: BusType = BusType(28u16);
+    #[doc(alias = "BUS_RMI")]
+    pub const RMI: BusType = BusType(29u16);
+    #[doc(alias = "BUS_CEC")]
+    pub const CEC: BusType = BusType(30u16);
+    #[doc(alias = "BUS_INTEL_ISHTP")]
+    pub const INTEL_ISHTP: BusType = BusType(31u16);
+    #[doc(alias = "BUS_AMD_SFH")]
+    pub const AMD_SFH: BusType = BusType(32u16);
+}
+impl BusType {}
 #[doc = "Event types."]
 #[repr(transparent)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct EventType(u32);
 impl EventType {
     pub const fn new(value: u32) -> Self {
@@ -434,6 +1955,152 @@ impl EventType {
         self.0
     }
 }
+impl EventType {
+    #[doc = r" Returns the name of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" If the code has multiple names, the canonical name is returned. The other names of the code are"]
+    #[doc = r" returned by [`aliases`](Self::aliases)."]
+    #[doc = r""]
+    #[doc = r" Returns [`None`] if the value is not a known code."]
+    pub const fn name(self) -> Option<&'static str> {
+        let index = self.0.wrapping_sub(0u32) as usize;
+        if index < Self::NAMES_BY_VALUE.len() {
+            Self::NAMES_BY_VALUE[index]
+        } else {
+            None
+        }
+    }
+}
+impl EventType {
+    #[doc = r" Returns the other names of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" The canonical name returned by [`name`](Self::name) is not included. Returns an empty slice if the"]
+    #[doc = r" code has no other names or is not a known code."]
+    pub const fn aliases(self) -> &'static [&'static str] {
+        &[]
+    }
+}
+impl EventType {
+    const NAMES_BY_VALUE: &'static [Option<&'static str>] = &[
+        Some("EV_SYN"),
+        Some("EV_KEY"),
+        Some("EV_REL"),
+        Some("EV_ABS"),
+        Some("EV_MSC"),
+        Some("EV_SW"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("EV_LED"),
+        Some("EV_SND"),
+        None,
+        Some("EV_REP"),
+        Some("EV_FF"),
+        Some("EV_PWR"),
+        Some("EV_FF_STATUS"),
+    ];
+    const NAME_MAP: crate::phf::NameMap<Self> = crate::phf::NameMap {
+        seeds: &[51u32, 17u32, 1u32, 20u32, 247u32, 299u32],
+        entries: &[
+            ("EV_LED", Self(17u32)),
+            ("EV_SYN", Self(0u32)),
+            ("SW", Self(5u32)),
+            ("SYN", Self(0u32)),
+            ("SND", Self(18u32)),
+            ("FF_STATUS", Self(23u32)),
+            ("EV_REP", Self(20u32)),
+            ("KEY", Self(1u32)),
+            ("EV_PWR", Self(22u32)),
+            ("REP", Self(20u32)),
+            ("REL", Self(2u32)),
+            ("EV_KEY", Self(1u32)),
+            ("EV_REL", Self(2u32)),
+            ("MSC", Self(4u32)),
+            ("ABS", Self(3u32)),
+            ("EV_FF_STATUS", Self(23u32)),
+            ("LED", Self(17u32)),
+            ("EV_SW", Self(5u32)),
+            ("EV_MSC", Self(4u32)),
+            ("EV_SND", Self(18u32)),
+            ("EV_FF", Self(21u32)),
+            ("EV_ABS", Self(3u32)),
+            ("FF", Self(21u32)),
+            ("PWR", Self(22u32)),
+        ],
+    };
+    #[doc = r" Looks up a code by name."]
+    #[doc = r""]
+    #[doc = r" Both the name defined in the kernel headers (`KEY_LEFTMETA`) and the name of the associated"]
+    #[doc = r" constant (`LEFTMETA`) are accepted. The name of a constant which starts with a digit may be given"]
+    #[doc = r" with or without its leading underscore (`_0` or `0`). The name is case sensitive, see"]
+    #[doc = r" [`from_name_ignore_case`](Self::from_name_ignore_case) for a case insensitive lookup."]
+    pub fn from_name(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, false)
+            .ok_or(crate::ParseCodeError::new("EventType"))
+    }
+    #[doc = r" Looks up a code by name, ignoring ASCII case."]
+    #[doc = r""]
+    #[doc = r" This accepts the same names as [`from_name`](Self::from_name)."]
+    pub fn from_name_ignore_case(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, true)
+            .ok_or(crate::ParseCodeError::new("EventType"))
+    }
+}
+impl EventType {
+    #[doc = r" Every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" Codes with multiple names are only listed once, and values which only describe the range of codes"]
+    #[doc = r" (such as `MAX`) are not included."]
+    pub const ALL: &'static [Self] = &[
+        Self(0u32),
+        Self(1u32),
+        Self(2u32),
+        Self(3u32),
+        Self(4u32),
+        Self(5u32),
+        Self(17u32),
+        Self(18u32),
+        Self(20u32),
+        Self(21u32),
+        Self(22u32),
+        Self(23u32),
+    ];
+    #[doc = r" Returns an iterator over every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" This iterates over [`ALL`](Self::ALL)."]
+    pub fn iter() -> ::core::iter::Copied<::core::slice::Iter<'static, Self>> {
+        Self::ALL.iter().copied()
+    }
+}
+impl ::core::str::FromStr for EventType {
+    type Err = crate::ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s)
+    }
+}
+impl ::core::fmt::Debug for EventType {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(self, f)
+    }
+}
+impl ::core::fmt::Display for EventType {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.name() {
+            Some(name) => f.pad(name),
+            None => write!(f, "EV_{:#x}", self.0),
+        }
+    }
+}
 impl From<u32> for EventType {
     fn from(value: u32) -> Self {
         Self(value)
@@ -484,14 +2151,425 @@ impl EventType {
     pub const PWR: EventType = EventType(22u32);
     #[doc(alias = "EV_FF_STATUS")]
     pub const FF_STATUS: EventType = EventType(23u32);
+}
+impl EventType {
+    #[doc = r" The largest value a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is a bound rather than a code, and may have the same value as a code."]
     #[doc(alias = "EV_MAX")]
-    pub const MAX: EventType = EventType(31u32);
+    pub const MAX: Self = Self(31u32);
+    #[doc = r" The number of values a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is the size of the bitmaps the kernel uses to describe the supported codes."]
     #[doc(alias = "EV_CNT")]
-    pub const CNT: EventType = EventType(32u32);
+    pub const COUNT: usize = 32usize;
+}
+#[doc = "Force feedback effect types, waveforms and device properties."]
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ForceFeedback(u32);
+impl ForceFeedback {
+    pub const fn new(value: u32) -> Self {
+        Self(value)
+    }
+    pub const fn into_inner(self) -> u32 {
+        self.0
+    }
+}
+impl ForceFeedback {
+    #[doc = r" Returns the name of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" If the code has multiple names, the canonical name is returned. The other names of the code are"]
+    #[doc = r" returned by [`aliases`](Self::aliases)."]
+    #[doc = r""]
+    #[doc = r" Returns [`None`] if the value is not a known code."]
+    pub const fn name(self) -> Option<&'static str> {
+        let index = self.0.wrapping_sub(80u32) as usize;
+        if index < Self::NAMES_BY_VALUE.len() {
+            Self::NAMES_BY_VALUE[index]
+        } else {
+            None
+        }
+    }
+}
+impl ForceFeedback {
+    #[doc = r" Returns the other names of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" The canonical name returned by [`name`](Self::name) is not included. Returns an empty slice if the"]
+    #[doc = r" code has no other names or is not a known code."]
+    pub const fn aliases(self) -> &'static [&'static str] {
+        &[]
+    }
+}
+impl ForceFeedback {
+    const NAMES_BY_VALUE: &'static [Option<&'static str>] = &[
+        Some("FF_RUMBLE"),
+        Some("FF_PERIODIC"),
+        Some("FF_CONSTANT"),
+        Some("FF_SPRING"),
+        Some("FF_FRICTION"),
+        Some("FF_DAMPER"),
+        Some("FF_INERTIA"),
+        Some("FF_RAMP"),
+        Some("FF_SQUARE"),
+        Some("FF_TRIANGLE"),
+        Some("FF_SINE"),
+        Some("FF_SAW_UP"),
+        Some("FF_SAW_DOWN"),
+        Some("FF_CUSTOM"),
+        None,
+        None,
+        Some("FF_GAIN"),
+        Some("FF_AUTOCENTER"),
+    ];
+    const NAME_MAP: crate::phf::NameMap<Self> = crate::phf::NameMap {
+        seeds: &[12u32, 3u32, 39u32, 25u32, 27u32, 36u32, 83u32, 367u32],
+        entries: &[
+            ("FF_SAW_UP", Self(91u32)),
+            ("FF_CUSTOM", Self(93u32)),
+            ("TRIANGLE", Self(89u32)),
+            ("DAMPER", Self(85u32)),
+            ("PERIODIC", Self(81u32)),
+            ("FF_SPRING", Self(83u32)),
+            ("CONSTANT", Self(82u32)),
+            ("FF_FRICTION", Self(84u32)),
+            ("RUMBLE", Self(80u32)),
+            ("FF_GAIN", Self(96u32)),
+            ("SPRING", Self(83u32)),
+            ("SQUARE", Self(88u32)),
+            ("FF_RUMBLE", Self(80u32)),
+            ("FF_AUTOCENTER", Self(97u32)),
+            ("CUSTOM", Self(93u32)),
+            ("FF_TRIANGLE", Self(89u32)),
+            ("FF_PERIODIC", Self(81u32)),
+            ("AUTOCENTER", Self(97u32)),
+            ("FF_SQUARE", Self(88u32)),
+            ("FF_RAMP", Self(87u32)),
+            ("RAMP", Self(87u32)),
+            ("FRICTION", Self(84u32)),
+            ("FF_SAW_DOWN", Self(92u32)),
+            ("GAIN", Self(96u32)),
+            ("SAW_UP", Self(91u32)),
+            ("FF_DAMPER", Self(85u32)),
+            ("INERTIA", Self(86u32)),
+            ("FF_INERTIA", Self(86u32)),
+            ("FF_SINE", Self(90u32)),
+            ("SINE", Self(90u32)),
+            ("FF_CONSTANT", Self(82u32)),
+            ("SAW_DOWN", Self(92u32)),
+        ],
+    };
+    #[doc = r" Looks up a code by name."]
+    #[doc = r""]
+    #[doc = r" Both the name defined in the kernel headers (`KEY_LEFTMETA`) and the name of the associated"]
+    #[doc = r" constant (`LEFTMETA`) are accepted. The name of a constant which starts with a digit may be given"]
+    #[doc = r" with or without its leading underscore (`_0` or `0`). The name is case sensitive, see"]
+    #[doc = r" [`from_name_ignore_case`](Self::from_name_ignore_case) for a case insensitive lookup."]
+    pub fn from_name(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, false)
+            .ok_or(crate::ParseCodeError::new("ForceFeedback"))
+    }
+    #[doc = r" Looks up a code by name, ignoring ASCII case."]
+    #[doc = r""]
+    #[doc = r" This accepts the same names as [`from_name`](Self::from_name)."]
+    pub fn from_name_ignore_case(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, true)
+            .ok_or(crate::ParseCodeError::new("ForceFeedback"))
+    }
+}
+impl ForceFeedback {
+    #[doc = r" Every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" Codes with multiple names are only listed once, and values which only describe the range of codes"]
+    #[doc = r" (such as `MAX`) are not included."]
+    pub const ALL: &'static [Self] = &[
+        Self(80u32),
+        Self(81u32),
+        Self(82u32),
+        Self(83u32),
+        Self(84u32),
+        Self(85u32),
+        Self(86u32),
+        Self(87u32),
+        Self(88u32),
+        Self(89u32),
+        Self(90u32),
+        Self(91u32),
+        Self(92u32),
+        Self(93u32),
+        Self(96u32),
+        Self(97u32),
+    ];
+    #[doc = r" Returns an iterator over every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" This iterates over [`ALL`](Self::ALL)."]
+    pub fn iter() -> ::core::iter::Copied<::core::slice::Iter<'static, Self>> {
+        Self::ALL.iter().copied()
+    }
+}
+impl ::core::str::FromStr for ForceFeedback {
+    type Err = crate::ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s)
+    }
+}
+impl ::core::fmt::Debug for ForceFeedback {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(self, f)
+    }
+}
+impl ::core::fmt::Display for ForceFeedback {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.name() {
+            Some(name) => f.pad(name),
+            None => write!(f, "FF_{:#x}", self.0),
+        }
+    }
+}
+impl From<u32> for ForceFeedback {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<ForceFeedback> for u32 {
+    fn from(value: ForceFeedback) -> u32 {
+        value.0
+    }
+}
+impl AsRef<u32> for ForceFeedback {
+    fn as_ref(&self) -> &u32 {
+        &self.0
+    }
+}
+impl ::core::borrow::Borrow<u32> for ForceFeedback {
+    fn borrow(&self) -> &u32 {
+        &self.0
+    }
+}
+impl PartialEq<u32> for ForceFeedback {
+    fn eq(&self, other: &u32) -> bool {
+        &self.0 == other
+    }
+}
+impl ForceFeedback {
+    #[doc(alias = "FF_RUMBLE")]
+    pub const RUMBLE: ForceFeedback = ForceFeedback(80u32);
+    #[doc(alias = "FF_PERIODIC")]
+    pub const PERIODIC: ForceFeedback = ForceFeedback(81u32);
+    #[doc(alias = "FF_CONSTANT")]
+    pub const CONSTANT: ForceFeedback = ForceFeedback(82u32);
+    #[doc(alias = "FF_SPRING")]
+    pub const SPRING: ForceFeedback = ForceFeedback(83u32);
+    #[doc(alias = "FF_FRICTION")]
+    pub const FRICTION: ForceFeedback = ForceFeedback(84u32);
+    #[doc(alias = "FF_DAMPER")]
+    pub const DAMPER: ForceFeedback = ForceFeedback(85u32);
+    #[doc(alias = "FF_INERTIA")]
+    pub const INERTIA: ForceFeedback = ForceFeedback(86u32);
+    #[doc(alias = "FF_RAMP")]
+    pub const RAMP: ForceFeedback = ForceFeedback(87u32);
+    #[doc(alias = "FF_SQUARE")]
+    pub const SQUARE: ForceFeedback = ForceFeedback(88u32);
+    #[doc(alias = "FF_TRIANGLE")]
+    pub const TRIANGLE: ForceFeedback = ForceFeedback(89u32);
+    #[doc(alias = "FF_SINE")]
+    pub const SINE: ForceFeedback = ForceFeedback(90u32);
+    #[doc(alias = "FF_SAW_UP")]
+    pub const SAW_UP: ForceFeedback = ForceFeedback(91u32);
+    #[doc(alias = "FF_SAW_DOWN")]
+    pub const SAW_DOWN: ForceFeedback = ForceFeedback(92u32);
+    #[doc(alias = "FF_CUSTOM")]
+    pub const CUSTOM: ForceFeedback = ForceFeedback(93u32);
+    #[doc(alias = "FF_GAIN")]
+    pub const GAIN: ForceFeedback = ForceFeedback(96u32);
+    #[doc(alias = "FF_AUTOCENTER")]
+    pub const AUTOCENTER: ForceFeedback = ForceFeedback(97u32);
 }
+impl ForceFeedback {
+    #[doc = "The first effect code."]
+    #[doc = r""]
+    #[doc = r" This is a bound rather than a code, and has the same value as a code."]
+    #[doc(alias = "FF_EFFECT_MIN")]
+    pub const EFFECT_MIN: Self = Self(80u32);
+    #[doc = "The last effect code."]
+    #[doc = r""]
+    #[doc = r" This is a bound rather than a code, and has the same value as a code."]
+    #[doc(alias = "FF_EFFECT_MAX")]
+    pub const EFFECT_MAX: Self = Self(87u32);
+    #[doc = "The first waveform code."]
+    #[doc = r""]
+    #[doc = r" This is a bound rather than a code, and has the same value as a code."]
+    #[doc(alias = "FF_WAVEFORM_MIN")]
+    pub const WAVEFORM_MIN: Self = Self(88u32);
+    #[doc = "The last waveform code."]
+    #[doc = r""]
+    #[doc = r" This is a bound rather than a code, and has the same value as a code."]
+    #[doc(alias = "FF_WAVEFORM_MAX")]
+    pub const WAVEFORM_MAX: Self = Self(93u32);
+    #[doc = r" The largest number of effects a device may have uploaded at once."]
+    #[doc(alias = "FF_MAX_EFFECTS")]
+    pub const MAX_EFFECTS: usize = 96usize;
+    #[doc = r" The largest value a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is a bound rather than a code, and may have the same value as a code."]
+    #[doc(alias = "FF_MAX")]
+    pub const MAX: Self = Self(127u32);
+    #[doc = r" The number of values a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is the size of the bitmaps the kernel uses to describe the supported codes."]
+    #[doc(alias = "FF_CNT")]
+    pub const COUNT: usize = 128usize;
+}
+#[doc = "Indices of the fields of an input id, when read as an array of `u16`."]
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InputIdField(u32);
+impl InputIdField {
+    pub const fn new(value: u32) -> Self {
+        Self(value)
+    }
+    pub const fn into_inner(self) -> u32 {
+        self.0
+    }
+}
+impl InputIdField {
+    #[doc = r" Returns the name of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" If the code has multiple names, the canonical name is returned. The other names of the code are"]
+    #[doc = r" returned by [`aliases`](Self::aliases)."]
+    #[doc = r""]
+    #[doc = r" Returns [`None`] if the value is not a known code."]
+    pub const fn name(self) -> Option<&'static str> {
+        let index = self.0.wrapping_sub(0u32) as usize;
+        if index < Self::NAMES_BY_VALUE.len() {
+            Self::NAMES_BY_VALUE[index]
+        } else {
+            None
+        }
+    }
+}
+impl InputIdField {
+    #[doc = r" Returns the other names of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" The canonical name returned by [`name`](Self::name) is not included. Returns an empty slice if the"]
+    #[doc = r" code has no other names or is not a known code."]
+    pub const fn aliases(self) -> &'static [&'static str] {
+        &[]
+    }
+}
+impl InputIdField {
+    const NAMES_BY_VALUE: &'static [Option<&'static str>] = &[
+        Some("ID_BUS"),
+        Some("ID_VENDOR"),
+        Some("ID_PRODUCT"),
+        Some("ID_VERSION"),
+    ];
+    const NAME_MAP: crate::phf::NameMap<Self> = crate::phf::NameMap {
+        seeds: &[73u32, 3u32],
+        entries: &[
+            ("VENDOR", Self(1u32)),
+            ("BUS", Self(0u32)),
+            ("ID_PRODUCT", Self(2u32)),
+            ("PRODUCT", Self(2u32)),
+            ("ID_BUS", Self(0u32)),
+            ("ID_VENDOR", Self(1u32)),
+            ("VERSION", Self(3u32)),
+            ("ID_VERSION", Self(3u32)),
+        ],
+    };
+    #[doc = r" Looks up a code by name."]
+    #[doc = r""]
+    #[doc = r" Both the name defined in the kernel headers (`KEY_LEFTMETA`) and the name of the associated"]
+    #[doc = r" constant (`LEFTMETA`) are accepted. The name of a constant which starts with a digit may be given"]
+    #[doc = r" with or without its leading underscore (`_0` or `0`). The name is case sensitive, see"]
+    #[doc = r" [`from_name_ignore_case`](Self::from_name_ignore_case) for a case insensitive lookup."]
+    pub fn from_name(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, false)
+            .ok_or(crate::ParseCodeError::new("InputIdField"))
+    }
+    #[doc = r" Looks up a code by name, ignoring ASCII case."]
+    #[doc = r""]
+    #[doc = r" This accepts the same names as [`from_name`](Self::from_name)."]
+    pub fn from_name_ignore_case(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, true)
+            .ok_or(crate::ParseCodeError::new("InputIdField"))
+    }
+}
+impl InputIdField {
+    #[doc = r" Every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" Codes with multiple names are only listed once, and values which only describe the range of codes"]
+    #[doc = r" (such as `MAX`) are not included."]
+    pub const ALL: &'static [Self] = &[Self(0u32), Self(1u32), Self(2u32), Self(3u32)];
+    #[doc = r" Returns an iterator over every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" This iterates over [`ALL`](Self::ALL)."]
+    pub fn iter() -> ::core::iter::Copied<::core::slice::Iter<'static, Self>> {
+        Self::ALL.iter().copied()
+    }
+}
+impl ::core::str::FromStr for InputIdField {
+    type Err = crate::ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s)
+    }
+}
+impl ::core::fmt::Debug for InputIdField {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(self, f)
+    }
+}
+impl ::core::fmt::Display for InputIdField {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.name() {
+            Some(name) => f.pad(name),
+            None => write!(f, "ID_{:#x}", self.0),
+        }
+    }
+}
+impl From<u32> for InputIdField {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<InputIdField> for u32 {
+    fn from(value: InputIdField) -> u32 {
+        value.0
+    }
+}
+impl AsRef<u32> for InputIdField {
+    fn as_ref(&self) -> &u32 {
+        &self.0
+    }
+}
+impl ::core::borrow::Borrow<u32> for InputIdField {
+    fn borrow(&self) -> &u32 {
+        &self.0
+    }
+}
+impl PartialEq<u32> for InputIdField {
+    fn eq(&self, other: &u32) -> bool {
+        &self.0 == other
+    }
+}
+impl InputIdField {
+    #[doc(alias = "ID_BUS")]
+    pub const BUS: InputIdField = InputIdField(0u32);
+    #[doc(alias = "ID_VENDOR")]
+    pub const VENDOR: InputIdField = InputIdField(1u32);
+    #[doc(alias = "ID_PRODUCT")]
+    pub const PRODUCT: InputIdField = InputIdField(2u32);
+    #[doc(alias = "ID_VERSION")]
+    pub const VERSION: InputIdField = InputIdField(3u32);
+}
+impl InputIdField {}
 #[doc = "Device properties and quirks."]
 #[repr(transparent)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct InputQuirk(u32);
 impl InputQuirk {
     pub const fn new(value: u32) -> Self {
@@ -501,6 +2579,120 @@ impl InputQuirk {
         self.0
     }
 }
+impl InputQuirk {
+    #[doc = r" Returns the name of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" If the code has multiple names, the canonical name is returned. The other names of the code are"]
+    #[doc = r" returned by [`aliases`](Self::aliases)."]
+    #[doc = r""]
+    #[doc = r" Returns [`None`] if the value is not a known code."]
+    pub const fn name(self) -> Option<&'static str> {
+        let index = self.0.wrapping_sub(0u32) as usize;
+        if index < Self::NAMES_BY_VALUE.len() {
+            Self::NAMES_BY_VALUE[index]
+        } else {
+            None
+        }
+    }
+}
+impl InputQuirk {
+    #[doc = r" Returns the other names of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" The canonical name returned by [`name`](Self::name) is not included. Returns an empty slice if the"]
+    #[doc = r" code has no other names or is not a known code."]
+    pub const fn aliases(self) -> &'static [&'static str] {
+        &[]
+    }
+}
+impl InputQuirk {
+    const NAMES_BY_VALUE: &'static [Option<&'static str>] = &[
+        Some("INPUT_PROP_POINTER"),
+        Some("INPUT_PROP_DIRECT"),
+        Some("INPUT_PROP_BUTTONPAD"),
+        Some("INPUT_PROP_SEMI_MT"),
+        Some("INPUT_PROP_TOPBUTTONPAD"),
+        Some("INPUT_PROP_POINTING_STICK"),
+        Some("INPUT_PROP_ACCELEROMETER"),
+    ];
+    const NAME_MAP: crate::phf::NameMap<Self> = crate::phf::NameMap {
+        seeds: &[2u32, 25u32, 3u32, 87u32],
+        entries: &[
+            ("PROP_SEMI_MT", Self(3u32)),
+            ("INPUT_PROP_POINTING_STICK", Self(5u32)),
+            ("INPUT_PROP_BUTTONPAD", Self(2u32)),
+            ("INPUT_PROP_POINTER", Self(0u32)),
+            ("INPUT_PROP_DIRECT", Self(1u32)),
+            ("PROP_ACCELEROMETER", Self(6u32)),
+            ("PROP_DIRECT", Self(1u32)),
+            ("PROP_TOPBUTTONPAD", Self(4u32)),
+            ("INPUT_PROP_ACCELEROMETER", Self(6u32)),
+            ("PROP_BUTTONPAD", Self(2u32)),
+            ("INPUT_PROP_TOPBUTTONPAD", Self(4u32)),
+            ("INPUT_PROP_SEMI_MT", Self(3u32)),
+            ("PROP_POINTER", Self(0u32)),
+            ("PROP_POINTING_STICK", Self(5u32)),
+        ],
+    };
+    #[doc = r" Looks up a code by name."]
+    #[doc = r""]
+    #[doc = r" Both the name defined in the kernel headers (`KEY_LEFTMETA`) and the name of the associated"]
+    #[doc = r" constant (`LEFTMETA`) are accepted. The name of a constant which starts with a digit may be given"]
+    #[doc = r" with or without its leading underscore (`_0` or `0`). The name is case sensitive, see"]
+    #[doc = r" [`from_name_ignore_case`](Self::from_name_ignore_case) for a case insensitive lookup."]
+    pub fn from_name(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, false)
+            .ok_or(crate::ParseCodeError::new("InputQuirk"))
+    }
+    #[doc = r" Looks up a code by name, ignoring ASCII case."]
+    #[doc = r""]
+    #[doc = r" This accepts the same names as [`from_name`](Self::from_name)."]
+    pub fn from_name_ignore_case(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, true)
+            .ok_or(crate::ParseCodeError::new("InputQuirk"))
+    }
+}
+impl InputQuirk {
+    #[doc = r" Every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" Codes with multiple names are only listed once, and values which only describe the range of codes"]
+    #[doc = r" (such as `MAX`) are not included."]
+    pub const ALL: &'static [Self] = &[
+        Self(0u32),
+        Self(1u32),
+        Self(2u32),
+        Self(3u32),
+        Self(4u32),
+        Self(5u32),
+        Self(6u32),
+    ];
+    #[doc = r" Returns an iterator over every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" This iterates over [`ALL`](Self::ALL)."]
+    pub fn iter() -> ::core::iter::Copied<::core::slice::Iter<'static, Self>> {
+        Self::ALL.iter().copied()
+    }
+}
+impl ::core::str::FromStr for InputQuirk {
+    type Err = crate::ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s)
+    }
+}
+impl ::core::fmt::Debug for InputQuirk {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(self, f)
+    }
+}
+impl ::core::fmt::Display for InputQuirk {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.name() {
+            Some(name) => f.pad(name),
+            None => write!(f, "INPUT_PROP_{:#x}", self.0),
+        }
+    }
+}
 impl From<u32> for InputQuirk {
     fn from(value: u32) -> Self {
         Self(value)
@@ -548,13 +2740,21 @@ impl InputQuirk {
     #[doc = " has accelerometer "]
     #[doc(alias = "INPUT_PROP_ACCELEROMETER")]
     pub const PROP_ACCELEROMETER: InputQuirk = InputQuirk(6u32);
+}
+impl InputQuirk {
+    #[doc = r" The largest value a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is a bound rather than a code, and may have the same value as a code."]
     #[doc(alias = "INPUT_PROP_MAX")]
-    pub const PROP_MAX: InputQuirk = InputQuirk(31u32);
+    pub const MAX: Self = Self(31u32);
+    #[doc = r" The number of values a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is the size of the bitmaps the kernel uses to describe the supported codes."]
     #[doc(alias = "INPUT_PROP_CNT")]
-    pub const PROP_CNT: InputQuirk = InputQuirk(32u32);
+    pub const COUNT: usize = 32usize;
 }
 #[repr(transparent)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Key(u32);
 impl Key {
     pub const fn new(value: u32) -> Self {
@@ -564,6 +2764,2314 @@ impl Key {
         self.0
     }
 }
+impl Key {
+    #[doc = r" Returns the name of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" If the code has multiple names, the canonical name is returned. The other names of the code are"]
+    #[doc = r" returned by [`aliases`](Self::aliases)."]
+    #[doc = r""]
+    #[doc = r" Returns [`None`] if the value is not a known code."]
+    pub const fn name(self) -> Option<&'static str> {
+        let index = self.0.wrapping_sub(0u32) as usize;
+        if index < Self::NAMES_BY_VALUE.len() {
+            Self::NAMES_BY_VALUE[index]
+        } else {
+            None
+        }
+    }
+}
+impl Key {
+    #[doc = r" Returns the other names of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" The canonical name returned by [`name`](Self::name) is not included. Returns an empty slice if the"]
+    #[doc = r" code has no other names or is not a known code."]
+    pub const fn aliases(self) -> &'static [&'static str] {
+        match self.0 {
+            122u32 => &["KEY_HANGUEL"],
+            152u32 => &["KEY_SCREENLOCK"],
+            153u32 => &["KEY_DIRECTION"],
+            244u32 => &["KEY_BRIGHTNESS_ZERO"],
+            246u32 => &["KEY_WIMAX"],
+            372u32 => &["KEY_ZOOM"],
+            375u32 => &["KEY_SCREEN"],
+            431u32 => &["KEY_BRIGHTNESS_TOGGLE"],
+            _ => &[],
+        }
+    }
+}
+impl Key {
+    const NAMES_BY_VALUE: &'static [Option<&'static str>] = &[
+        Some("KEY_RESERVED"),
+        Some("KEY_ESC"),
+        Some("KEY_1"),
+        Some("KEY_2"),
+        Some("KEY_3"),
+        Some("KEY_4"),
+        Some("KEY_5"),
+        Some("KEY_6"),
+        Some("KEY_7"),
+        Some("KEY_8"),
+        Some("KEY_9"),
+        Some("KEY_0"),
+        Some("KEY_MINUS"),
+        Some("KEY_EQUAL"),
+        Some("KEY_BACKSPACE"),
+        Some("KEY_TAB"),
+        Some("KEY_Q"),
+        Some("KEY_W"),
+        Some("KEY_E"),
+        Some("KEY_R"),
+        Some("KEY_T"),
+        Some("KEY_Y"),
+        Some("KEY_U"),
+        Some("KEY_I"),
+        Some("KEY_O"),
+        Some("KEY_P"),
+        Some("KEY_LEFTBRACE"),
+        Some("KEY_RIGHTBRACE"),
+        Some("KEY_ENTER"),
+        Some("KEY_LEFTCTRL"),
+        Some("KEY_A"),
+        Some("KEY_S"),
+        Some("KEY_D"),
+        Some("KEY_F"),
+        Some("KEY_G"),
+        Some("KEY_H"),
+        Some("KEY_J"),
+        Some("KEY_K"),
+        Some("KEY_L"),
+        Some("KEY_SEMICOLON"),
+        Some("KEY_APOSTROPHE"),
+        Some("KEY_GRAVE"),
+        Some("KEY_LEFTSHIFT"),
+        Some("KEY_BACKSLASH"),
+        Some("KEY_Z"),
+        Some("KEY_X"),
+        Some("KEY_C"),
+        Some("KEY_V"),
+        Some("KEY_B"),
+        Some("KEY_N"),
+        Some("KEY_M"),
+        Some("KEY_COMMA"),
+        Some("KEY_DOT"),
+        Some("KEY_SLASH"),
+        Some("KEY_RIGHTSHIFT"),
+        Some("KEY_KPASTERISK"),
+        Some("KEY_LEFTALT"),
+        Some("KEY_SPACE"),
+        Some("KEY_CAPSLOCK"),
+        Some("KEY_F1"),
+        Some("KEY_F2"),
+        Some("KEY_F3"),
+        Some("KEY_F4"),
+        Some("KEY_F5"),
+        Some("KEY_F6"),
+        Some("KEY_F7"),
+        Some("KEY_F8"),
+        Some("KEY_F9"),
+        Some("KEY_F10"),
+        Some("KEY_NUMLOCK"),
+        Some("KEY_SCROLLLOCK"),
+        Some("KEY_KP7"),
+        Some("KEY_KP8"),
+        Some("KEY_KP9"),
+        Some("KEY_KPMINUS"),
+        Some("KEY_KP4"),
+        Some("KEY_KP5"),
+        Some("KEY_KP6"),
+        Some("KEY_KPPLUS"),
+        Some("KEY_KP1"),
+        Some("KEY_KP2"),
+        Some("KEY_KP3"),
+        Some("KEY_KP0"),
+        Some("KEY_KPDOT"),
+        None,
+        Some("KEY_ZENKAKUHANKAKU"),
+        Some("KEY_102ND"),
+        Some("KEY_F11"),
+        Some("KEY_F12"),
+        Some("KEY_RO"),
+        Some("KEY_KATAKANA"),
+        Some("KEY_HIRAGANA"),
+        Some("KEY_HENKAN"),
+        Some("KEY_KATAKANAHIRAGANA"),
+        Some("KEY_MUHENKAN"),
+        Some("KEY_KPJPCOMMA"),
+        Some("KEY_KPENTER"),
+        Some("KEY_RIGHTCTRL"),
+        Some("KEY_KPSLASH"),
+        Some("KEY_SYSRQ"),
+        Some("KEY_RIGHTALT"),
+        Some("KEY_LINEFEED"),
+        Some("KEY_HOME"),
+        Some("KEY_UP"),
+        Some("KEY_PAGEUP"),
+        Some("KEY_LEFT"),
+        Some("KEY_RIGHT"),
+        Some("KEY_END"),
+        Some("KEY_DOWN"),
+        Some("KEY_PAGEDOWN"),
+        Some("KEY_INSERT"),
+        Some("KEY_DELETE"),
+        Some("KEY_MACRO"),
+        Some("KEY_MUTE"),
+        Some("KEY_VOLUMEDOWN"),
+        Some("KEY_VOLUMEUP"),
+        Some("KEY_POWER"),
+        Some("KEY_KPEQUAL"),
+        Some("KEY_KPPLUSMINUS"),
+        Some("KEY_PAUSE"),
+        Some("KEY_SCALE"),
+        Some("KEY_KPCOMMA"),
+        Some("KEY_HANGEUL"),
+        Some("KEY_HANJA"),
+        Some("KEY_YEN"),
+        Some("KEY_LEFTMETA"),
+        Some("KEY_RIGHTMETA"),
+        Some("KEY_COMPOSE"),
+        Some("KEY_STOP"),
+        Some("KEY_AGAIN"),
+        Some("KEY_PROPS"),
+        Some("KEY_UNDO"),
+        Some("KEY_FRONT"),
+        Some("KEY_COPY"),
+        Some("KEY_OPEN"),
+        Some("KEY_PASTE"),
+        Some("KEY_FIND"),
+        Some("KEY_CUT"),
+        Some("KEY_HELP"),
+        Some("KEY_MENU"),
+        Some("KEY_CALC"),
+        Some("KEY_SETUP"),
+        Some("KEY_SLEEP"),
+        Some("KEY_WAKEUP"),
+        Some("KEY_FILE"),
+        Some("KEY_SENDFILE"),
+        Some("KEY_DELETEFILE"),
+        Some("KEY_XFER"),
+        Some("KEY_PROG1"),
+        Some("KEY_PROG2"),
+        Some("KEY_WWW"),
+        Some("KEY_MSDOS"),
+        Some("KEY_COFFEE"),
+        Some("KEY_ROTATE_DISPLAY"),
+        Some("KEY_CYCLEWINDOWS"),
+        Some("KEY_MAIL"),
+        Some("KEY_BOOKMARKS"),
+        Some("KEY_COMPUTER"),
+        Some("KEY_BACK"),
+        Some("KEY_FORWARD"),
+        Some("KEY_CLOSECD"),
+        Some("KEY_EJECTCD"),
+        Some("KEY_EJECTCLOSECD"),
+        Some("KEY_NEXTSONG"),
+        Some("KEY_PLAYPAUSE"),
+        Some("KEY_PREVIOUSSONG"),
+        Some("KEY_STOPCD"),
+        Some("KEY_RECORD"),
+        Some("KEY_REWIND"),
+        Some("KEY_PHONE"),
+        Some("KEY_ISO"),
+        Some("KEY_CONFIG"),
+        Some("KEY_HOMEPAGE"),
+        Some("KEY_REFRESH"),
+        Some("KEY_EXIT"),
+        Some("KEY_MOVE"),
+        Some("KEY_EDIT"),
+        Some("KEY_SCROLLUP"),
+        Some("KEY_SCROLLDOWN"),
+        Some("KEY_KPLEFTPAREN"),
+        Some("KEY_KPRIGHTPAREN"),
+        Some("KEY_NEW"),
+        Some("KEY_REDO"),
+        Some("KEY_F13"),
+        Some("KEY_F14"),
+        Some("KEY_F15"),
+        Some("KEY_F16"),
+        Some("KEY_F17"),
+        Some("KEY_F18"),
+        Some("KEY_F19"),
+        Some("KEY_F20"),
+        Some("KEY_F21"),
+        Some("KEY_F22"),
+        Some("KEY_F23"),
+        Some("KEY_F24"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("KEY_PLAYCD"),
+        Some("KEY_PAUSECD"),
+        Some("KEY_PROG3"),
+        Some("KEY_PROG4"),
+        Some("KEY_DASHBOARD"),
+        Some("KEY_SUSPEND"),
+        Some("KEY_CLOSE"),
+        Some("KEY_PLAY"),
+        Some("KEY_FASTFORWARD"),
+        Some("KEY_BASSBOOST"),
+        Some("KEY_PRINT"),
+        Some("KEY_HP"),
+        Some("KEY_CAMERA"),
+        Some("KEY_SOUND"),
+        Some("KEY_QUESTION"),
+        Some("KEY_EMAIL"),
+        Some("KEY_CHAT"),
+        Some("KEY_SEARCH"),
+        Some("KEY_CONNECT"),
+        Some("KEY_FINANCE"),
+        Some("KEY_SPORT"),
+        Some("KEY_SHOP"),
+        Some("KEY_ALTERASE"),
+        Some("KEY_CANCEL"),
+        Some("KEY_BRIGHTNESSDOWN"),
+        Some("KEY_BRIGHTNESSUP"),
+        Some("KEY_MEDIA"),
+        Some("KEY_SWITCHVIDEOMODE"),
+        Some("KEY_KBDILLUMTOGGLE"),
+        Some("KEY_KBDILLUMDOWN"),
+        Some("KEY_KBDILLUMUP"),
+        Some("KEY_SEND"),
+        Some("KEY_REPLY"),
+        Some("KEY_FORWARDMAIL"),
+        Some("KEY_SAVE"),
+        Some("KEY_DOCUMENTS"),
+        Some("KEY_BATTERY"),
+        Some("KEY_BLUETOOTH"),
+        Some("KEY_WLAN"),
+        Some("KEY_UWB"),
+        Some("KEY_UNKNOWN"),
+        Some("KEY_VIDEO_NEXT"),
+        Some("KEY_VIDEO_PREV"),
+        Some("KEY_BRIGHTNESS_CYCLE"),
+        Some("KEY_BRIGHTNESS_AUTO"),
+        Some("KEY_DISPLAY_OFF"),
+        Some("KEY_WWAN"),
+        Some("KEY_RFKILL"),
+        Some("KEY_MICMUTE"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("KEY_OK"),
+        Some("KEY_SELECT"),
+        Some("KEY_GOTO"),
+        Some("KEY_CLEAR"),
+        Some("KEY_POWER2"),
+        Some("KEY_OPTION"),
+        Some("KEY_INFO"),
+        Some("KEY_TIME"),
+        Some("KEY_VENDOR"),
+        Some("KEY_ARCHIVE"),
+        Some("KEY_PROGRAM"),
+        Some("KEY_CHANNEL"),
+        Some("KEY_FAVORITES"),
+        Some("KEY_EPG"),
+        Some("KEY_PVR"),
+        Some("KEY_MHP"),
+        Some("KEY_LANGUAGE"),
+        Some("KEY_TITLE"),
+        Some("KEY_SUBTITLE"),
+        Some("KEY_ANGLE"),
+        Some("KEY_FULL_SCREEN"),
+        Some("KEY_MODE"),
+        Some("KEY_KEYBOARD"),
+        Some("KEY_ASPECT_RATIO"),
+        Some("KEY_PC"),
+        Some("KEY_TV"),
+        Some("KEY_TV2"),
+        Some("KEY_VCR"),
+        Some("KEY_VCR2"),
+        Some("KEY_SAT"),
+        Some("KEY_SAT2"),
+        Some("KEY_CD"),
+        Some("KEY_TAPE"),
+        Some("KEY_RADIO"),
+        Some("KEY_TUNER"),
+        Some("KEY_PLAYER"),
+        Some("KEY_TEXT"),
+        Some("KEY_DVD"),
+        Some("KEY_AUX"),
+        Some("KEY_MP3"),
+        Some("KEY_AUDIO"),
+        Some("KEY_VIDEO"),
+        Some("KEY_DIRECTORY"),
+        Some("KEY_LIST"),
+        Some("KEY_MEMO"),
+        Some("KEY_CALENDAR"),
+        Some("KEY_RED"),
+        Some("KEY_GREEN"),
+        Some("KEY_YELLOW"),
+        Some("KEY_BLUE"),
+        Some("KEY_CHANNELUP"),
+        Some("KEY_CHANNELDOWN"),
+        Some("KEY_FIRST"),
+        Some("KEY_LAST"),
+        Some("KEY_AB"),
+        Some("KEY_NEXT"),
+        Some("KEY_RESTART"),
+        Some("KEY_SLOW"),
+        Some("KEY_SHUFFLE"),
+        Some("KEY_BREAK"),
+        Some("KEY_PREVIOUS"),
+        Some("KEY_DIGITS"),
+        Some("KEY_TEEN"),
+        Some("KEY_TWEN"),
+        Some("KEY_VIDEOPHONE"),
+        Some("KEY_GAMES"),
+        Some("KEY_ZOOMIN"),
+        Some("KEY_ZOOMOUT"),
+        Some("KEY_ZOOMRESET"),
+        Some("KEY_WORDPROCESSOR"),
+        Some("KEY_EDITOR"),
+        Some("KEY_SPREADSHEET"),
+        Some("KEY_GRAPHICSEDITOR"),
+        Some("KEY_PRESENTATION"),
+        Some("KEY_DATABASE"),
+        Some("KEY_NEWS"),
+        Some("KEY_VOICEMAIL"),
+        Some("KEY_ADDRESSBOOK"),
+        Some("KEY_MESSENGER"),
+        Some("KEY_DISPLAYTOGGLE"),
+        Some("KEY_SPELLCHECK"),
+        Some("KEY_LOGOFF"),
+        Some("KEY_DOLLAR"),
+        Some("KEY_EURO"),
+        Some("KEY_FRAMEBACK"),
+        Some("KEY_FRAMEFORWARD"),
+        Some("KEY_CONTEXT_MENU"),
+        Some("KEY_MEDIA_REPEAT"),
+        Some("KEY_10CHANNELSUP"),
+        Some("KEY_10CHANNELSDOWN"),
+        Some("KEY_IMAGES"),
+        None,
+        Some("KEY_NOTIFICATION_CENTER"),
+        Some("KEY_PICKUP_PHONE"),
+        Some("KEY_HANGUP_PHONE"),
+        None,
+        Some("KEY_DEL_EOL"),
+        Some("KEY_DEL_EOS"),
+        Some("KEY_INS_LINE"),
+        Some("KEY_DEL_LINE"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("KEY_FN"),
+        Some("KEY_FN_ESC"),
+        Some("KEY_FN_F1"),
+        Some("KEY_FN_F2"),
+        Some("KEY_FN_F3"),
+        Some("KEY_FN_F4"),
+        Some("KEY_FN_F5"),
+        Some("KEY_FN_F6"),
+        Some("KEY_FN_F7"),
+        Some("KEY_FN_F8"),
+        Some("KEY_FN_F9"),
+        Some("KEY_FN_F10"),
+        Some("KEY_FN_F11"),
+        Some("KEY_FN_F12"),
+        Some("KEY_FN_1"),
+        Some("KEY_FN_2"),
+        Some("KEY_FN_D"),
+        Some("KEY_FN_E"),
+        Some("KEY_FN_F"),
+        Some("KEY_FN_S"),
+        Some("KEY_FN_B"),
+        Some("KEY_FN_RIGHT_SHIFT"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("KEY_BRL_DOT1"),
+        Some("KEY_BRL_DOT2"),
+        Some("KEY_BRL_DOT3"),
+        Some("KEY_BRL_DOT4"),
+        Some("KEY_BRL_DOT5"),
+        Some("KEY_BRL_DOT6"),
+        Some("KEY_BRL_DOT7"),
+        Some("KEY_BRL_DOT8"),
+        Some("KEY_BRL_DOT9"),
+        Some("KEY_BRL_DOT10"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("KEY_NUMERIC_0"),
+        Some("KEY_NUMERIC_1"),
+        Some("KEY_NUMERIC_2"),
+        Some("KEY_NUMERIC_3"),
+        Some("KEY_NUMERIC_4"),
+        Some("KEY_NUMERIC_5"),
+        Some("KEY_NUMERIC_6"),
+        Some("KEY_NUMERIC_7"),
+        Some("KEY_NUMERIC_8"),
+        Some("KEY_NUMERIC_9"),
+        Some("KEY_NUMERIC_STAR"),
+        Some("KEY_NUMERIC_POUND"),
+        Some("KEY_NUMERIC_A"),
+        Some("KEY_NUMERIC_B"),
+        Some("KEY_NUMERIC_C"),
+        Some("KEY_NUMERIC_D"),
+        Some("KEY_CAMERA_FOCUS"),
+        Some("KEY_WPS_BUTTON"),
+        Some("KEY_TOUCHPAD_TOGGLE"),
+        Some("KEY_TOUCHPAD_ON"),
+        Some("KEY_TOUCHPAD_OFF"),
+        Some("KEY_CAMERA_ZOOMIN"),
+        Some("KEY_CAMERA_ZOOMOUT"),
+        Some("KEY_CAMERA_UP"),
+        Some("KEY_CAMERA_DOWN"),
+        Some("KEY_CAMERA_LEFT"),
+        Some("KEY_CAMERA_RIGHT"),
+        Some("KEY_ATTENDANT_ON"),
+        Some("KEY_ATTENDANT_OFF"),
+        Some("KEY_ATTENDANT_TOGGLE"),
+        Some("KEY_LIGHTS_TOGGLE"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("KEY_ALS_TOGGLE"),
+        Some("KEY_ROTATE_LOCK_TOGGLE"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("KEY_BUTTONCONFIG"),
+        Some("KEY_TASKMANAGER"),
+        Some("KEY_JOURNAL"),
+        Some("KEY_CONTROLPANEL"),
+        Some("KEY_APPSELECT"),
+        Some("KEY_SCREENSAVER"),
+        Some("KEY_VOICECOMMAND"),
+        Some("KEY_ASSISTANT"),
+        Some("KEY_KBD_LAYOUT_NEXT"),
+        Some("KEY_EMOJI_PICKER"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("KEY_BRIGHTNESS_MIN"),
+        Some("KEY_BRIGHTNESS_MAX"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("KEY_KBDINPUTASSIST_PREV"),
+        Some("KEY_KBDINPUTASSIST_NEXT"),
+        Some("KEY_KBDINPUTASSIST_PREVGROUP"),
+        Some("KEY_KBDINPUTASSIST_NEXTGROUP"),
+        Some("KEY_KBDINPUTASSIST_ACCEPT"),
+        Some("KEY_KBDINPUTASSIST_CANCEL"),
+        Some("KEY_RIGHT_UP"),
+        Some("KEY_RIGHT_DOWN"),
+        Some("KEY_LEFT_UP"),
+        Some("KEY_LEFT_DOWN"),
+        Some("KEY_ROOT_MENU"),
+        Some("KEY_MEDIA_TOP_MENU"),
+        Some("KEY_NUMERIC_11"),
+        Some("KEY_NUMERIC_12"),
+        Some("KEY_AUDIO_DESC"),
+        Some("KEY_3D_MODE"),
+        Some("KEY_NEXT_FAVORITE"),
+        Some("KEY_STOP_RECORD"),
+        Some("KEY_PAUSE_RECORD"),
+        Some("KEY_VOD"),
+        Some("KEY_UNMUTE"),
+        Some("KEY_FASTREVERSE"),
+        Some("KEY_SLOWREVERSE"),
+        Some("KEY_DATA"),
+        Some("KEY_ONSCREEN_KEYBOARD"),
+        Some("KEY_PRIVACY_SCREEN_TOGGLE"),
+        Some("KEY_SELECTIVE_SCREENSHOT"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("KEY_MACRO1"),
+        Some("KEY_MACRO2"),
+        Some("KEY_MACRO3"),
+        Some("KEY_MACRO4"),
+        Some("KEY_MACRO5"),
+        Some("KEY_MACRO6"),
+        Some("KEY_MACRO7"),
+        Some("KEY_MACRO8"),
+        Some("KEY_MACRO9"),
+        Some("KEY_MACRO10"),
+        Some("KEY_MACRO11"),
+        Some("KEY_MACRO12"),
+        Some("KEY_MACRO13"),
+        Some("KEY_MACRO14"),
+        Some("KEY_MACRO15"),
+        Some("KEY_MACRO16"),
+        Some("KEY_MACRO17"),
+        Some("KEY_MACRO18"),
+        Some("KEY_MACRO19"),
+        Some("KEY_MACRO20"),
+        Some("KEY_MACRO21"),
+        Some("KEY_MACRO22"),
+        Some("KEY_MACRO23"),
+        Some("KEY_MACRO24"),
+        Some("KEY_MACRO25"),
+        Some("KEY_MACRO26"),
+        Some("KEY_MACRO27"),
+        Some("KEY_MACRO28"),
+        Some("KEY_MACRO29"),
+        Some("KEY_MACRO30"),
+        None,
+        None,
+        Some("KEY_MACRO_RECORD_START"),
+        Some("KEY_MACRO_RECORD_STOP"),
+        Some("KEY_MACRO_PRESET_CYCLE"),
+        Some("KEY_MACRO_PRESET1"),
+        Some("KEY_MACRO_PRESET2"),
+        Some("KEY_MACRO_PRESET3"),
+        None,
+        None,
+        Some("KEY_KBD_LCD_MENU1"),
+        Some("KEY_KBD_LCD_MENU2"),
+        Some("KEY_KBD_LCD_MENU3"),
+        Some("KEY_KBD_LCD_MENU4"),
+        Some("KEY_KBD_LCD_MENU5"),
+    ];
+    const NAME_MAP: crate::phf::NameMap<Self> = crate::phf::NameMap {
+        seeds: &[
+            15u32, 1u32, 77u32, 105u32, 41u32, 106u32, 19u32, 2u32, 28u32, 39u32, 72u32, 19u32,
+            49u32, 84u32, 114u32, 29u32, 6u32, 60u32, 25u32, 7u32, 21u32, 21u32, 12u32, 84u32,
+            1u32, 14u32, 55u32, 55u32, 17u32, 77u32, 4u32, 252u32, 99u32, 239u32, 13u32, 45u32,
+            122u32, 81u32, 26u32, 359u32, 43u32, 1u32, 484u32, 18u32, 190u32, 99u32, 2u32, 5u32,
+            303u32, 1u32, 23u32, 1u32, 20u32, 59u32, 2u32, 238u32, 65u32, 31u32, 1u32, 37u32,
+            131u32, 146u32, 40u32, 30u32, 56u32, 39u32, 516u32, 120u32, 195u32, 417u32, 9u32,
+            87u32, 12u32, 168u32, 309u32, 1u32, 32u32, 5u32, 2u32, 2u32, 1u32, 52u32, 1u32, 14u32,
+            85u32, 55u32, 184u32, 2u32, 87u32, 57u32, 1u32, 57u32, 90u32, 4u32, 36u32, 151u32,
+            115u32, 2u32, 124u32, 8u32, 35u32, 42u32, 9u32, 14u32, 1u32, 39u32, 2u32, 33u32, 2u32,
+            7u32, 400u32, 30u32, 133u32, 117u32, 52u32, 74u32, 4u32, 28u32, 123u32, 61u32, 11u32,
+            38u32, 53u32, 638u32, 10u32, 136u32, 207u32, 23u32, 334u32, 134u32, 127u32, 1u32,
+            414u32, 1u32, 6u32, 0u32, 10u32, 1u32, 27u32, 1u32, 142u32, 258u32, 57u32, 5u32, 27u32,
+            593u32, 1u32, 863u32, 6u32, 4u32, 373u32, 199u32, 77u32, 2u32, 58u32, 354u32, 106u32,
+            140u32, 1135u32, 3u32, 1321u32, 60u32, 240u32, 420u32, 1u32, 21u32, 8u32, 24u32,
+            296u32, 5u32, 57u32, 14u32, 133u32, 579u32, 8u32, 33u32, 8u32, 532u32, 11u32, 1u32,
+            6u32, 384u32, 4u32, 24u32, 1765u32, 76u32, 121u32, 11u32, 356u32, 355u32, 193u32,
+            33u32, 460u32, 0u32, 1993u32, 1871u32, 13u32, 261u32, 313u32, 156u32, 6u32, 6u32,
+            38u32, 4440u32, 333u32, 2719u32, 16u32, 1u32, 21u32, 248u32, 313u32, 3u32, 589u32,
+            36u32, 522u32, 13u32, 1u32, 0u32, 4u32, 11u32, 16u32, 19u32, 734u32, 44u32, 234u32,
+            321u32, 180u32, 2u32, 9u32, 16u32, 41u32, 61u32, 232u32, 121u32, 132u32, 75u32,
+            1792u32, 1093u32, 199u32, 0u32, 17u32, 417u32, 1u32, 20u32, 1u32, 32u32, 3861u32,
+            1504u32, 273u32, 379u32, 226u32,
+        ],
+        entries: &[
+            ("NUMERIC_2", Self(514u32)),
+            ("CHANNEL", Self(363u32)),
+            ("MSDOS", Self(151u32)),
+            ("KEY_RIGHTCTRL", Self(97u32)),
+            ("XFER", Self(147u32)),
+            ("FAVORITES", Self(364u32)),
+            ("KBD_LCD_MENU5", Self(700u32)),
+            ("POWER2", Self(356u32)),
+            ("UNMUTE", Self(628u32)),
+            ("KEY_DEL_EOL", Self(448u32)),
+            ("KEY_MINUS", Self(12u32)),
+            ("FN_F1", Self(466u32)),
+            ("KEY_DISPLAYTOGGLE", Self(431u32)),
+            ("F1", Self(59u32)),
+            ("KEY_SCROLLDOWN", Self(178u32)),
+            ("HANGUP_PHONE", Self(446u32)),
+            ("BRIGHTNESS_TOGGLE", Self(431u32)),
+            ("KEY_NUMERIC_7", Self(519u32)),
+            ("KEY_SUSPEND", Self(205u32)),
+            ("KEY_FORWARD", Self(159u32)),
+            ("PAUSE", Self(119u32)),
+            ("KEY_REFRESH", Self(173u32)),
+            ("KEY_UNKNOWN", Self(240u32)),
+            ("KEY_NUMERIC_6", Self(518u32)),
+            ("KEY_CONFIG", Self(171u32)),
+            ("KEY_HOME", Self(102u32)),
+            ("S", Self(31u32)),
+            ("KEY_FN_D", Self(480u32)),
+            ("FULL_SCREEN", Self(372u32)),
+            ("KEY_HANGUP_PHONE", Self(446u32)),
+            ("TAPE", Self(384u32)),
+            ("CANCEL", Self(223u32)),
+            ("CLEAR", Self(355u32)),
+            ("KEYBOARD", Self(374u32)),
+            ("KEY_STOP_RECORD", Self(625u32)),
+            ("3", Self(4u32)),
+            ("WLAN", Self(238u32)),
+            ("KEY_JOURNAL", Self(578u32)),
+            ("REWIND", Self(168u32)),
+            ("KEY_3D_MODE", Self(623u32)),
+            ("NUMERIC_8", Self(520u32)),
+            ("NOTIFICATION_CENTER", Self(444u32)),
+            ("ESC", Self(1u32)),
+            ("KEY_ATTENDANT_TOGGLE", Self(541u32)),
+            ("Z", Self(44u32)),
+            ("F6", Self(64u32)),
+            ("PRESENTATION", Self(425u32)),
+            ("ARCHIVE", Self(361u32)),
+            ("KEY_FN_F6", Self(471u32)),
+            ("KEY_BRL_DOT8", Self(504u32)),
+            ("KEY_KPEQUAL", Self(117u32)),
+            ("SUBTITLE", Self(370u32)),
+            ("CONTEXT_MENU", Self(438u32)),
+            ("KEY_RO", Self(89u32)),
+            ("PROG1", Self(148u32)),
+            ("MACRO21", Self(676u32)),
+            ("PRINT", Self(210u32)),
+            ("COPY", Self(133u32)),
+            ("MACRO17", Self(672u32)),
+            ("F19", Self(189u32)),
+            ("KEY_ALTERASE", Self(222u32)),
+            ("KEY_DVD", Self(389u32)),
+            ("KEY_MACRO30", Self(685u32)),
+            ("HELP", Self(138u32)),
+            ("KBDINPUTASSIST_PREV", Self(608u32)),
+            ("YELLOW", Self(400u32)),
+            ("KEY_PC", Self(376u32)),
+            ("KEY_D", Self(32u32)),
+            ("KEY_BOOKMARKS", Self(156u32)),
+            ("KEY_F20", Self(190u32)),
+            ("BRIGHTNESSDOWN", Self(224u32)),
+            ("KP5", Self(76u32)),
+            ("KEY_MACRO9", Self(664u32)),
+            ("J", Self(36u32)),
+            ("KEY_VOLUMEUP", Self(115u32)),
+            ("KEY_KP1", Self(79u32)),
+            ("FN_1", Self(478u32)),
+            ("KEY_E", Self(18u32)),
+            ("KEY_LEFT", Self(105u32)),
+            ("NUMERIC_11", Self(620u32)),
+            ("KEY_LEFT_DOWN", Self(617u32)),
+            ("NEXT_FAVORITE", Self(624u32)),
+            ("KEY_RESERVED", Self(0u32)),
+            ("PASTE", Self(135u32)),
+            ("NUMERIC_3", Self(515u32)),
+            ("F7", Self(65u32)),
+            ("KEY_POWER", Self(116u32)),
+            ("PROGRAM", Self(362u32)),
+            ("TV", Self(377u32)),
+            ("EDIT", Self(176u32)),
+            ("KEY_HANGUEL", Self(122u32)),
+            ("CYCLEWINDOWS", Self(154u32)),
+            ("KEY_MACRO26", Self(681u32)),
+            ("MACRO30", Self(685u32)),
+            ("KEY_KBD_LCD_MENU3", Self(698u32)),
+            ("BRIGHTNESS_MIN", Self(592u32)),
+            ("BLUETOOTH", Self(237u32)),
+            ("NUMERIC_4", Self(516u32)),
+            ("KEY_PHONE", Self(169u32)),
+            ("PHONE", Self(169u32)),
+            ("KEY_DEL_EOS", Self(449u32)),
+            ("FIND", Self(136u32)),
+            ("BRL_DOT1", Self(497u32)),
+            ("KEY_F6", Self(64u32)),
+            ("EQUAL", Self(13u32)),
+            ("KPJPCOMMA", Self(95u32)),
+            ("F23", Self(193u32)),
+            ("KEY_VIDEO_NEXT", Self(241u32)),
+            ("TOUCHPAD_OFF", Self(532u32)),
+            ("KEY_MACRO23", Self(678u32)),
+            ("KEY_SLOW", Self(409u32)),
+            ("KEY_EURO", Self(435u32)),
+            ("KEY_GAMES", Self(417u32)),
+            ("VIDEO_PREV", Self(242u32)),
+            ("KEY_PROG2", Self(149u32)),
+            ("KEY_VOICEMAIL", Self(428u32)),
+            ("KEY_NUMERIC_4", Self(516u32)),
+            ("KEY_AUX", Self(390u32)),
+            ("KEY_MACRO", Self(112u32)),
+            ("TASKMANAGER", Self(577u32)),
+            ("KEY_EMOJI_PICKER", Self(585u32)),
+            ("F18", Self(188u32)),
+            ("RADIO", Self(385u32)),
+            ("SCREENSAVER", Self(581u32)),
+            ("SCREENLOCK", Self(152u32)),
+            ("KEY_KBD_LAYOUT_NEXT", Self(584u32)),
+            ("KEY_G", Self(34u32)),
+            ("KEY_A", Self(30u32)),
+            ("F12", Self(88u32)),
+            ("KEY_FILE", Self(144u32)),
+            ("KEY_MACRO16", Self(671u32)),
+            ("KEY_VCR", Self(379u32)),
+            ("KEY_DELETEFILE", Self(146u32)),
+            ("KEY_COPY", Self(133u32)),
+            ("KBDINPUTASSIST_NEXT", Self(609u32)),
+            ("F2", Self(60u32)),
+            ("INFO", Self(358u32)),
+            ("KEY_STOPCD", Self(166u32)),
+            ("KEY_BLUE", Self(401u32)),
+            ("KEY_FN_RIGHT_SHIFT", Self(485u32)),
+            ("KEY_FN_ESC", Self(465u32)),
+            ("CHANNELUP", Self(402u32)),
+            ("KEY_H", Self(35u32)),
+            ("SPREADSHEET", Self(423u32)),
+            ("KEY_7", Self(8u32)),
+            ("KEY_ROTATE_DISPLAY", Self(153u32)),
+            ("KEY_FN_1", Self(478u32)),
+            ("KEY_DOWN", Self(108u32)),
+            ("KEY_RADIO", Self(385u32)),
+            ("KEY_KBDILLUMDOWN", Self(229u32)),
+            ("KEY_SPREADSHEET", Self(423u32)),
+            ("REDO", Self(182u32)),
+            ("MEDIA_TOP_MENU", Self(619u32)),
+            ("TITLE", Self(369u32)),
+            ("KEY_RED", Self(398u32)),
+            ("KEY_KATAKANA", Self(90u32)),
+            ("CLOSECD", Self(160u32)),
+            ("KEY_WPS_BUTTON", Self(529u32)),
+            ("TEEN", Self(414u32)),
+            ("KEY_KP6", Self(77u32)),
+            ("KEY_FRONT", Self(132u32)),
+            ("KEY_CHANNELUP", Self(402u32)),
+            ("A", Self(30u32)),
+            ("KEY_MACRO3", Self(658u32)),
+            ("KP6", Self(77u32)),
+            ("KEY_SWITCHVIDEOMODE", Self(227u32)),
+            ("TOUCHPAD_TOGGLE", Self(530u32)),
+            ("CUT", Self(137u32)),
+            ("KEY_BRL_DOT10", Self(506u32)),
+            ("MACRO2", Self(657u32)),
+            ("SEMICOLON", Self(39u32)),
+            ("KEY_KP3", Self(81u32)),
+            ("COFFEE", Self(152u32)),
+            ("KEY_DATABASE", Self(426u32)),
+            ("FN_F11", Self(476u32)),
+            ("BRL_DOT2", Self(498u32)),
+            ("KEY_FIND", Self(136u32)),
+            ("KEY_NUMERIC_B", Self(525u32)),
+            ("FN_D", Self(480u32)),
+            ("KEY_DIGITS", Self(413u32)),
+            ("KEY_EJECTCD", Self(161u32)),
+            ("WWAN", Self(246u32)),
+            ("KEY_F11", Self(87u32)),
+            ("KEY_SEND", Self(231u32)),
+            ("KEY_DEL_LINE", Self(451u32)),
+            ("KEY_ENTER", Self(28u32)),
+            ("KEY_BLUETOOTH", Self(237u32)),
+            ("FN_F", Self(482u32)),
+            ("_3", Self(4u32)),
+            ("KEY_BRIGHTNESS_TOGGLE", Self(431u32)),
+            ("KEY_NEXTSONG", Self(163u32)),
+            ("_102ND", Self(86u32)),
+            ("FORWARD", Self(159u32)),
+            ("KEY_MACRO_RECORD_START", Self(688u32)),
+            ("Y", Self(21u32)),
+            ("KEY_PROG1", Self(148u32)),
+            ("_6", Self(7u32)),
+            ("KEY_LEFTSHIFT", Self(42u32)),
+            ("ALTERASE", Self(222u32)),
+            ("KEY_DATA", Self(631u32)),
+            ("KEY_CAMERA_RIGHT", Self(538u32)),
+            ("KEY_FULL_SCREEN", Self(372u32)),
+            ("PROG4", Self(203u32)),
+            ("KEY_SELECT", Self(353u32)),
+            ("KPCOMMA", Self(121u32)),
+            ("KEY_F4", Self(62u32)),
+            ("VCR2", Self(380u32)),
+            ("KEY_CD", Self(383u32)),
+            ("KEY_VOLUMEDOWN", Self(114u32)),
+            ("KEY_PROG3", Self(202u32)),
+            ("KEY_SPELLCHECK", Self(432u32)),
+            ("KEY_RIGHT_DOWN", Self(615u32)),
+            ("FRAMEFORWARD", Self(437u32)),
+            ("KEY_EDIT", Self(176u32)),
+            ("BREAK", Self(411u32)),
+            ("KEY_AGAIN", Self(129u32)),
+            ("KBDINPUTASSIST_PREVGROUP", Self(610u32)),
+            ("PROG3", Self(202u32)),
+            ("MACRO27", Self(682u32)),
+            ("KEY_MOVE", Self(175u32)),
+            ("KEY_SCREEN", Self(375u32)),
+            ("KEY_WLAN", Self(238u32)),
+            ("KEY_FIRST", Self(404u32)),
+            ("KEY_WIMAX", Self(246u32)),
+            ("10CHANNELSUP", Self(440u32)),
+            ("VCR", Self(379u32)),
+            ("SCROLLUP", Self(177u32)),
+            ("KEY_FN_F2", Self(467u32)),
+            ("KEY_KBD_LCD_MENU5", Self(700u32)),
+            ("KEY_TOUCHPAD_ON", Self(531u32)),
+            ("LEFT", Self(105u32)),
+            ("MUTE", Self(113u32)),
+            ("2", Self(3u32)),
+            ("CAMERA_ZOOMOUT", Self(534u32)),
+            ("KEY_COMPOSE", Self(127u32)),
+            ("KEY_NEWS", Self(427u32)),
+            ("KEY_BRIGHTNESS_AUTO", Self(244u32)),
+            ("PAUSE_RECORD", Self(626u32)),
+            ("KEY_UNMUTE", Self(628u32)),
+            ("KEY_SCROLLUP", Self(177u32)),
+            ("KEY_CAPSLOCK", Self(58u32)),
+            ("SELECT", Self(353u32)),
+            ("ROTATE_LOCK_TOGGLE", Self(561u32)),
+            ("PROG2", Self(149u32)),
+            ("KEY_LEFTMETA", Self(125u32)),
+            ("MACRO_PRESET2", Self(692u32)),
+            ("DEL_EOL", Self(448u32)),
+            ("ASSISTANT", Self(583u32)),
+            ("SOUND", Self(213u32)),
+            ("SPACE", Self(57u32)),
+            ("EXIT", Self(174u32)),
+            ("KEY_NUMERIC_12", Self(621u32)),
+            ("CAMERA_FOCUS", Self(528u32)),
+            ("RIGHT", Self(106u32)),
+            ("LIGHTS_TOGGLE", Self(542u32)),
+            ("KEY_VIDEOPHONE", Self(416u32)),
+            ("BACKSPACE", Self(14u32)),
+            ("KEY_KPPLUS", Self(78u32)),
+            ("KBDINPUTASSIST_CANCEL", Self(613u32)),
+            ("F15", Self(185u32)),
+            ("KEY_DISPLAY_OFF", Self(245u32)),
+            ("KEY_KPASTERISK", Self(55u32)),
+            ("TAB", Self(15u32)),
+            ("INSERT", Self(110u32)),
+            ("K", Self(37u32)),
+            ("KEY_HP", Self(211u32)),
+            ("KEY_CAMERA_DOWN", Self(536u32)),
+            ("KEY_UNDO", Self(131u32)),
+            ("KBDILLUMTOGGLE", Self(228u32)),
+            ("ZOOMRESET", Self(420u32)),
+            ("KEY_PRINT", Self(210u32)),
+            ("ONSCREEN_KEYBOARD", Self(632u32)),
+            ("UP", Self(103u32)),
+            ("KEY_MACRO24", Self(679u32)),
+            ("KEY_NUMERIC_2", Self(514u32)),
+            ("PC", Self(376u32)),
+            ("KEY_BRIGHTNESS_MIN", Self(592u32)),
+            ("CALENDAR", Self(397u32)),
+            ("DISPLAYTOGGLE", Self(431u32)),
+            ("MHP", Self(367u32)),
+            ("HIRAGANA", Self(91u32)),
+            ("KEY_B", Self(48u32)),
+            ("FN_F3", Self(468u32)),
+            ("KEY_O", Self(24u32)),
+            ("MEDIA_REPEAT", Self(439u32)),
+            ("KEY_FN_F3", Self(468u32)),
+            ("KEY_MP3", Self(391u32)),
+            ("KEY_SPACE", Self(57u32)),
+            ("KEY_F22", Self(192u32)),
+            ("RESTART", Self(408u32)),
+            ("KEY_BACKSPACE", Self(14u32)),
+            ("MACRO16", Self(671u32)),
+            ("DOT", Self(52u32)),
+            ("STOPCD", Self(166u32)),
+            ("KEY_HANJA", Self(123u32)),
+            ("MACRO15", Self(670u32)),
+            ("MICMUTE", Self(248u32)),
+            ("_7", Self(8u32)),
+            ("KEY_COMMA", Self(51u32)),
+            ("RFKILL", Self(247u32)),
+            ("KEY_ESC", Self(1u32)),
+            ("MACRO_RECORD_STOP", Self(689u32)),
+            ("DEL_LINE", Self(451u32)),
+            ("BLUE", Self(401u32)),
+            ("KEY_F23", Self(193u32)),
+            ("STOP_RECORD", Self(625u32)),
+            ("KEY_ATTENDANT_OFF", Self(540u32)),
+            ("FN_F12", Self(477u32)),
+            ("KEY_ONSCREEN_KEYBOARD", Self(632u32)),
+            ("KEY_GOTO", Self(354u32)),
+            ("_10CHANNELSDOWN", Self(441u32)),
+            ("MACRO29", Self(684u32)),
+            ("KEY_KBDINPUTASSIST_NEXT", Self(609u32)),
+            ("KEY_FORWARDMAIL", Self(233u32)),
+            ("KEY_K", Self(37u32)),
+            ("ZOOM", Self(372u32)),
+            ("E", Self(18u32)),
+            ("HANGEUL", Self(122u32)),
+            ("KEY_SHUFFLE", Self(410u32)),
+            ("FN_ESC", Self(465u32)),
+            ("F21", Self(191u32)),
+            ("KEY_LEFTBRACE", Self(26u32)),
+            ("X", Self(45u32)),
+            ("UNKNOWN", Self(240u32)),
+            ("KEY_CONTROLPANEL", Self(579u32)),
+            ("KEY_CLOSE", Self(206u32)),
+            ("KEY_F10", Self(68u32)),
+            ("KEY_KBDINPUTASSIST_NEXTGROUP", Self(611u32)),
+            ("KBD_LAYOUT_NEXT", Self(584u32)),
+            ("KEY_F3", Self(61u32)),
+            ("F3", Self(61u32)),
+            ("KEY_ZENKAKUHANKAKU", Self(85u32)),
+            ("FN_RIGHT_SHIFT", Self(485u32)),
+            ("BRIGHTNESS_MAX", Self(593u32)),
+            ("KEY_MEDIA", Self(226u32)),
+            ("KEY_NUMERIC_D", Self(527u32)),
+            ("RIGHT_UP", Self(614u32)),
+            ("KATAKANA", Self(90u32)),
+            ("KEY_CAMERA", Self(212u32)),
+            ("SAT2", Self(382u32)),
+            ("KEY_2", Self(3u32)),
+            ("KEY_IMAGES", Self(442u32)),
+            ("FN_F9", Self(474u32)),
+            ("KEY_COFFEE", Self(152u32)),
+            ("KEY_KP8", Self(72u32)),
+            ("SEND", Self(231u32)),
+            ("LEFTSHIFT", Self(42u32)),
+            ("7", Self(8u32)),
+            ("KEY_TEXT", Self(388u32)),
+            ("FN_F10", Self(475u32)),
+            ("ANGLE", Self(371u32)),
+            ("MACRO10", Self(665u32)),
+            ("VOLUMEDOWN", Self(114u32)),
+            ("KEY_PREVIOUSSONG", Self(165u32)),
+            ("CAMERA", Self(212u32)),
+            ("MACRO22", Self(677u32)),
+            ("FN_F5", Self(470u32)),
+            ("KP4", Self(75u32)),
+            ("KEY_PASTE", Self(135u32)),
+            ("DOLLAR", Self(434u32)),
+            ("KEY_MACRO17", Self(672u32)),
+            ("MINUS", Self(12u32)),
+            ("END", Self(107u32)),
+            ("NEWS", Self(427u32)),
+            ("KEY_F14", Self(184u32)),
+            ("BRL_DOT5", Self(501u32)),
+            ("REFRESH", Self(173u32)),
+            ("MACRO3", Self(658u32)),
+            ("KPASTERISK", Self(55u32)),
+            ("NEW", Self(181u32)),
+            ("KEY_DIRECTORY", Self(394u32)),
+            ("KEY_REDO", Self(182u32)),
+            ("DELETE", Self(111u32)),
+            ("OPEN", Self(134u32)),
+            ("KEY_NEXT_FAVORITE", Self(624u32)),
+            ("KEY_KBDINPUTASSIST_PREV", Self(608u32)),
+            ("KEY_LEFTCTRL", Self(29u32)),
+            ("KEY_PREVIOUS", Self(412u32)),
+            ("KEY_F18", Self(188u32)),
+            ("KEY_R", Self(19u32)),
+            ("LAST", Self(405u32)),
+            ("4", Self(5u32)),
+            ("KEY_FN_F10", Self(475u32)),
+            ("KEY_F9", Self(67u32)),
+            ("KEY_RIGHTSHIFT", Self(54u32)),
+            ("Q", Self(16u32)),
+            ("BATTERY", Self(236u32)),
+            ("MACRO_RECORD_START", Self(688u32)),
+            ("KEY_CHAT", Self(216u32)),
+            ("KEY_BREAK", Self(411u32)),
+            ("SCROLLDOWN", Self(178u32)),
+            ("TEXT", Self(388u32)),
+            ("LEFTBRACE", Self(26u32)),
+            ("KEY_ALS_TOGGLE", Self(560u32)),
+            ("MACRO", Self(112u32)),
+            ("TOUCHPAD_ON", Self(531u32)),
+            ("MACRO9", Self(664u32)),
+            ("KEY_NUMERIC_9", Self(521u32)),
+            ("KEY_MACRO29", Self(684u32)),
+            ("NUMERIC_B", Self(525u32)),
+            ("KEY_TWEN", Self(415u32)),
+            ("VIDEO_NEXT", Self(241u32)),
+            ("VENDOR", Self(360u32)),
+            ("KEY_TV2", Self(378u32)),
+            ("B", Self(48u32)),
+            ("LEFT_DOWN", Self(617u32)),
+            ("F22", Self(192u32)),
+            ("HENKAN", Self(92u32)),
+            ("KEY_SELECTIVE_SCREENSHOT", Self(634u32)),
+            ("KEY_WWW", Self(150u32)),
+            ("KEY_KBD_LCD_MENU4", Self(699u32)),
+            ("FINANCE", Self(219u32)),
+            ("SAVE", Self(234u32)),
+            ("F9", Self(67u32)),
+            ("JOURNAL", Self(578u32)),
+            ("_1", Self(2u32)),
+            ("R", Self(19u32)),
+            ("KEY_END", Self(107u32)),
+            ("KEY_F19", Self(189u32)),
+            ("_5", Self(6u32)),
+            ("KEY_CAMERA_ZOOMOUT", Self(534u32)),
+            ("KEY_KBDINPUTASSIST_CANCEL", Self(613u32)),
+            ("KEY_UP", Self(103u32)),
+            ("KEY_OPEN", Self(134u32)),
+            ("KEY_MACRO_RECORD_STOP", Self(689u32)),
+            ("KEY_HOMEPAGE", Self(172u32)),
+            ("KEY_CHANNELDOWN", Self(403u32)),
+            ("KEY_MACRO_PRESET_CYCLE", Self(690u32)),
+            ("KEY_CUT", Self(137u32)),
+            ("F24", Self(194u32)),
+            ("MACRO7", Self(662u32)),
+            ("KATAKANAHIRAGANA", Self(93u32)),
+            ("KEY_KPJPCOMMA", Self(95u32)),
+            ("FN_E", Self(481u32)),
+            ("KEY_YELLOW", Self(400u32)),
+            ("KEY_MACRO_PRESET3", Self(693u32)),
+            ("LANGUAGE", Self(368u32)),
+            ("MEMO", Self(396u32)),
+            ("KEY_CLEAR", Self(355u32)),
+            ("SPORT", Self(220u32)),
+            ("LIST", Self(395u32)),
+            ("SLOW", Self(409u32)),
+            ("KEY_CAMERA_ZOOMIN", Self(533u32)),
+            ("5", Self(6u32)),
+            ("PLAYPAUSE", Self(164u32)),
+            ("SLASH", Self(53u32)),
+            ("MACRO6", Self(661u32)),
+            ("KEY_NEW", Self(181u32)),
+            ("KEY_ADDRESSBOOK", Self(429u32)),
+            ("GRAPHICSEDITOR", Self(424u32)),
+            ("KEY_RIGHT_UP", Self(614u32)),
+            ("KEY_ROTATE_LOCK_TOGGLE", Self(561u32)),
+            ("KEY_KPSLASH", Self(98u32)),
+            ("KEY_SENDFILE", Self(145u32)),
+            ("KEY_9", Self(10u32)),
+            ("GRAVE", Self(41u32)),
+            ("AUDIO_DESC", Self(622u32)),
+            ("KEY_F5", Self(63u32)),
+            ("KEY_KPLEFTPAREN", Self(179u32)),
+            ("KEY_BRIGHTNESS_ZERO", Self(244u32)),
+            ("KEY_MACRO8", Self(663u32)),
+            ("KEY_J", Self(36u32)),
+            ("NUMERIC_6", Self(518u32)),
+            ("KEY_KBDINPUTASSIST_ACCEPT", Self(612u32)),
+            ("9", Self(10u32)),
+            ("FORWARDMAIL", Self(233u32)),
+            ("KP9", Self(73u32)),
+            ("UNDO", Self(131u32)),
+            ("MESSENGER", Self(430u32)),
+            ("KEY_KP5", Self(76u32)),
+            ("KEY_VOD", Self(627u32)),
+            ("COMMA", Self(51u32)),
+            ("SENDFILE", Self(145u32)),
+            ("RO", Self(89u32)),
+            ("KBD_LCD_MENU4", Self(699u32)),
+            ("KEY_FN_F12", Self(477u32)),
+            ("KEY_BACKSLASH", Self(43u32)),
+            ("KEY_CALC", Self(140u32)),
+            ("KEY_SCROLLLOCK", Self(70u32)),
+            ("_4", Self(5u32)),
+            ("SEARCH", Self(217u32)),
+            ("KEY_CAMERA_UP", Self(535u32)),
+            ("KEY_CHANNEL", Self(363u32)),
+            ("F4", Self(62u32)),
+            ("KEY_CAMERA_LEFT", Self(537u32)),
+            ("KEY_KBD_LCD_MENU2", Self(697u32)),
+            ("KEY_NUMERIC_8", Self(520u32)),
+            ("KEY_RIGHTALT", Self(100u32)),
+            ("KEY_I", Self(23u32)),
+            ("DELETEFILE", Self(146u32)),
+            ("LEFTCTRL", Self(29u32)),
+            ("CONNECT", Self(218u32)),
+            ("SCREEN", Self(375u32)),
+            ("KEY_FRAMEBACK", Self(436u32)),
+            ("BRIGHTNESSUP", Self(225u32)),
+            ("MACRO20", Self(675u32)),
+            ("KEY_C", Self(46u32)),
+            ("KEY_MAIL", Self(155u32)),
+            ("KEY_INFO", Self(358u32)),
+            ("FN", Self(464u32)),
+            ("MACRO4", Self(659u32)),
+            ("KEY_LEFT_UP", Self(616u32)),
+            ("KEY_RIGHTBRACE", Self(27u32)),
+            ("MACRO5", Self(660u32)),
+            ("KEY_DOLLAR", Self(434u32)),
+            ("BRL_DOT6", Self(502u32)),
+            ("KEY_MACRO7", Self(662u32)),
+            ("KEY_FN", Self(464u32)),
+            ("KP7", Self(71u32)),
+            ("KEY_S", Self(31u32)),
+            ("WWW", Self(150u32)),
+            ("DIRECTION", Self(153u32)),
+            ("HOMEPAGE", Self(172u32)),
+            ("KBD_LCD_MENU1", Self(696u32)),
+            ("FASTFORWARD", Self(208u32)),
+            ("KEY_EPG", Self(365u32)),
+            ("KEY_NUMERIC_5", Self(517u32)),
+            ("VIDEO", Self(393u32)),
+            ("G", Self(34u32)),
+            ("KEY_LIST", Self(395u32)),
+            ("KEY_WAKEUP", Self(143u32)),
+            ("KEY_FASTFORWARD", Self(208u32)),
+            ("KEY_FN_F7", Self(472u32)),
+            ("F10", Self(68u32)),
+            ("BACK", Self(158u32)),
+            ("LINEFEED", Self(101u32)),
+            ("M", Self(50u32)),
+            ("KEY_KBD_LCD_MENU1", Self(696u32)),
+            ("PREVIOUS", Self(412u32)),
+            ("BRIGHTNESS_AUTO", Self(244u32)),
+            ("N", Self(49u32)),
+            ("HANJA", Self(123u32)),
+            ("KEY_T", Self(20u32)),
+            ("NUMERIC_POUND", Self(523u32)),
+            ("KEY_KP0", Self(82u32)),
+            ("KEY_RECORD", Self(167u32)),
+            ("KEY_REWIND", Self(168u32)),
+            ("KEY_BRL_DOT1", Self(497u32)),
+            ("KEY_PLAYPAUSE", Self(164u32)),
+            ("NUMERIC_7", Self(519u32)),
+            ("PRIVACY_SCREEN_TOGGLE", Self(633u32)),
+            ("KEY_MACRO_PRESET1", Self(691u32)),
+            ("F5", Self(63u32)),
+            ("O", Self(24u32)),
+            ("KEY_PLAYCD", Self(200u32)),
+            ("VOLUMEUP", Self(115u32)),
+            ("PLAYCD", Self(200u32)),
+            ("DOWN", Self(108u32)),
+            ("KEY_F17", Self(187u32)),
+            ("KEY_KATAKANAHIRAGANA", Self(93u32)),
+            ("KEY_3", Self(4u32)),
+            ("EJECTCLOSECD", Self(162u32)),
+            ("ATTENDANT_OFF", Self(540u32)),
+            ("KEY_ASPECT_RATIO", Self(375u32)),
+            ("KEY_NUMERIC_1", Self(513u32)),
+            ("OK", Self(352u32)),
+            ("COMPOSE", Self(127u32)),
+            ("HOME", Self(102u32)),
+            ("NUMERIC_12", Self(621u32)),
+            ("DVD", Self(389u32)),
+            ("KEY_F16", Self(186u32)),
+            ("KEY_FN_2", Self(479u32)),
+            ("CHANNELDOWN", Self(403u32)),
+            ("FN_F7", Self(472u32)),
+            ("KEY_SYSRQ", Self(99u32)),
+            ("KEY_VIDEO", Self(393u32)),
+            ("ISO", Self(170u32)),
+            ("ATTENDANT_ON", Self(539u32)),
+            ("KPSLASH", Self(98u32)),
+            ("KEY_DOT", Self(52u32)),
+            ("KEY_F12", Self(88u32)),
+            ("F", Self(33u32)),
+            ("KEY_MACRO1", Self(656u32)),
+            ("FN_F8", Self(473u32)),
+            ("KEY_ROOT_MENU", Self(618u32)),
+            ("0", Self(11u32)),
+            ("DISPLAY_OFF", Self(245u32)),
+            ("SELECTIVE_SCREENSHOT", Self(634u32)),
+            ("KEY_TOUCHPAD_OFF", Self(532u32)),
+            ("ASPECT_RATIO", Self(375u32)),
+            ("KEY_MACRO21", Self(676u32)),
+            ("KEY_FN_F8", Self(473u32)),
+            ("KEY_10CHANNELSDOWN", Self(441u32)),
+            ("KBDILLUMUP", Self(230u32)),
+            ("KEY_CONTEXT_MENU", Self(438u32)),
+            ("DIGITS", Self(413u32)),
+            ("KEY_PLAY", Self(207u32)),
+            ("KEY_FN_E", Self(481u32)),
+            ("KEY_F7", Self(65u32)),
+            ("MENU", Self(139u32)),
+            ("P", Self(25u32)),
+            ("KPENTER", Self(96u32)),
+            ("HP", Self(211u32)),
+            ("MOVE", Self(175u32)),
+            ("LOGOFF", Self(433u32)),
+            ("FN_S", Self(483u32)),
+            ("TIME", Self(359u32)),
+            ("KEY_ZOOMRESET", Self(420u32)),
+            ("DASHBOARD", Self(204u32)),
+            ("KEY_MHP", Self(367u32)),
+            ("KEY_PAUSE", Self(119u32)),
+            ("KEY_MACRO14", Self(669u32)),
+            ("10CHANNELSDOWN", Self(441u32)),
+            ("SWITCHVIDEOMODE", Self(227u32)),
+            ("KEY_SUBTITLE", Self(370u32)),
+            ("KEY_MUHENKAN", Self(94u32)),
+            ("KEY_SEMICOLON", Self(39u32)),
+            ("KEY_GREEN", Self(399u32)),
+            ("KEY_F8", Self(66u32)),
+            ("KEY_MEDIA_TOP_MENU", Self(619u32)),
+            ("KEY_KP9", Self(73u32)),
+            ("KEY_TOUCHPAD_TOGGLE", Self(530u32)),
+            ("DIRECTORY", Self(394u32)),
+            ("_0", Self(11u32)),
+            ("UWB", Self(239u32)),
+            ("RIGHTBRACE", Self(27u32)),
+            ("KEY_4", Self(5u32)),
+            ("LEFTALT", Self(56u32)),
+            ("V", Self(47u32)),
+            ("RECORD", Self(167u32)),
+            ("KEY_MACRO13", Self(668u32)),
+            ("KEY_Z", Self(44u32)),
+            ("QUESTION", Self(214u32)),
+            ("KEY_F1", Self(59u32)),
+            ("KEY_KPRIGHTPAREN", Self(180u32)),
+            ("KEY_6", Self(7u32)),
+            ("KBD_LCD_MENU2", Self(697u32)),
+            ("FASTREVERSE", Self(629u32)),
+            ("KEY_WWAN", Self(246u32)),
+            ("KPRIGHTPAREN", Self(180u32)),
+            ("PAUSECD", Self(201u32)),
+            ("KEY_P", Self(25u32)),
+            ("KEY_RIGHTMETA", Self(126u32)),
+            ("MODE", Self(373u32)),
+            ("_2", Self(3u32)),
+            ("SLOWREVERSE", Self(630u32)),
+            ("ZOOMIN", Self(418u32)),
+            ("TV2", Self(378u32)),
+            ("KEY_F24", Self(194u32)),
+            ("_8", Self(9u32)),
+            ("KEY_YEN", Self(124u32)),
+            ("CONFIG", Self(171u32)),
+            ("KEY_ASSISTANT", Self(583u32)),
+            ("MUHENKAN", Self(94u32)),
+            ("KEY_TEEN", Self(414u32)),
+            ("KEY_102ND", Self(86u32)),
+            ("MACRO24", Self(679u32)),
+            ("KEY_INSERT", Self(110u32)),
+            ("KEY_KBDILLUMUP", Self(230u32)),
+            ("KEY_LINEFEED", Self(101u32)),
+            ("FRAMEBACK", Self(436u32)),
+            ("KEY_LAST", Self(405u32)),
+            ("F11", Self(87u32)),
+            ("KEY_F", Self(33u32)),
+            ("U", Self(22u32)),
+            ("MACRO18", Self(673u32)),
+            ("RIGHTMETA", Self(126u32)),
+            ("I", Self(23u32)),
+            ("KEY_KPDOT", Self(83u32)),
+            ("KEY_ZOOM", Self(372u32)),
+            ("KEY_MACRO6", Self(661u32)),
+            ("102ND", Self(86u32)),
+            ("CAMERA_UP", Self(535u32)),
+            ("KEY_SCREENLOCK", Self(152u32)),
+            ("FN_F4", Self(469u32)),
+            ("SAT", Self(381u32)),
+            ("KEY_MACRO_PRESET2", Self(692u32)),
+            ("KEY_PAUSECD", Self(201u32)),
+            ("KEY_PRESENTATION", Self(425u32)),
+            ("KEY_X", Self(45u32)),
+            ("STOP", Self(128u32)),
+            ("VOICEMAIL", Self(428u32)),
+            ("KEY_MEMO", Self(396u32)),
+            ("EMAIL", Self(215u32)),
+            ("ALS_TOGGLE", Self(560u32)),
+            ("WORDPROCESSOR", Self(421u32)),
+            ("KEY_SCALE", Self(120u32)),
+            ("KEY_NUMERIC_0", Self(512u32)),
+            ("EMOJI_PICKER", Self(585u32)),
+            ("BOOKMARKS", Self(156u32)),
+            ("KEY_NUMERIC_STAR", Self(522u32)),
+            ("AUX", Self(390u32)),
+            ("KEY_MACRO15", Self(670u32)),
+            ("VOICECOMMAND", Self(582u32)),
+            ("KEY_ANGLE", Self(371u32)),
+            ("BRL_DOT9", Self(505u32)),
+            ("KPEQUAL", Self(117u32)),
+            ("DATABASE", Self(426u32)),
+            ("BRL_DOT4", Self(500u32)),
+            ("KEY_M", Self(50u32)),
+            ("BRL_DOT3", Self(499u32)),
+            ("IMAGES", Self(442u32)),
+            ("KEY_VCR2", Self(380u32)),
+            ("INS_LINE", Self(450u32)),
+            ("KEY_TV", Self(377u32)),
+            ("KEY_SLEEP", Self(142u32)),
+            ("KEY_FINANCE", Self(219u32)),
+            ("PICKUP_PHONE", Self(445u32)),
+            ("KEY_LIGHTS_TOGGLE", Self(542u32)),
+            ("KEY_UWB", Self(239u32)),
+            ("KEY_MACRO5", Self(660u32)),
+            ("_9", Self(10u32)),
+            ("KEY_Y", Self(21u32)),
+            ("MACRO_PRESET1", Self(691u32)),
+            ("KEY_EXIT", Self(174u32)),
+            ("KEY_GRAVE", Self(41u32)),
+            ("KEY_Q", Self(16u32)),
+            ("KEY_BRL_DOT4", Self(500u32)),
+            ("KEY_PROG4", Self(203u32)),
+            ("PAGEUP", Self(104u32)),
+            ("KEY_KPCOMMA", Self(121u32)),
+            ("KEY_HENKAN", Self(92u32)),
+            ("H", Self(35u32)),
+            ("C", Self(46u32)),
+            ("KEY_TAB", Self(15u32)),
+            ("ENTER", Self(28u32)),
+            ("GOTO", Self(354u32)),
+            ("KEY_FN_B", Self(484u32)),
+            ("KEY_FN_F11", Self(476u32)),
+            ("KEY_MICMUTE", Self(248u32)),
+            ("KEY_ZOOMIN", Self(418u32)),
+            ("FN_F6", Self(471u32)),
+            ("BUTTONCONFIG", Self(576u32)),
+            ("NEXT", Self(407u32)),
+            ("KEY_FASTREVERSE", Self(629u32)),
+            ("KEY_MACRO27", Self(682u32)),
+            ("KEY_MESSENGER", Self(430u32)),
+            ("BRIGHTNESS_ZERO", Self(244u32)),
+            ("KEY_BRIGHTNESS_CYCLE", Self(243u32)),
+            ("RESERVED", Self(0u32)),
+            ("KEY_AUDIO_DESC", Self(622u32)),
+            ("KPDOT", Self(83u32)),
+            ("SHOP", Self(221u32)),
+            ("1", Self(2u32)),
+            ("HANGUEL", Self(122u32)),
+            ("KP1", Self(79u32)),
+            ("KEY_CYCLEWINDOWS", Self(154u32)),
+            ("KEY_POWER2", Self(356u32)),
+            ("KEY_PAGEDOWN", Self(109u32)),
+            ("KEY_SHOP", Self(221u32)),
+            ("PLAY", Self(207u32)),
+            ("KEY_DOCUMENTS", Self(235u32)),
+            ("WAKEUP", Self(143u32)),
+            ("6", Self(7u32)),
+            ("PAGEDOWN", Self(109u32)),
+            ("KEY_NUMLOCK", Self(69u32)),
+            ("F8", Self(66u32)),
+            ("KEY_MACRO20", Self(675u32)),
+            ("KEY_BRIGHTNESSUP", Self(225u32)),
+            ("KEY_APPSELECT", Self(580u32)),
+            ("KEY_LANGUAGE", Self(368u32)),
+            ("KEY_VIDEO_PREV", Self(242u32)),
+            ("KEY_10CHANNELSUP", Self(440u32)),
+            ("PVR", Self(366u32)),
+            ("KEY_PLAYER", Self(387u32)),
+            ("AB", Self(406u32)),
+            ("KEY_BRL_DOT7", Self(503u32)),
+            ("KEY_SAVE", Self(234u32)),
+            ("KEY_FN_F", Self(482u32)),
+            ("KEY_VENDOR", Self(360u32)),
+            ("KEY_OK", Self(352u32)),
+            ("KEY_SPORT", Self(220u32)),
+            ("KEY_SLASH", Self(53u32)),
+            ("KEY_BRIGHTNESSDOWN", Self(224u32)),
+            ("CALC", Self(140u32)),
+            ("F17", Self(187u32)),
+            ("F16", Self(186u32)),
+            ("SCALE", Self(120u32)),
+            ("KEY_STOP", Self(128u32)),
+            ("KEY_MACRO11", Self(666u32)),
+            ("KEY_KEYBOARD", Self(374u32)),
+            ("_3D_MODE", Self(623u32)),
+            ("KEY_KPMINUS", Self(74u32)),
+            ("NUMERIC_A", Self(524u32)),
+            ("PLAYER", Self(387u32)),
+            ("KEY_SEARCH", Self(217u32)),
+            ("BRL_DOT7", Self(503u32)),
+            ("KEY_SLOWREVERSE", Self(630u32)),
+            ("KEY_NOTIFICATION_CENTER", Self(444u32)),
+            ("KEY_ATTENDANT_ON", Self(539u32)),
+            ("MACRO14", Self(669u32)),
+            ("CLOSE", Self(206u32)),
+            ("KEY_F15", Self(185u32)),
+            ("APOSTROPHE", Self(40u32)),
+            ("KEY_ZOOMOUT", Self(419u32)),
+            ("KPPLUS", Self(78u32)),
+            ("KEY_KBDINPUTASSIST_PREVGROUP", Self(610u32)),
+            ("L", Self(38u32)),
+            ("KEY_KPPLUSMINUS", Self(118u32)),
+            ("F20", Self(190u32)),
+            ("KEY_BUTTONCONFIG", Self(576u32)),
+            ("KEY_NUMERIC_A", Self(524u32)),
+            ("KEY_KPENTER", Self(96u32)),
+            ("KEY_TUNER", Self(386u32)),
+            ("KEY_BACK", Self(158u32)),
+            ("MP3", Self(391u32)),
+            ("KEY_1", Self(2u32)),
+            ("KP2", Self(80u32)),
+            ("KEY_CLOSECD", Self(160u32)),
+            ("ROOT_MENU", Self(618u32)),
+            ("KEY_OPTION", Self(357u32)),
+            ("KEY_FN_F1", Self(466u32)),
+            ("KEY_FAVORITES", Self(364u32)),
+            ("SLEEP", Self(142u32)),
+            ("NUMERIC_D", Self(527u32)),
+            ("T", Self(20u32)),
+            ("DATA", Self(631u32)),
+            ("KEY_BRIGHTNESS_MAX", Self(593u32)),
+            ("KEY_V", Self(47u32)),
+            ("KEY_FN_F5", Self(470u32)),
+            ("KEY_CAMERA_FOCUS", Self(528u32)),
+            ("KEY_BRL_DOT3", Self(499u32)),
+            ("FN_B", Self(484u32)),
+            ("EJECTCD", Self(161u32)),
+            ("ADDRESSBOOK", Self(429u32)),
+            ("KEY_PAUSE_RECORD", Self(626u32)),
+            ("KEY_DASHBOARD", Self(204u32)),
+            ("EPG", Self(365u32)),
+            ("KEY_HELP", Self(138u32)),
+            ("KEY_KBDILLUMTOGGLE", Self(228u32)),
+            ("KEY_N", Self(49u32)),
+            ("MACRO19", Self(674u32)),
+            ("SETUP", Self(141u32)),
+            ("FN_2", Self(479u32)),
+            ("KEY_U", Self(22u32)),
+            ("KEY_MENU", Self(139u32)),
+            ("KEY_ISO", Self(170u32)),
+            ("KEY_MACRO18", Self(673u32)),
+            ("KEY_MACRO25", Self(680u32)),
+            ("CD", Self(383u32)),
+            ("RIGHT_DOWN", Self(615u32)),
+            ("3D_MODE", Self(623u32)),
+            ("KPPLUSMINUS", Self(118u32)),
+            ("FIRST", Self(404u32)),
+            ("ATTENDANT_TOGGLE", Self(541u32)),
+            ("BRIGHTNESS_CYCLE", Self(243u32)),
+            ("KEY_W", Self(17u32)),
+            ("KEY_NUMERIC_11", Self(620u32)),
+            ("LEFT_UP", Self(616u32)),
+            ("KEY_PROPS", Self(130u32)),
+            ("KEY_5", Self(6u32)),
+            ("LEFTMETA", Self(125u32)),
+            ("NUMERIC_5", Self(517u32)),
+            ("VIDEOPHONE", Self(416u32)),
+            ("SCROLLLOCK", Self(70u32)),
+            ("NEXTSONG", Self(163u32)),
+            ("KEY_MACRO19", Self(674u32)),
+            ("KEY_0", Self(11u32)),
+            ("KEY_MACRO22", Self(677u32)),
+            ("KEY_EDITOR", Self(422u32)),
+            ("NUMERIC_STAR", Self(522u32)),
+            ("TWEN", Self(415u32)),
+            ("SHUFFLE", Self(410u32)),
+            ("KEY_TASKMANAGER", Self(577u32)),
+            ("EDITOR", Self(422u32)),
+            ("KEY_PROGRAM", Self(362u32)),
+            ("KEY_KP4", Self(75u32)),
+            ("KEY_PRIVACY_SCREEN_TOGGLE", Self(633u32)),
+            ("KEY_REPLY", Self(232u32)),
+            ("KEY_BATTERY", Self(236u32)),
+            ("BRL_DOT10", Self(506u32)),
+            ("KP0", Self(82u32)),
+            ("KEY_L", Self(38u32)),
+            ("KEY_RESTART", Self(408u32)),
+            ("CAMERA_ZOOMIN", Self(533u32)),
+            ("KEY_EMAIL", Self(215u32)),
+            ("PROPS", Self(130u32)),
+            ("KEY_FRAMEFORWARD", Self(437u32)),
+            ("NUMERIC_C", Self(526u32)),
+            ("_10CHANNELSUP", Self(440u32)),
+            ("KEY_PAGEUP", Self(104u32)),
+            ("CAMERA_LEFT", Self(537u32)),
+            ("YEN", Self(124u32)),
+            ("KEY_KP2", Self(80u32)),
+            ("NUMERIC_0", Self(512u32)),
+            ("NUMLOCK", Self(69u32)),
+            ("MACRO25", Self(680u32)),
+            ("KEY_MACRO10", Self(665u32)),
+            ("KEY_TITLE", Self(369u32)),
+            ("FN_F2", Self(467u32)),
+            ("MACRO26", Self(681u32)),
+            ("RIGHTALT", Self(100u32)),
+            ("KEY_BASSBOOST", Self(209u32)),
+            ("DEL_EOS", Self(449u32)),
+            ("KEY_SOUND", Self(213u32)),
+            ("KP3", Self(81u32)),
+            ("KPMINUS", Self(74u32)),
+            ("KEY_COMPUTER", Self(157u32)),
+            ("FRONT", Self(132u32)),
+            ("AGAIN", Self(129u32)),
+            ("KEY_ARCHIVE", Self(361u32)),
+            ("KEY_XFER", Self(147u32)),
+            ("MACRO12", Self(667u32)),
+            ("KEY_DELETE", Self(111u32)),
+            ("KEY_GRAPHICSEDITOR", Self(424u32)),
+            ("MACRO_PRESET_CYCLE", Self(690u32)),
+            ("KEY_LEFTALT", Self(56u32)),
+            ("EURO", Self(435u32)),
+            ("KBDINPUTASSIST_ACCEPT", Self(612u32)),
+            ("W", Self(17u32)),
+            ("SPELLCHECK", Self(432u32)),
+            ("KEY_F21", Self(191u32)),
+            ("KEY_BRL_DOT2", Self(498u32)),
+            ("RIGHTCTRL", Self(97u32)),
+            ("KEY_TAPE", Self(384u32)),
+            ("KEY_SCREENSAVER", Self(581u32)),
+            ("PREVIOUSSONG", Self(165u32)),
+            ("8", Self(9u32)),
+            ("KEY_MACRO4", Self(659u32)),
+            ("RIGHTSHIFT", Self(54u32)),
+            ("KEY_CONNECT", Self(218u32)),
+            ("KEY_BRL_DOT5", Self(501u32)),
+            ("ZOOMOUT", Self(419u32)),
+            ("KEY_QUESTION", Self(214u32)),
+            ("MEDIA", Self(226u32)),
+            ("KEY_NUMERIC_3", Self(515u32)),
+            ("POWER", Self(116u32)),
+            ("KEY_MUTE", Self(113u32)),
+            ("KEY_EQUAL", Self(13u32)),
+            ("F13", Self(183u32)),
+            ("KEY_NUMERIC_C", Self(526u32)),
+            ("FILE", Self(144u32)),
+            ("MACRO11", Self(666u32)),
+            ("KEY_PICKUP_PHONE", Self(445u32)),
+            ("KEY_RFKILL", Self(247u32)),
+            ("KEY_CANCEL", Self(223u32)),
+            ("KEY_8", Self(9u32)),
+            ("KEY_NUMERIC_POUND", Self(523u32)),
+            ("KEY_VOICECOMMAND", Self(582u32)),
+            ("KEY_KP7", Self(71u32)),
+            ("KEY_AB", Self(406u32)),
+            ("KBDILLUMDOWN", Self(229u32)),
+            ("SUSPEND", Self(205u32)),
+            ("KEY_BRL_DOT6", Self(502u32)),
+            ("KEY_INS_LINE", Self(450u32)),
+            ("KBDINPUTASSIST_NEXTGROUP", Self(611u32)),
+            ("MACRO1", Self(656u32)),
+            ("KEY_SAT", Self(381u32)),
+            ("KEY_FN_F9", Self(474u32)),
+            ("BACKSLASH", Self(43u32)),
+            ("KEY_WORDPROCESSOR", Self(421u32)),
+            ("KEY_SAT2", Self(382u32)),
+            ("KEY_LOGOFF", Self(433u32)),
+            ("BRL_DOT8", Self(504u32)),
+            ("CAMERA_DOWN", Self(536u32)),
+            ("MACRO8", Self(663u32)),
+            ("GREEN", Self(399u32)),
+            ("KEY_FN_S", Self(483u32)),
+            ("KBD_LCD_MENU3", Self(698u32)),
+            ("KEY_F2", Self(60u32)),
+            ("KEY_HANGEUL", Self(122u32)),
+            ("KEY_FN_F4", Self(469u32)),
+            ("ROTATE_DISPLAY", Self(153u32)),
+            ("KEY_MACRO2", Self(657u32)),
+            ("KEY_TIME", Self(359u32)),
+            ("CONTROLPANEL", Self(579u32)),
+            ("KEY_MACRO12", Self(667u32)),
+            ("MACRO13", Self(668u32)),
+            ("WPS_BUTTON", Self(529u32)),
+            ("SYSRQ", Self(99u32)),
+            ("KEY_PVR", Self(366u32)),
+            ("KEY_MSDOS", Self(151u32)),
+            ("KEY_MACRO28", Self(683u32)),
+            ("KEY_EJECTCLOSECD", Self(162u32)),
+            ("MAIL", Self(155u32)),
+            ("KEY_HIRAGANA", Self(91u32)),
+            ("COMPUTER", Self(157u32)),
+            ("VOD", Self(627u32)),
+            ("APPSELECT", Self(580u32)),
+            ("KEY_BRL_DOT9", Self(505u32)),
+            ("GAMES", Self(417u32)),
+            ("BASSBOOST", Self(209u32)),
+            ("KEY_SETUP", Self(141u32)),
+            ("DOCUMENTS", Self(235u32)),
+            ("REPLY", Self(232u32)),
+            ("TUNER", Self(386u32)),
+            ("KP8", Self(72u32)),
+            ("KEY_MODE", Self(373u32)),
+            ("CAMERA_RIGHT", Self(538u32)),
+            ("D", Self(32u32)),
+            ("KEY_RIGHT", Self(106u32)),
+            ("RED", Self(398u32)),
+            ("KPLEFTPAREN", Self(179u32)),
+            ("KEY_APOSTROPHE", Self(40u32)),
+            ("KEY_CALENDAR", Self(397u32)),
+            ("CHAT", Self(216u32)),
+            ("OPTION", Self(357u32)),
+            ("KEY_MEDIA_REPEAT", Self(439u32)),
+            ("MACRO23", Self(678u32)),
+            ("KEY_F13", Self(183u32)),
+            ("MACRO28", Self(683u32)),
+            ("F14", Self(184u32)),
+            ("CAPSLOCK", Self(58u32)),
+            ("KEY_DIRECTION", Self(153u32)),
+            ("WIMAX", Self(246u32)),
+            ("MACRO_PRESET3", Self(693u32)),
+            ("KEY_NEXT", Self(407u32)),
+            ("NUMERIC_9", Self(521u32)),
+            ("NUMERIC_1", Self(513u32)),
+            ("AUDIO", Self(392u32)),
+            ("KEY_AUDIO", Self(392u32)),
+            ("ZENKAKUHANKAKU", Self(85u32)),
+        ],
+    };
+    #[doc = r" Looks up a code by name."]
+    #[doc = r""]
+    #[doc = r" Both the name defined in the kernel headers (`KEY_LEFTMETA`) and the name of the associated"]
+    #[doc = r" constant (`LEFTMETA`) are accepted. The name of a constant which starts with a digit may be given"]
+    #[doc = r" with or without its leading underscore (`_0` or `0`). The name is case sensitive, see"]
+    #[doc = r" [`from_name_ignore_case`](Self::from_name_ignore_case) for a case insensitive lookup."]
+    pub fn from_name(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, false)
+            .ok_or(crate::ParseCodeError::new("Key"))
+    }
+    #[doc = r" Looks up a code by name, ignoring ASCII case."]
+    #[doc = r""]
+    #[doc = r" This accepts the same names as [`from_name`](Self::from_name)."]
+    pub fn from_name_ignore_case(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, true)
+            .ok_or(crate::ParseCodeError::new("Key"))
+    }
+}
+impl Key {
+    #[doc = r" Every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" Codes with multiple names are only listed once, and values which only describe the range of codes"]
+    #[doc = r" (such as `MAX`) are not included."]
+    pub const ALL: &'static [Self] = &[
+        Self(0u32),
+        Self(1u32),
+        Self(2u32),
+        Self(3u32),
+        Self(4u32),
+        Self(5u32),
+        Self(6u32),
+        Self(7u32),
+        Self(8u32),
+        Self(9u32),
+        Self(10u32),
+        Self(11u32),
+        Self(12u32),
+        Self(13u32),
+        Self(14u32),
+        Self(15u32),
+        Self(16u32),
+        Self(17u32),
+        Self(18u32),
+        Self(19u32),
+        Self(20u32),
+        Self(21u32),
+        Self(22u32),
+        Self(23u32),
+        Self(24u32),
+        Self(25u32),
+        Self(26u32),
+        Self(27u32),
+        Self(28u32),
+        Self(29u32),
+        Self(30u32),
+        Self(31u32),
+        Self(32u32),
+        Self(33u32),
+        Self(34u32),
+        Self(35u32),
+        Self(36u32),
+        Self(37u32),
+        Self(38u32),
+        Self(39u32),
+        Self(40u32),
+        Self(41u32),
+        Self(42u32),
+        Self(43u32),
+        Self(44u32),
+        Self(45u32),
+        Self(46u32),
+        Self(47u32),
+        Self(48u32),
+        Self(49u32),
+        Self(50u32),
+        Self(51u32),
+        Self(52u32),
+        Self(53u32),
+        Self(54u32),
+        Self(55u32),
+        Self(56u32),
+        Self(57u32),
+        Self(58u32),
+        Self(59u32),
+        Self(60u32),
+        Self(61u32),
+        Self(62u32),
+        Self(63u32),
+        Self(64u32),
+        Self(65u32),
+        Self(66u32),
+        Self(67u32),
+        Self(68u32),
+        Self(69u32),
+        Self(70u32),
+        Self(71u32),
+        Self(72u32),
+        Self(73u32),
+        Self(74u32),
+        Self(75u32),
+        Self(76u32),
+        Self(77u32),
+        Self(78u32),
+        Self(79u32),
+        Self(80u32),
+        Self(81u32),
+        Self(82u32),
+        Self(83u32),
+        Self(85u32),
+        Self(86u32),
+        Self(87u32),
+        Self(88u32),
+        Self(89u32),
+        Self(90u32),
+        Self(91u32),
+        Self(92u32),
+        Self(93u32),
+        Self(94u32),
+        Self(95u32),
+        Self(96u32),
+        Self(97u32),
+        Self(98u32),
+        Self(99u32),
+        Self(100u32),
+        Self(101u32),
+        Self(102u32),
+        Self(103u32),
+        Self(104u32),
+        Self(105u32),
+        Self(106u32),
+        Self(107u32),
+        Self(108u32),
+        Self(109u32),
+        Self(110u32),
+        Self(111u32),
+        Self(112u32),
+        Self(113u32),
+        Self(114u32),
+        Self(115u32),
+        Self(116u32),
+        Self(117u32),
+        Self(118u32),
+        Self(119u32),
+        Self(120u32),
+        Self(121u32),
+        Self(122u32),
+        Self(123u32),
+        Self(124u32),
+        Self(125u32),
+        Self(126u32),
+        Self(127u32),
+        Self(128u32),
+        Self(129u32),
+        Self(130u32),
+        Self(131u32),
+        Self(132u32),
+        Self(133u32),
+        Self(134u32),
+        Self(135u32),
+        Self(136u32),
+        Self(137u32),
+        Self(138u32),
+        Self(139u32),
+        Self(140u32),
+        Self(141u32),
+        Self(142u32),
+        Self(143u32),
+        Self(144u32),
+        Self(145u32),
+        Self(146u32),
+        Self(147u32),
+        Self(148u32),
+        Self(149u32),
+        Self(150u32),
+        Self(151u32),
+        Self(152u32),
+        Self(153u32),
+        Self(154u32),
+        Self(155u32),
+        Self(156u32),
+        Self(157u32),
+        Self(158u32),
+        Self(159u32),
+        Self(160u32),
+        Self(161u32),
+        Self(162u32),
+        Self(163u32),
+        Self(164u32),
+        Self(165u32),
+        Self(166u32),
+        Self(167u32),
+        Self(168u32),
+        Self(169u32),
+        Self(170u32),
+        Self(171u32),
+        Self(172u32),
+        Self(173u32),
+        Self(174u32),
+        Self(175u32),
+        Self(176u32),
+        Self(177u32),
+        Self(178u32),
+        Self(179u32),
+        Self(180u32),
+        Self(181u32),
+        Self(182u32),
+        Self(183u32),
+        Self(184u32),
+        Self(185u32),
+        Self(186u32),
+        Self(187u32),
+        Self(188u32),
+        Self(189u32),
+        Self(190u32),
+        Self(191u32),
+        Self(192u32),
+        Self(193u32),
+        Self(194u32),
+        Self(200u32),
+        Self(201u32),
+        Self(202u32),
+        Self(203u32),
+        Self(204u32),
+        Self(205u32),
+        Self(206u32),
+        Self(207u32),
+        Self(208u32),
+        Self(209u32),
+        Self(210u32),
+        Self(211u32),
+        Self(212u32),
+        Self(213u32),
+        Self(214u32),
+        Self(215u32),
+        Self(216u32),
+        Self(217u32),
+        Self(218u32),
+        Self(219u32),
+        Self(220u32),
+        Self(221u32),
+        Self(222u32),
+        Self(223u32),
+        Self(224u32),
+        Self(225u32),
+        Self(226u32),
+        Self(227u32),
+        Self(228u32),
+        Self(229u32),
+        Self(230u32),
+        Self(231u32),
+        Self(232u32),
+        Self(233u32),
+        Self(234u32),
+        Self(235u32),
+        Self(236u32),
+        Self(237u32),
+        Self(238u32),
+        Self(239u32),
+        Self(240u32),
+        Self(241u32),
+        Self(242u32),
+        Self(243u32),
+        Self(244u32),
+        Self(245u32),
+        Self(246u32),
+        Self(247u32),
+        Self(248u32),
+        Self(352u32),
+        Self(353u32),
+        Self(354u32),
+        Self(355u32),
+        Self(356u32),
+        Self(357u32),
+        Self(358u32),
+        Self(359u32),
+        Self(360u32),
+        Self(361u32),
+        Self(362u32),
+        Self(363u32),
+        Self(364u32),
+        Self(365u32),
+        Self(366u32),
+        Self(367u32),
+        Self(368u32),
+        Self(369u32),
+        Self(370u32),
+        Self(371u32),
+        Self(372u32),
+        Self(373u32),
+        Self(374u32),
+        Self(375u32),
+        Self(376u32),
+        Self(377u32),
+        Self(378u32),
+        Self(379u32),
+        Self(380u32),
+        Self(381u32),
+        Self(382u32),
+        Self(383u32),
+        Self(384u32),
+        Self(385u32),
+        Self(386u32),
+        Self(387u32),
+        Self(388u32),
+        Self(389u32),
+        Self(390u32),
+        Self(391u32),
+        Self(392u32),
+        Self(393u32),
+        Self(394u32),
+        Self(395u32),
+        Self(396u32),
+        Self(397u32),
+        Self(398u32),
+        Self(399u32),
+        Self(400u32),
+        Self(401u32),
+        Self(402u32),
+        Self(403u32),
+        Self(404u32),
+        Self(405u32),
+        Self(406u32),
+        Self(407u32),
+        Self(408u32),
+        Self(409u32),
+        Self(410u32),
+        Self(411u32),
+        Self(412u32),
+        Self(413u32),
+        Self(414u32),
+        Self(415u32),
+        Self(416u32),
+        Self(417u32),
+        Self(418u32),
+        Self(419u32),
+        Self(420u32),
+        Self(421u32),
+        Self(422u32),
+        Self(423u32),
+        Self(424u32),
+        Self(425u32),
+        Self(426u32),
+        Self(427u32),
+        Self(428u32),
+        Self(429u32),
+        Self(430u32),
+        Self(431u32),
+        Self(432u32),
+        Self(433u32),
+        Self(434u32),
+        Self(435u32),
+        Self(436u32),
+        Self(437u32),
+        Self(438u32),
+        Self(439u32),
+        Self(440u32),
+        Self(441u32),
+        Self(442u32),
+        Self(444u32),
+        Self(445u32),
+        Self(446u32),
+        Self(448u32),
+        Self(449u32),
+        Self(450u32),
+        Self(451u32),
+        Self(464u32),
+        Self(465u32),
+        Self(466u32),
+        Self(467u32),
+        Self(468u32),
+        Self(469u32),
+        Self(470u32),
+        Self(471u32),
+        Self(472u32),
+        Self(473u32),
+        Self(474u32),
+        Self(475u32),
+        Self(476u32),
+        Self(477u32),
+        Self(478u32),
+        Self(479u32),
+        Self(480u32),
+        Self(481u32),
+        Self(482u32),
+        Self(483u32),
+        Self(484u32),
+        Self(485u32),
+        Self(497u32),
+        Self(498u32),
+        Self(499u32),
+        Self(500u32),
+        Self(501u32),
+        Self(502u32),
+        Self(503u32),
+        Self(504u32),
+        Self(505u32),
+        Self(506u32),
+        Self(512u32),
+        Self(513u32),
+        Self(514u32),
+        Self(515u32),
+        Self(516u32),
+        Self(517u32),
+        Self(518u32),
+        Self(519u32),
+        Self(520u32),
+        Self(521u32),
+        Self(522u32),
+        Self(523u32),
+        Self(524u32),
+        Self(525u32),
+        Self(526u32),
+        Self(527u32),
+        Self(528u32),
+        Self(529u32),
+        Self(530u32),
+        Self(531u32),
+        Self(532u32),
+        Self(533u32),
+        Self(534u32),
+        Self(535u32),
+        Self(536u32),
+        Self(537u32),
+        Self(538u32),
+        Self(539u32),
+        Self(540u32),
+        Self(541u32),
+        Self(542u32),
+        Self(560u32),
+        Self(561u32),
+        Self(576u32),
+        Self(577u32),
+        Self(578u32),
+        Self(579u32),
+        Self(580u32),
+        Self(581u32),
+        Self(582u32),
+        Self(583u32),
+        Self(584u32),
+        Self(585u32),
+        Self(592u32),
+        Self(593u32),
+        Self(608u32),
+        Self(609u32),
+        Self(610u32),
+        Self(611u32),
+        Self(612u32),
+        Self(613u32),
+        Self(614u32),
+        Self(615u32),
+        Self(616u32),
+        Self(617u32),
+        Self(618u32),
+        Self(619u32),
+        Self(620u32),
+        Self(621u32),
+        Self(622u32),
+        Self(623u32),
+        Self(624u32),
+        Self(625u32),
+        Self(626u32),
+        Self(627u32),
+        Self(628u32),
+        Self(629u32),
+        Self(630u32),
+        Self(631u32),
+        Self(632u32),
+        Self(633u32),
+        Self(634u32),
+        Self(656u32),
+        Self(657u32),
+        Self(658u32),
+        Self(659u32),
+        Self(660u32),
+        Self(661u32),
+        Self(662u32),
+        Self(663u32),
+        Self(664u32),
+        Self(665u32),
+        Self(666u32),
+        Self(667u32),
+        Self(668u32),
+        Self(669u32),
+        Self(670u32),
+        Self(671u32),
+        Self(672u32),
+        Self(673u32),
+        Self(674u32),
+        Self(675u32),
+        Self(676u32),
+        Self(677u32),
+        Self(678u32),
+        Self(679u32),
+        Self(680u32),
+        Self(681u32),
+        Self(682u32),
+        Self(683u32),
+        Self(684u32),
+        Self(685u32),
+        Self(688u32),
+        Self(689u32),
+        Self(690u32),
+        Self(691u32),
+        Self(692u32),
+        Self(693u32),
+        Self(696u32),
+        Self(697u32),
+        Self(698u32),
+        Self(699u32),
+        Self(700u32),
+    ];
+    #[doc = r" Returns an iterator over every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" This iterates over [`ALL`](Self::ALL)."]
+    pub fn iter() -> ::core::iter::Copied<::core::slice::Iter<'static, Self>> {
+        Self::ALL.iter().copied()
+    }
+}
+impl ::core::str::FromStr for Key {
+    type Err = crate::ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s)
+    }
+}
+impl ::core::fmt::Debug for Key {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(self, f)
+    }
+}
+impl ::core::fmt::Display for Key {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.name() {
+            Some(name) => f.pad(name),
+            None => write!(f, "KEY_{:#x}", self.0),
+        }
+    }
+}
 impl From<u32> for Key {
     fn from(value: u32) -> Self {
         Self(value)
@@ -836,6 +5344,7 @@ impl Key {
     pub const KPCOMMA: Key = Key(121u32);
     #[doc(alias = "KEY_HANGEUL")]
     pub const HANGEUL: Key = Key(122u32);
+    #[doc = "Alias of [`HANGEUL`](Self::HANGEUL)."]
     #[doc(alias = "KEY_HANGUEL")]
     pub const HANGUEL: Key = Key(122u32);
     #[doc(alias = "KEY_HANJA")]
@@ -914,11 +5423,13 @@ impl Key {
     #[doc = " AL Terminal Lock/Screensaver "]
     #[doc(alias = "KEY_COFFEE")]
     pub const COFFEE: Key = Key(152u32);
+    #[doc = "Alias of [`COFFEE`](Self::COFFEE)."]
     #[doc(alias = "KEY_SCREENLOCK")]
     pub const SCREENLOCK: Key = Key(152u32);
     #[doc = " Display orientation for e.g. tablets "]
     #[doc(alias = "KEY_ROTATE_DISPLAY")]
     pub const ROTATE_DISPLAY: Key = Key(153u32);
+    #[doc = "Alias of [`ROTATE_DISPLAY`](Self::ROTATE_DISPLAY)."]
     #[doc(alias = "KEY_DIRECTION")]
     pub const DIRECTION: Key = Key(153u32);
     #[doc(alias = "KEY_CYCLEWINDOWS")]
@@ -1117,6 +5628,7 @@ impl Key {
     #[doc = " Set Auto Brightness: manual\n\t\t\t\t\t  brightness control is off,\n\t\t\t\t\t  rely on ambient "]
     #[doc(alias = "KEY_BRIGHTNESS_AUTO")]
     pub const BRIGHTNESS_AUTO: Key = Key(244u32);
+    #[doc = "Alias of [`BRIGHTNESS_AUTO`](Self::BRIGHTNESS_AUTO)."]
     #[doc(alias = "KEY_BRIGHTNESS_ZERO")]
     pub const BRIGHTNESS_ZERO: Key = Key(244u32);
     #[doc = " display device to off state "]
@@ -1125,6 +5637,7 @@ impl Key {
     #[doc = " Wireless WAN (LTE, UMTS, GSM, etc.) "]
     #[doc(alias = "KEY_WWAN")]
     pub const WWAN: Key = Key(246u32);
+    #[doc = "Alias of [`WWAN`](Self::WWAN)."]
     #[doc(alias = "KEY_WIMAX")]
     pub const WIMAX: Key = Key(246u32);
     #[doc = " Key that controls all radios "]
@@ -1179,6 +5692,7 @@ impl Key {
     #[doc = " AC View Toggle "]
     #[doc(alias = "KEY_FULL_SCREEN")]
     pub const FULL_SCREEN: Key = Key(372u32);
+    #[doc = "Alias of [`FULL_SCREEN`](Self::FULL_SCREEN)."]
     #[doc(alias = "KEY_ZOOM")]
     pub const ZOOM: Key = Key(372u32);
     #[doc(alias = "KEY_MODE")]
@@ -1188,6 +5702,7 @@ impl Key {
     #[doc = " HUTRR37: Aspect "]
     #[doc(alias = "KEY_ASPECT_RATIO")]
     pub const ASPECT_RATIO: Key = Key(375u32);
+    #[doc = "Alias of [`ASPECT_RATIO`](Self::ASPECT_RATIO)."]
     #[doc(alias = "KEY_SCREEN")]
     pub const SCREEN: Key = Key(375u32);
     #[doc = " Media Select Computer "]
@@ -1334,6 +5849,7 @@ impl Key {
     #[doc = " Turn display (LCD) on and off "]
     #[doc(alias = "KEY_DISPLAYTOGGLE")]
     pub const DISPLAYTOGGLE: Key = Key(431u32);
+    #[doc = "Alias of [`DISPLAYTOGGLE`](Self::DISPLAYTOGGLE)."]
     #[doc(alias = "KEY_BRIGHTNESS_TOGGLE")]
     pub const BRIGHTNESS_TOGGLE: Key = Key(431u32);
     #[doc = " AL Spell Check "]
@@ -1696,15 +6212,26 @@ impl Key {
     pub const KBD_LCD_MENU4: Key = Key(699u32);
     #[doc(alias = "KEY_KBD_LCD_MENU5")]
     pub const KBD_LCD_MENU5: Key = Key(700u32);
+}
+impl Key {
+    #[doc = r" The smallest code the kernel includes in module aliases."]
+    #[doc = r""]
+    #[doc = r" This is a bound rather than a code, and has the same value as a code."]
     #[doc(alias = "KEY_MIN_INTERESTING")]
-    pub const MIN_INTERESTING: Key = Key(113u32);
+    pub const MIN_INTERESTING: Self = Self(113u32);
+    #[doc = r" The largest value a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is a bound rather than a code, and may have the same value as a code."]
     #[doc(alias = "KEY_MAX")]
-    pub const MAX: Key = Key(767u32);
+    pub const MAX: Self = Self(767u32);
+    #[doc = r" The number of values a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is the size of the bitmaps the kernel uses to describe the supported codes."]
     #[doc(alias = "KEY_CNT")]
-    pub const CNT: Key = Key(768u32);
+    pub const COUNT: usize = 768usize;
 }
 #[repr(transparent)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Led(u32);
 impl Led {
     pub const fn new(value: u32) -> Self {
@@ -1714,6 +6241,136 @@ impl Led {
         self.0
     }
 }
+impl Led {
+    #[doc = r" Returns the name of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" If the code has multiple names, the canonical name is returned. The other names of the code are"]
+    #[doc = r" returned by [`aliases`](Self::aliases)."]
+    #[doc = r""]
+    #[doc = r" Returns [`None`] if the value is not a known code."]
+    pub const fn name(self) -> Option<&'static str> {
+        let index = self.0.wrapping_sub(0u32) as usize;
+        if index < Self::NAMES_BY_VALUE.len() {
+            Self::NAMES_BY_VALUE[index]
+        } else {
+            None
+        }
+    }
+}
+impl Led {
+    #[doc = r" Returns the other names of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" The canonical name returned by [`name`](Self::name) is not included. Returns an empty slice if the"]
+    #[doc = r" code has no other names or is not a known code."]
+    pub const fn aliases(self) -> &'static [&'static str] {
+        &[]
+    }
+}
+impl Led {
+    const NAMES_BY_VALUE: &'static [Option<&'static str>] = &[
+        Some("LED_NUML"),
+        Some("LED_CAPSL"),
+        Some("LED_SCROLLL"),
+        Some("LED_COMPOSE"),
+        Some("LED_KANA"),
+        Some("LED_SLEEP"),
+        Some("LED_SUSPEND"),
+        Some("LED_MUTE"),
+        Some("LED_MISC"),
+        Some("LED_MAIL"),
+        Some("LED_CHARGING"),
+    ];
+    const NAME_MAP: crate::phf::NameMap<Self> = crate::phf::NameMap {
+        seeds: &[17u32, 45u32, 66u32, 918u32, 33u32, 5u32],
+        entries: &[
+            ("LED_SCROLLL", Self(2u32)),
+            ("LED_CHARGING", Self(10u32)),
+            ("NUML", Self(0u32)),
+            ("MUTE", Self(7u32)),
+            ("COMPOSE", Self(3u32)),
+            ("LED_SUSPEND", Self(6u32)),
+            ("SLEEP", Self(5u32)),
+            ("LED_CAPSL", Self(1u32)),
+            ("LED_COMPOSE", Self(3u32)),
+            ("LED_MISC", Self(8u32)),
+            ("SUSPEND", Self(6u32)),
+            ("KANA", Self(4u32)),
+            ("LED_MUTE", Self(7u32)),
+            ("LED_MAIL", Self(9u32)),
+            ("LED_SLEEP", Self(5u32)),
+            ("SCROLLL", Self(2u32)),
+            ("CAPSL", Self(1u32)),
+            ("LED_KANA", Self(4u32)),
+            ("MISC", Self(8u32)),
+            ("MAIL", Self(9u32)),
+            ("CHARGING", Self(10u32)),
+            ("LED_NUML", Self(0u32)),
+        ],
+    };
+    #[doc = r" Looks up a code by name."]
+    #[doc = r""]
+    #[doc = r" Both the name defined in the kernel headers (`KEY_LEFTMETA`) and the name of the associated"]
+    #[doc = r" constant (`LEFTMETA`) are accepted. The name of a constant which starts with a digit may be given"]
+    #[doc = r" with or without its leading underscore (`_0` or `0`). The name is case sensitive, see"]
+    #[doc = r" [`from_name_ignore_case`](Self::from_name_ignore_case) for a case insensitive lookup."]
+    pub fn from_name(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, false)
+            .ok_or(crate::ParseCodeError::new("Led"))
+    }
+    #[doc = r" Looks up a code by name, ignoring ASCII case."]
+    #[doc = r""]
+    #[doc = r" This accepts the same names as [`from_name`](Self::from_name)."]
+    pub fn from_name_ignore_case(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, true)
+            .ok_or(crate::ParseCodeError::new("Led"))
+    }
+}
+impl Led {
+    #[doc = r" Every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" Codes with multiple names are only listed once, and values which only describe the range of codes"]
+    #[doc = r" (such as `MAX`) are not included."]
+    pub const ALL: &'static [Self] = &[
+        Self(0u32),
+        Self(1u32),
+        Self(2u32),
+        Self(3u32),
+        Self(4u32),
+        Self(5u32),
+        Self(6u32),
+        Self(7u32),
+        Self(8u32),
+        Self(9u32),
+        Self(10u32),
+    ];
+    #[doc = r" Returns an iterator over every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" This iterates over [`ALL`](Self::ALL)."]
+    pub fn iter() -> ::core::iter::Copied<::core::slice::Iter<'static, Self>> {
+        Self::ALL.iter().copied()
+    }
+}
+impl ::core::str::FromStr for Led {
+    type Err = crate::ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s)
+    }
+}
+impl ::core::fmt::Debug for Led {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(self, f)
+    }
+}
+impl ::core::fmt::Display for Led {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.name() {
+            Some(name) => f.pad(name),
+            None => write!(f, "LED_{:#x}", self.0),
+        }
+    }
+}
 impl From<u32> for Led {
     fn from(value: u32) -> Self {
         Self(value)
@@ -1762,14 +6419,22 @@ impl Led {
     pub const MAIL: Led = Led(9u32);
     #[doc(alias = "LED_CHARGING")]
     pub const CHARGING: Led = Led(10u32);
+}
+impl Led {
+    #[doc = r" The largest value a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is a bound rather than a code, and may have the same value as a code."]
     #[doc(alias = "LED_MAX")]
-    pub const MAX: Led = Led(15u32);
+    pub const MAX: Self = Self(15u32);
+    #[doc = r" The number of values a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is the size of the bitmaps the kernel uses to describe the supported codes."]
     #[doc(alias = "LED_CNT")]
-    pub const CNT: Led = Led(16u32);
+    pub const COUNT: usize = 16usize;
 }
 #[doc = "Miscellaneous events."]
 #[repr(transparent)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct MiscEvent(u32);
 impl MiscEvent {
     pub const fn new(value: u32) -> Self {
@@ -1779,6 +6444,116 @@ impl MiscEvent {
         self.0
     }
 }
+impl MiscEvent {
+    #[doc = r" Returns the name of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" If the code has multiple names, the canonical name is returned. The other names of the code are"]
+    #[doc = r" returned by [`aliases`](Self::aliases)."]
+    #[doc = r""]
+    #[doc = r" Returns [`None`] if the value is not a known code."]
+    pub const fn name(self) -> Option<&'static str> {
+        let index = self.0.wrapping_sub(0u32) as usize;
+        if index < Self::NAMES_BY_VALUE.len() {
+            Self::NAMES_BY_VALUE[index]
+        } else {
+            None
+        }
+    }
+}
+impl MiscEvent {
+    #[doc = r" Returns the other names of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" The canonical name returned by [`name`](Self::name) is not included. Returns an empty slice if the"]
+    #[doc = r" code has no other names or is not a known code."]
+    pub const fn aliases(self) -> &'static [&'static str] {
+        &[]
+    }
+}
+impl MiscEvent {
+    const NAMES_BY_VALUE: &'static [Option<&'static str>] = &[
+        Some("MSC_SERIAL"),
+        Some("MSC_PULSELED"),
+        Some("MSC_GESTURE"),
+        Some("MSC_RAW"),
+        Some("MSC_SCAN"),
+        Some("MSC_TIMESTAMP"),
+    ];
+    const NAME_MAP: crate::phf::NameMap<Self> = crate::phf::NameMap {
+        seeds: &[353u32, 1u32, 64u32],
+        entries: &[
+            ("SCAN", Self(4u32)),
+            ("MSC_RAW", Self(3u32)),
+            ("TIMESTAMP", Self(5u32)),
+            ("MSC_PULSELED", Self(1u32)),
+            ("MSC_TIMESTAMP", Self(5u32)),
+            ("MSC_GESTURE", Self(2u32)),
+            ("PULSELED", Self(1u32)),
+            ("MSC_SCAN", Self(4u32)),
+            ("SERIAL", Self(0u32)),
+            ("MSC_SERIAL", Self(0u32)),
+            ("RAW", Self(3u32)),
+            ("GESTURE", Self(2u32)),
+        ],
+    };
+    #[doc = r" Looks up a code by name."]
+    #[doc = r""]
+    #[doc = r" Both the name defined in the kernel headers (`KEY_LEFTMETA`) and the name of the associated"]
+    #[doc = r" constant (`LEFTMETA`) are accepted. The name of a constant which starts with a digit may be given"]
+    #[doc = r" with or without its leading underscore (`_0` or `0`). The name is case sensitive, see"]
+    #[doc = r" [`from_name_ignore_case`](Self::from_name_ignore_case) for a case insensitive lookup."]
+    pub fn from_name(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, false)
+            .ok_or(crate::ParseCodeError::new("MiscEvent"))
+    }
+    #[doc = r" Looks up a code by name, ignoring ASCII case."]
+    #[doc = r""]
+    #[doc = r" This accepts the same names as [`from_name`](Self::from_name)."]
+    pub fn from_name_ignore_case(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, true)
+            .ok_or(crate::ParseCodeError::new("MiscEvent"))
+    }
+}
+impl MiscEvent {
+    #[doc = r" Every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" Codes with multiple names are only listed once, and values which only describe the range of codes"]
+    #[doc = r" (such as `MAX`) are not included."]
+    pub const ALL: &'static [Self] = &[
+        Self(0u32),
+        Self(1u32),
+        Self(2u32),
+        Self(3u32),
+        Self(4u32),
+        Self(5u32),
+    ];
+    #[doc = r" Returns an iterator over every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" This iterates over [`ALL`](Self::ALL)."]
+    pub fn iter() -> ::core::iter::Copied<::core::slice::Iter<'static, Self>> {
+        Self::ALL.iter().copied()
+    }
+}
+impl ::core::str::FromStr for MiscEvent {
+    type Err = crate::ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s)
+    }
+}
+impl ::core::fmt::Debug for MiscEvent {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(self, f)
+    }
+}
+impl ::core::fmt::Display for MiscEvent {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.name() {
+            Some(name) => f.pad(name),
+            None => write!(f, "MSC_{:#x}", self.0),
+        }
+    }
+}
 impl From<u32> for MiscEvent {
     fn from(value: u32) -> Self {
         Self(value)
@@ -1817,14 +6592,22 @@ impl MiscEvent {
     pub const SCAN: MiscEvent = MiscEvent(4u32);
     #[doc(alias = "MSC_TIMESTAMP")]
     pub const TIMESTAMP: MiscEvent = MiscEvent(5u32);
+}
+impl MiscEvent {
+    #[doc = r" The largest value a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is a bound rather than a code, and may have the same value as a code."]
     #[doc(alias = "MSC_MAX")]
-    pub const MAX: MiscEvent = MiscEvent(7u32);
+    pub const MAX: Self = Self(7u32);
+    #[doc = r" The number of values a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is the size of the bitmaps the kernel uses to describe the supported codes."]
     #[doc(alias = "MSC_CNT")]
-    pub const CNT: MiscEvent = MiscEvent(8u32);
+    pub const COUNT: usize = 8usize;
 }
 #[doc = "Relative axes."]
 #[repr(transparent)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct RelativeAxis(u32);
 impl RelativeAxis {
     pub const fn new(value: u32) -> Self {
@@ -1834,6 +6617,144 @@ impl RelativeAxis {
         self.0
     }
 }
+impl RelativeAxis {
+    #[doc = r" Returns the name of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" If the code has multiple names, the canonical name is returned. The other names of the code are"]
+    #[doc = r" returned by [`aliases`](Self::aliases)."]
+    #[doc = r""]
+    #[doc = r" Returns [`None`] if the value is not a known code."]
+    pub const fn name(self) -> Option<&'static str> {
+        let index = self.0.wrapping_sub(0u32) as usize;
+        if index < Self::NAMES_BY_VALUE.len() {
+            Self::NAMES_BY_VALUE[index]
+        } else {
+            None
+        }
+    }
+}
+impl RelativeAxis {
+    #[doc = r" Returns the other names of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" The canonical name returned by [`name`](Self::name) is not included. Returns an empty slice if the"]
+    #[doc = r" code has no other names or is not a known code."]
+    pub const fn aliases(self) -> &'static [&'static str] {
+        &[]
+    }
+}
+impl RelativeAxis {
+    const NAMES_BY_VALUE: &'static [Option<&'static str>] = &[
+        Some("REL_X"),
+        Some("REL_Y"),
+        Some("REL_Z"),
+        Some("REL_RX"),
+        Some("REL_RY"),
+        Some("REL_RZ"),
+        Some("REL_HWHEEL"),
+        Some("REL_DIAL"),
+        Some("REL_WHEEL"),
+        Some("REL_MISC"),
+        Some("REL_RESERVED"),
+        Some("REL_WHEEL_HI_RES"),
+        Some("REL_HWHEEL_HI_RES"),
+    ];
+    const NAME_MAP: crate::phf::NameMap<Self> = crate::phf::NameMap {
+        seeds: &[10u32, 14u32, 17u32, 4u32, 3u32, 641u32, 520u32],
+        entries: &[
+            ("Z", Self(2u32)),
+            ("REL_DIAL", Self(7u32)),
+            ("WHEEL_HI_RES", Self(11u32)),
+            ("REL_RX", Self(3u32)),
+            ("REL_MISC", Self(9u32)),
+            ("REL_WHEEL_HI_RES", Self(11u32)),
+            ("DIAL", Self(7u32)),
+            ("RZ", Self(5u32)),
+            ("REL_RZ", Self(5u32)),
+            ("RY", Self(4u32)),
+            ("REL_Z", Self(2u32)),
+            ("X", Self(0u32)),
+            ("REL_RY", Self(4u32)),
+            ("WHEEL", Self(8u32)),
+            ("MISC", Self(9u32)),
+            ("REL_HWHEEL_HI_RES", Self(12u32)),
+            ("REL_WHEEL", Self(8u32)),
+            ("REL_Y", Self(1u32)),
+            ("HWHEEL_HI_RES", Self(12u32)),
+            ("RESERVED", Self(10u32)),
+            ("REL_HWHEEL", Self(6u32)),
+            ("Y", Self(1u32)),
+            ("RX", Self(3u32)),
+            ("REL_RESERVED", Self(10u32)),
+            ("HWHEEL", Self(6u32)),
+            ("REL_X", Self(0u32)),
+        ],
+    };
+    #[doc = r" Looks up a code by name."]
+    #[doc = r""]
+    #[doc = r" Both the name defined in the kernel headers (`KEY_LEFTMETA`) and the name of the associated"]
+    #[doc = r" constant (`LEFTMETA`) are accepted. The name of a constant which starts with a digit may be given"]
+    #[doc = r" with or without its leading underscore (`_0` or `0`). The name is case sensitive, see"]
+    #[doc = r" [`from_name_ignore_case`](Self::from_name_ignore_case) for a case insensitive lookup."]
+    pub fn from_name(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, false)
+            .ok_or(crate::ParseCodeError::new("RelativeAxis"))
+    }
+    #[doc = r" Looks up a code by name, ignoring ASCII case."]
+    #[doc = r""]
+    #[doc = r" This accepts the same names as [`from_name`](Self::from_name)."]
+    pub fn from_name_ignore_case(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, true)
+            .ok_or(crate::ParseCodeError::new("RelativeAxis"))
+    }
+}
+impl RelativeAxis {
+    #[doc = r" Every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" Codes with multiple names are only listed once, and values which only describe the range of codes"]
+    #[doc = r" (such as `MAX`) are not included."]
+    pub const ALL: &'static [Self] = &[
+        Self(0u32),
+        Self(1u32),
+        Self(2u32),
+        Self(3u32),
+        Self(4u32),
+        Self(5u32),
+        Self(6u32),
+        Self(7u32),
+        Self(8u32),
+        Self(9u32),
+        Self(10u32),
+        Self(11u32),
+        Self(12u32),
+    ];
+    #[doc = r" Returns an iterator over every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" This iterates over [`ALL`](Self::ALL)."]
+    pub fn iter() -> ::core::iter::Copied<::core::slice::Iter<'static, Self>> {
+        Self::ALL.iter().copied()
+    }
+}
+impl ::core::str::FromStr for RelativeAxis {
+    type Err = crate::ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s)
+    }
+}
+impl ::core::fmt::Debug for RelativeAxis {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(self, f)
+    }
+}
+impl ::core::fmt::Display for RelativeAxis {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.name() {
+            Some(name) => f.pad(name),
+            None => write!(f, "REL_{:#x}", self.0),
+        }
+    }
+}
 impl From<u32> for RelativeAxis {
     fn from(value: u32) -> Self {
         Self(value)
@@ -1886,14 +6807,22 @@ impl RelativeAxis {
     pub const WHEEL_HI_RES: RelativeAxis = RelativeAxis(11u32);
     #[doc(alias = "REL_HWHEEL_HI_RES")]
     pub const HWHEEL_HI_RES: RelativeAxis = RelativeAxis(12u32);
+}
+impl RelativeAxis {
+    #[doc = r" The largest value a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is a bound rather than a code, and may have the same value as a code."]
     #[doc(alias = "REL_MAX")]
-    pub const MAX: RelativeAxis = RelativeAxis(15u32);
+    pub const MAX: Self = Self(15u32);
+    #[doc = r" The number of values a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is the size of the bitmaps the kernel uses to describe the supported codes."]
     #[doc(alias = "REL_CNT")]
-    pub const CNT: RelativeAxis = RelativeAxis(16u32);
+    pub const COUNT: usize = 16usize;
 }
 #[doc = "Autorepeat values."]
 #[repr(transparent)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct AutoRepeat(u32);
 impl AutoRepeat {
     pub const fn new(value: u32) -> Self {
@@ -1903,6 +6832,95 @@ impl AutoRepeat {
         self.0
     }
 }
+impl AutoRepeat {
+    #[doc = r" Returns the name of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" If the code has multiple names, the canonical name is returned. The other names of the code are"]
+    #[doc = r" returned by [`aliases`](Self::aliases)."]
+    #[doc = r""]
+    #[doc = r" Returns [`None`] if the value is not a known code."]
+    pub const fn name(self) -> Option<&'static str> {
+        let index = self.0.wrapping_sub(0u32) as usize;
+        if index < Self::NAMES_BY_VALUE.len() {
+            Self::NAMES_BY_VALUE[index]
+        } else {
+            None
+        }
+    }
+}
+impl AutoRepeat {
+    #[doc = r" Returns the other names of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" The canonical name returned by [`name`](Self::name) is not included. Returns an empty slice if the"]
+    #[doc = r" code has no other names or is not a known code."]
+    pub const fn aliases(self) -> &'static [&'static str] {
+        &[]
+    }
+}
+impl AutoRepeat {
+    const NAMES_BY_VALUE: &'static [Option<&'static str>] =
+        &[Some("REP_DELAY"), Some("REP_PERIOD")];
+    const NAME_MAP: crate::phf::NameMap<Self> = crate::phf::NameMap {
+        seeds: &[23u32],
+        entries: &[
+            ("REP_DELAY", Self(0u32)),
+            ("REP_PERIOD", Self(1u32)),
+            ("DELAY", Self(0u32)),
+            ("PERIOD", Self(1u32)),
+        ],
+    };
+    #[doc = r" Looks up a code by name."]
+    #[doc = r""]
+    #[doc = r" Both the name defined in the kernel headers (`KEY_LEFTMETA`) and the name of the associated"]
+    #[doc = r" constant (`LEFTMETA`) are accepted. The name of a constant which starts with a digit may be given"]
+    #[doc = r" with or without its leading underscore (`_0` or `0`). The name is case sensitive, see"]
+    #[doc = r" [`from_name_ignore_case`](Self::from_name_ignore_case) for a case insensitive lookup."]
+    pub fn from_name(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, false)
+            .ok_or(crate::ParseCodeError::new("AutoRepeat"))
+    }
+    #[doc = r" Looks up a code by name, ignoring ASCII case."]
+    #[doc = r""]
+    #[doc = r" This accepts the same names as [`from_name`](Self::from_name)."]
+    pub fn from_name_ignore_case(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, true)
+            .ok_or(crate::ParseCodeError::new("AutoRepeat"))
+    }
+}
+impl AutoRepeat {
+    #[doc = r" Every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" Codes with multiple names are only listed once, and values which only describe the range of codes"]
+    #[doc = r" (such as `MAX`) are not included."]
+    pub const ALL: &'static [Self] = &[Self(0u32), Self(1u32)];
+    #[doc = r" Returns an iterator over every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" This iterates over [`ALL`](Self::ALL)."]
+    pub fn iter() -> ::core::iter::Copied<::core::slice::Iter<'static, Self>> {
+        Self::ALL.iter().copied()
+    }
+}
+impl ::core::str::FromStr for AutoRepeat {
+    type Err = crate::ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s)
+    }
+}
+impl ::core::fmt::Debug for AutoRepeat {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(self, f)
+    }
+}
+impl ::core::fmt::Display for AutoRepeat {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.name() {
+            Some(name) => f.pad(name),
+            None => write!(f, "REP_{:#x}", self.0),
+        }
+    }
+}
 impl From<u32> for AutoRepeat {
     fn from(value: u32) -> Self {
         Self(value)
@@ -1933,13 +6951,21 @@ impl AutoRepeat {
     pub const DELAY: AutoRepeat = AutoRepeat(0u32);
     #[doc(alias = "REP_PERIOD")]
     pub const PERIOD: AutoRepeat = AutoRepeat(1u32);
+}
+impl AutoRepeat {
+    #[doc = r" The largest value a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is a bound rather than a code, and may have the same value as a code."]
     #[doc(alias = "REP_MAX")]
-    pub const MAX: AutoRepeat = AutoRepeat(1u32);
+    pub const MAX: Self = Self(1u32);
+    #[doc = r" The number of values a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is the size of the bitmaps the kernel uses to describe the supported codes."]
     #[doc(alias = "REP_CNT")]
-    pub const CNT: AutoRepeat = AutoRepeat(2u32);
+    pub const COUNT: usize = 2usize;
 }
 #[repr(transparent)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Sound(u32);
 impl Sound {
     pub const fn new(value: u32) -> Self {
@@ -1949,6 +6975,97 @@ impl Sound {
         self.0
     }
 }
+impl Sound {
+    #[doc = r" Returns the name of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" If the code has multiple names, the canonical name is returned. The other names of the code are"]
+    #[doc = r" returned by [`aliases`](Self::aliases)."]
+    #[doc = r""]
+    #[doc = r" Returns [`None`] if the value is not a known code."]
+    pub const fn name(self) -> Option<&'static str> {
+        let index = self.0.wrapping_sub(0u32) as usize;
+        if index < Self::NAMES_BY_VALUE.len() {
+            Self::NAMES_BY_VALUE[index]
+        } else {
+            None
+        }
+    }
+}
+impl Sound {
+    #[doc = r" Returns the other names of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" The canonical name returned by [`name`](Self::name) is not included. Returns an empty slice if the"]
+    #[doc = r" code has no other names or is not a known code."]
+    pub const fn aliases(self) -> &'static [&'static str] {
+        &[]
+    }
+}
+impl Sound {
+    const NAMES_BY_VALUE: &'static [Option<&'static str>] =
+        &[Some("SND_CLICK"), Some("SND_BELL"), Some("SND_TONE")];
+    const NAME_MAP: crate::phf::NameMap<Self> = crate::phf::NameMap {
+        seeds: &[1u32, 3u32],
+        entries: &[
+            ("CLICK", Self(0u32)),
+            ("BELL", Self(1u32)),
+            ("TONE", Self(2u32)),
+            ("SND_BELL", Self(1u32)),
+            ("SND_TONE", Self(2u32)),
+            ("SND_CLICK", Self(0u32)),
+        ],
+    };
+    #[doc = r" Looks up a code by name."]
+    #[doc = r""]
+    #[doc = r" Both the name defined in the kernel headers (`KEY_LEFTMETA`) and the name of the associated"]
+    #[doc = r" constant (`LEFTMETA`) are accepted. The name of a constant which starts with a digit may be given"]
+    #[doc = r" with or without its leading underscore (`_0` or `0`). The name is case sensitive, see"]
+    #[doc = r" [`from_name_ignore_case`](Self::from_name_ignore_case) for a case insensitive lookup."]
+    pub fn from_name(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, false)
+            .ok_or(crate::ParseCodeError::new("Sound"))
+    }
+    #[doc = r" Looks up a code by name, ignoring ASCII case."]
+    #[doc = r""]
+    #[doc = r" This accepts the same names as [`from_name`](Self::from_name)."]
+    pub fn from_name_ignore_case(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, true)
+            .ok_or(crate::ParseCodeError::new("Sound"))
+    }
+}
+impl Sound {
+    #[doc = r" Every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" Codes with multiple names are only listed once, and values which only describe the range of codes"]
+    #[doc = r" (such as `MAX`) are not included."]
+    pub const ALL: &'static [Self] = &[Self(0u32), Self(1u32), Self(2u32)];
+    #[doc = r" Returns an iterator over every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" This iterates over [`ALL`](Self::ALL)."]
+    pub fn iter() -> ::core::iter::Copied<::core::slice::Iter<'static, Self>> {
+        Self::ALL.iter().copied()
+    }
+}
+impl ::core::str::FromStr for Sound {
+    type Err = crate::ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s)
+    }
+}
+impl ::core::fmt::Debug for Sound {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(self, f)
+    }
+}
+impl ::core::fmt::Display for Sound {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.name() {
+            Some(name) => f.pad(name),
+            None => write!(f, "SND_{:#x}", self.0),
+        }
+    }
+}
 impl From<u32> for Sound {
     fn from(value: u32) -> Self {
         Self(value)
@@ -1981,14 +7098,22 @@ impl Sound {
     pub const BELL: Sound = Sound(1u32);
     #[doc(alias = "SND_TONE")]
     pub const TONE: Sound = Sound(2u32);
+}
+impl Sound {
+    #[doc = r" The largest value a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is a bound rather than a code, and may have the same value as a code."]
     #[doc(alias = "SND_MAX")]
-    pub const MAX: Sound = Sound(7u32);
+    pub const MAX: Self = Self(7u32);
+    #[doc = r" The number of values a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is the size of the bitmaps the kernel uses to describe the supported codes."]
     #[doc(alias = "SND_CNT")]
-    pub const CNT: Sound = Sound(8u32);
+    pub const COUNT: usize = 8usize;
 }
 #[doc = "Switch events."]
 #[repr(transparent)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SwitchEvent(u32);
 impl SwitchEvent {
     pub const fn new(value: u32) -> Self {
@@ -1998,6 +7123,165 @@ impl SwitchEvent {
         self.0
     }
 }
+impl SwitchEvent {
+    #[doc = r" Returns the name of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" If the code has multiple names, the canonical name is returned. The other names of the code are"]
+    #[doc = r" returned by [`aliases`](Self::aliases)."]
+    #[doc = r""]
+    #[doc = r" Returns [`None`] if the value is not a known code."]
+    pub const fn name(self) -> Option<&'static str> {
+        let index = self.0.wrapping_sub(0u32) as usize;
+        if index < Self::NAMES_BY_VALUE.len() {
+            Self::NAMES_BY_VALUE[index]
+        } else {
+            None
+        }
+    }
+}
+impl SwitchEvent {
+    #[doc = r" Returns the other names of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" The canonical name returned by [`name`](Self::name) is not included. Returns an empty slice if the"]
+    #[doc = r" code has no other names or is not a known code."]
+    pub const fn aliases(self) -> &'static [&'static str] {
+        match self.0 {
+            3u32 => &["SW_RADIO"],
+            _ => &[],
+        }
+    }
+}
+impl SwitchEvent {
+    const NAMES_BY_VALUE: &'static [Option<&'static str>] = &[
+        Some("SW_LID"),
+        Some("SW_TABLET_MODE"),
+        Some("SW_HEADPHONE_INSERT"),
+        Some("SW_RFKILL_ALL"),
+        Some("SW_MICROPHONE_INSERT"),
+        Some("SW_DOCK"),
+        Some("SW_LINEOUT_INSERT"),
+        Some("SW_JACK_PHYSICAL_INSERT"),
+        Some("SW_VIDEOOUT_INSERT"),
+        Some("SW_CAMERA_LENS_COVER"),
+        Some("SW_KEYPAD_SLIDE"),
+        Some("SW_FRONT_PROXIMITY"),
+        Some("SW_ROTATE_LOCK"),
+        Some("SW_LINEIN_INSERT"),
+        Some("SW_MUTE_DEVICE"),
+        Some("SW_PEN_INSERTED"),
+        Some("SW_MACHINE_COVER"),
+    ];
+    const NAME_MAP: crate::phf::NameMap<Self> = crate::phf::NameMap {
+        seeds: &[82u32, 770u32, 4u32, 1u32, 14u32, 8u32, 51u32, 3006u32, 1u32],
+        entries: &[
+            ("SW_KEYPAD_SLIDE", Self(10u32)),
+            ("SW_CAMERA_LENS_COVER", Self(9u32)),
+            ("SW_RADIO", Self(3u32)),
+            ("SW_ROTATE_LOCK", Self(12u32)),
+            ("SW_LID", Self(0u32)),
+            ("SW_DOCK", Self(5u32)),
+            ("SW_LINEIN_INSERT", Self(13u32)),
+            ("VIDEOOUT_INSERT", Self(8u32)),
+            ("MACHINE_COVER", Self(16u32)),
+            ("SW_JACK_PHYSICAL_INSERT", Self(7u32)),
+            ("SW_RFKILL_ALL", Self(3u32)),
+            ("MICROPHONE_INSERT", Self(4u32)),
+            ("FRONT_PROXIMITY", Self(11u32)),
+            ("HEADPHONE_INSERT", Self(2u32)),
+            ("DOCK", Self(5u32)),
+            ("TABLET_MODE", Self(1u32)),
+            ("MUTE_DEVICE", Self(14u32)),
+            ("SW_MUTE_DEVICE", Self(14u32)),
+            ("LID", Self(0u32)),
+            ("PEN_INSERTED", Self(15u32)),
+            ("RADIO", Self(3u32)),
+            ("SW_TABLET_MODE", Self(1u32)),
+            ("SW_LINEOUT_INSERT", Self(6u32)),
+            ("JACK_PHYSICAL_INSERT", Self(7u32)),
+            ("LINEIN_INSERT", Self(13u32)),
+            ("SW_PEN_INSERTED", Self(15u32)),
+            ("ROTATE_LOCK", Self(12u32)),
+            ("SW_HEADPHONE_INSERT", Self(2u32)),
+            ("SW_FRONT_PROXIMITY", Self(11u32)),
+            ("CAMERA_LENS_COVER", Self(9u32)),
+            ("KEYPAD_SLIDE", Self(10u32)),
+            ("SW_MICROPHONE_INSERT", Self(4u32)),
+            ("RFKILL_ALL", Self(3u32)),
+            ("SW_VIDEOOUT_INSERT", Self(8u32)),
+            ("LINEOUT_INSERT", Self(6u32)),
+            ("SW_MACHINE_COVER", Self(16u32)),
+        ],
+    };
+    #[doc = r" Looks up a code by name."]
+    #[doc = r""]
+    #[doc = r" Both the name defined in the kernel headers (`KEY_LEFTMETA`) and the name of the associated"]
+    #[doc = r" constant (`LEFTMETA`) are accepted. The name of a constant which starts with a digit may be given"]
+    #[doc = r" with or without its leading underscore (`_0` or `0`). The name is case sensitive, see"]
+    #[doc = r" [`from_name_ignore_case`](Self::from_name_ignore_case) for a case insensitive lookup."]
+    pub fn from_name(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, false)
+            .ok_or(crate::ParseCodeError::new("SwitchEvent"))
+    }
+    #[doc = r" Looks up a code by name, ignoring ASCII case."]
+    #[doc = r""]
+    #[doc = r" This accepts the same names as [`from_name`](Self::from_name)."]
+    pub fn from_name_ignore_case(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, true)
+            .ok_or(crate::ParseCodeError::new("SwitchEvent"))
+    }
+}
+impl SwitchEvent {
+    #[doc = r" Every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" Codes with multiple names are only listed once, and values which only describe the range of codes"]
+    #[doc = r" (such as `MAX`) are not included."]
+    pub const ALL: &'static [Self] = &[
+        Self(0u32),
+        Self(1u32),
+        Self(2u32),
+        Self(3u32),
+        Self(4u32),
+        Self(5u32),
+        Self(6u32),
+        Self(7u32),
+        Self(8u32),
+        Self(9u32),
+        Self(10u32),
+        Self(11u32),
+        Self(12u32),
+        Self(13u32),
+        Self(14u32),
+        Self(15u32),
+        Self(16u32),
+    ];
+    #[doc = r" Returns an iterator over every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" This iterates over [`ALL`](Self::ALL)."]
+    pub fn iter() -> ::core::iter::Copied<::core::slice::Iter<'static, Self>> {
+        Self::ALL.iter().copied()
+    }
+}
+impl ::core::str::FromStr for SwitchEvent {
+    type Err = crate::ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s)
+    }
+}
+impl ::core::fmt::Debug for SwitchEvent {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(self, f)
+    }
+}
+impl ::core::fmt::Display for SwitchEvent {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.name() {
+            Some(name) => f.pad(name),
+            None => write!(f, "SW_{:#x}", self.0),
+        }
+    }
+}
 impl From<u32> for SwitchEvent {
     fn from(value: u32) -> Self {
         Self(value)
@@ -2036,8 +7320,9 @@ impl SwitchEvent {
     #[doc = " rfkill master switch, type \"any\"\n\t\t\t\t\t set = radio enabled "]
     #[doc(alias = "SW_RFKILL_ALL")]
     pub const RFKILL_ALL: SwitchEvent = SwitchEvent(3u32);
-    #[doc = " deprecated "]
+    #[doc = "Alias of [`RFKILL_ALL`](Self::RFKILL_ALL)."]
     #[doc(alias = "SW_RADIO")]
+    #[deprecated(note = "use `SwitchEvent::RFKILL_ALL` instead")]
     pub const RADIO: SwitchEvent = SwitchEvent(3u32);
     #[doc = " set = inserted "]
     #[doc(alias = "SW_MICROPHONE_INSERT")]
@@ -2078,14 +7363,22 @@ impl SwitchEvent {
     #[doc = " set = cover closed "]
     #[doc(alias = "SW_MACHINE_COVER")]
     pub const MACHINE_COVER: SwitchEvent = SwitchEvent(16u32);
+}
+impl SwitchEvent {
+    #[doc = r" The largest value a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is a bound rather than a code, and may have the same value as a code."]
     #[doc(alias = "SW_MAX")]
-    pub const MAX: SwitchEvent = SwitchEvent(16u32);
+    pub const MAX: Self = Self(16u32);
+    #[doc = r" The number of values a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is the size of the bitmaps the kernel uses to describe the supported codes."]
     #[doc(alias = "SW_CNT")]
-    pub const CNT: SwitchEvent = SwitchEvent(17u32);
+    pub const COUNT: usize = 17usize;
 }
 #[doc = "Synchronization events."]
 #[repr(transparent)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SynchronizationEvent(u32);
 impl SynchronizationEvent {
     pub const fn new(value: u32) -> Self {
@@ -2095,6 +7388,103 @@ impl SynchronizationEvent {
         self.0
     }
 }
+impl SynchronizationEvent {
+    #[doc = r" Returns the name of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" If the code has multiple names, the canonical name is returned. The other names of the code are"]
+    #[doc = r" returned by [`aliases`](Self::aliases)."]
+    #[doc = r""]
+    #[doc = r" Returns [`None`] if the value is not a known code."]
+    pub const fn name(self) -> Option<&'static str> {
+        let index = self.0.wrapping_sub(0u32) as usize;
+        if index < Self::NAMES_BY_VALUE.len() {
+            Self::NAMES_BY_VALUE[index]
+        } else {
+            None
+        }
+    }
+}
+impl SynchronizationEvent {
+    #[doc = r" Returns the other names of the code as defined in the kernel headers."]
+    #[doc = r""]
+    #[doc = r" The canonical name returned by [`name`](Self::name) is not included. Returns an empty slice if the"]
+    #[doc = r" code has no other names or is not a known code."]
+    pub const fn aliases(self) -> &'static [&'static str] {
+        &[]
+    }
+}
+impl SynchronizationEvent {
+    const NAMES_BY_VALUE: &'static [Option<&'static str>] = &[
+        Some("SYN_REPORT"),
+        Some("SYN_CONFIG"),
+        Some("SYN_MT_REPORT"),
+        Some("SYN_DROPPED"),
+    ];
+    const NAME_MAP: crate::phf::NameMap<Self> = crate::phf::NameMap {
+        seeds: &[66u32, 1u32],
+        entries: &[
+            ("REPORT", Self(0u32)),
+            ("SYN_REPORT", Self(0u32)),
+            ("MT_REPORT", Self(2u32)),
+            ("SYN_DROPPED", Self(3u32)),
+            ("SYN_CONFIG", Self(1u32)),
+            ("DROPPED", Self(3u32)),
+            ("SYN_MT_REPORT", Self(2u32)),
+            ("CONFIG", Self(1u32)),
+        ],
+    };
+    #[doc = r" Looks up a code by name."]
+    #[doc = r""]
+    #[doc = r" Both the name defined in the kernel headers (`KEY_LEFTMETA`) and the name of the associated"]
+    #[doc = r" constant (`LEFTMETA`) are accepted. The name of a constant which starts with a digit may be given"]
+    #[doc = r" with or without its leading underscore (`_0` or `0`). The name is case sensitive, see"]
+    #[doc = r" [`from_name_ignore_case`](Self::from_name_ignore_case) for a case insensitive lookup."]
+    pub fn from_name(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, false)
+            .ok_or(crate::ParseCodeError::new("SynchronizationEvent"))
+    }
+    #[doc = r" Looks up a code by name, ignoring ASCII case."]
+    #[doc = r""]
+    #[doc = r" This accepts the same names as [`from_name`](Self::from_name)."]
+    pub fn from_name_ignore_case(name: &str) -> Result<Self, crate::ParseCodeError> {
+        Self::NAME_MAP
+            .get(name, true)
+            .ok_or(crate::ParseCodeError::new("SynchronizationEvent"))
+    }
+}
+impl SynchronizationEvent {
+    #[doc = r" Every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" Codes with multiple names are only listed once, and values which only describe the range of codes"]
+    #[doc = r" (such as `MAX`) are not included."]
+    pub const ALL: &'static [Self] = &[Self(0u32), Self(1u32), Self(2u32), Self(3u32)];
+    #[doc = r" Returns an iterator over every known code, ordered by value."]
+    #[doc = r""]
+    #[doc = r" This iterates over [`ALL`](Self::ALL)."]
+    pub fn iter() -> ::core::iter::Copied<::core::slice::Iter<'static, Self>> {
+        Self::ALL.iter().copied()
+    }
+}
+impl ::core::str::FromStr for SynchronizationEvent {
+    type Err = crate::ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s)
+    }
+}
+impl ::core::fmt::Debug for SynchronizationEvent {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(self, f)
+    }
+}
+impl ::core::fmt::Display for SynchronizationEvent {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.name() {
+            Some(name) => f.pad(name),
+            None => write!(f, "SYN_{:#x}", self.0),
+        }
+    }
+}
 impl From<u32> for SynchronizationEvent {
     fn from(value: u32) -> Self {
         Self(value)
@@ -2129,8 +7519,16 @@ impl SynchronizationEvent {
     pub const MT_REPORT: SynchronizationEvent = SynchronizationEvent(2u32);
     #[doc(alias = "SYN_DROPPED")]
     pub const DROPPED: SynchronizationEvent = SynchronizationEvent(3u32);
+}
+impl SynchronizationEvent {
+    #[doc = r" The largest value a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is a bound rather than a code, and may have the same value as a code."]
     #[doc(alias = "SYN_MAX")]
-    pub const MAX: SynchronizationEvent = SynchronizationEvent(15u32);
+    pub const MAX: Self = Self(15u32);
+    #[doc = r" The number of values a code of this type may have."]
+    #[doc = r""]
+    #[doc = r" This is the size of the bitmaps the kernel uses to describe the supported codes."]
     #[doc(alias = "SYN_CNT")]
-    pub const CNT: SynchronizationEvent = SynchronizationEvent(16u32);
+    pub const COUNT: usize = 16usize;
 }
@@ -0,0 +1,160 @@
+//! Tests of the code types generated from the kernel headers.
+
+use std::{format, string::ToString};
+
+use crate::{
+    AbsoluteAxis, AutoRepeat, BusType, Button, EventType, ForceFeedback, InputIdField, InputQuirk,
+    Key, Led, MiscEvent, ParseCodeError, RelativeAxis, Sound, SwitchEvent, SynchronizationEvent,
+};
+
+#[test]
+fn display() {
+    assert_eq!(Key::A.to_string(), "KEY_A");
+    assert_eq!(
+        format!("{:?}", AbsoluteAxis::MT_POSITION_X),
+        "ABS_MT_POSITION_X"
+    );
+    assert_eq!(InputQuirk::PROP_POINTER.to_string(), "INPUT_PROP_POINTER");
+
+    // Codes with multiple names are displayed with the canonical name.
+    assert_eq!(Key::SCREENLOCK.to_string(), "KEY_COFFEE");
+    assert_eq!(format!("{:?}", Button::MISC), "BTN_0");
+
+    // Unknown values are displayed in hexadecimal after the prefix.
+    assert_eq!(Key::new(0x1000).to_string(), "KEY_0x1000");
+    assert_eq!(format!("{:?}", SwitchEvent::new(0x20)), "SW_0x20");
+    assert_eq!(InputQuirk::new(0x1f).to_string(), "INPUT_PROP_0x1f");
+
+    // Known names are padded like strings.
+    assert_eq!(format!("{:>8}", Key::A), "   KEY_A");
+}
+
+#[test]
+fn from_name() {
+    // Both the kernel name and the name of the constant are accepted.
+    assert_eq!(Key::from_name("KEY_LEFTMETA"), Ok(Key::LEFTMETA));
+    assert_eq!(Key::from_name("LEFTMETA"), Ok(Key::LEFTMETA));
+    assert_eq!("BTN_LEFT".parse(), Ok(Button::LEFT));
+    assert_eq!(Key::from_name("KEY_SCREENLOCK"), Ok(Key::COFFEE));
+
+    // Names starting with a digit may be given with or without the underscore of the constant.
+    assert_eq!(Key::from_name("KEY_0"), Ok(Key::_0));
+    assert_eq!(Key::from_name("_0"), Ok(Key::_0));
+    assert_eq!(Key::from_name("0"), Ok(Key::_0));
+    assert_eq!(Button::from_name("0"), Ok(Button::_0));
+
+    assert_eq!(
+        Key::from_name_ignore_case("key_leftmeta"),
+        Ok(Key::LEFTMETA)
+    );
+    assert_eq!(Key::from_name_ignore_case("LeftMeta"), Ok(Key::LEFTMETA));
+    assert!(Key::from_name("key_leftmeta").is_err());
+
+    let error = Key::from_name("KEY_NOT_A_KEY").unwrap_err();
+    assert_eq!(error, ParseCodeError::new("Key"));
+    assert_eq!(error.category(), "Key");
+    assert_eq!(error.to_string(), "unknown Key name");
+    assert!("REL_X".parse::<Key>().is_err());
+    assert!(Key::from_name("").is_err());
+}
+
+#[test]
+fn all() {
+    macro_rules! check {
+        ($($code:ty),*) => {$(
+            // Sorted without duplicates, so codes with multiple names are listed once.
+            assert!(
+                <$code>::ALL.windows(2).all(|pair| pair[0] < pair[1]),
+                "{} is not sorted",
+                stringify!($code)
+            );
+            assert!(<$code>::ALL.iter().all(|code| code.name().is_some()));
+            assert!(<$code>::iter().eq(<$code>::ALL.iter().copied()));
+        )*};
+    }
+
+    check!(
+        AbsoluteAxis,
+        AutoRepeat,
+        Button,
+        BusType,
+        EventType,
+        ForceFeedback,
+        InputIdField,
+        InputQuirk,
+        Key,
+        Led,
+        MiscEvent,
+        RelativeAxis,
+        Sound,
+        SwitchEvent,
+        SynchronizationEvent
+    );
+
+    // Aliases are not listed separately.
+    assert_eq!(Key::iter().filter(|&key| key == Key::SCREENLOCK).count(), 1);
+    assert_eq!(
+        Button::iter()
+            .filter(|&button| button == Button::MISC)
+            .count(),
+        1
+    );
+    assert_eq!(Key::ALL.first(), Some(&Key::RESERVED));
+}
+
+#[test]
+fn range_markers() {
+    // Range markers are not codes, so they are not listed or looked up by name.
+    assert!(!Key::ALL.contains(&Key::MAX));
+    assert!(!EventType::ALL.contains(&EventType::MAX));
+    assert!(!InputQuirk::ALL.contains(&InputQuirk::MAX));
+
+    for name in [
+        "KEY_MAX",
+        "MAX",
+        "KEY_CNT",
+        "CNT",
+        "KEY_MIN_INTERESTING",
+        "MIN_INTERESTING",
+    ] {
+        assert!(Key::from_name(name).is_err(), "{}", name);
+    }
+
+    assert!(InputQuirk::from_name("INPUT_PROP_MAX").is_err());
+    assert!(InputQuirk::from_name("PROP_CNT").is_err());
+    assert!(ForceFeedback::from_name("FF_EFFECT_MIN").is_err());
+    assert!(ForceFeedback::from_name("FF_MAX_EFFECTS").is_err());
+
+    // A range marker is displayed as the code with the same value, if there is one.
+    assert_eq!(Key::MAX.to_string(), "KEY_0x2ff");
+    assert_eq!(EventType::MAX.to_string(), "EV_0x1f");
+    assert_eq!(InputQuirk::MAX.to_string(), "INPUT_PROP_0x1f");
+    assert_eq!(Key::MIN_INTERESTING.to_string(), "KEY_MUTE");
+    assert_eq!(SwitchEvent::MAX.to_string(), "SW_MACHINE_COVER");
+    assert_eq!(Key::COUNT, Key::MAX.into_inner() as usize + 1);
+}
+
+#[test]
+#[allow(deprecated)]
+fn aliases() {
+    // A constant defined in terms of another constant is never the canonical name.
+    assert_eq!(Key::SCREENLOCK, Key::COFFEE);
+    assert_eq!(Key::COFFEE.name(), Some("KEY_COFFEE"));
+    assert_eq!(Key::COFFEE.aliases(), ["KEY_SCREENLOCK"]);
+
+    // Otherwise the last constant defined with the value is canonical.
+    assert_eq!(Button::MISC, Button::_0);
+    assert_eq!(Button::MISC.name(), Some("BTN_0"));
+    assert_eq!(Button::_0.aliases(), ["BTN_MISC"]);
+
+    // Deprecated constants are still aliases of the code.
+    assert_eq!(SwitchEvent::RADIO, SwitchEvent::RFKILL_ALL);
+    assert_eq!(SwitchEvent::RFKILL_ALL.aliases(), ["SW_RADIO"]);
+    assert_eq!(
+        SwitchEvent::from_name("SW_RADIO"),
+        Ok(SwitchEvent::RFKILL_ALL)
+    );
+
+    assert!(Key::A.aliases().is_empty());
+    assert!(Key::new(0x1000).aliases().is_empty());
+}
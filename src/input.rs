@@ -0,0 +1,363 @@
+//! Structures defined in `linux/input.h` which are used by the evdev ioctls.
+//!
+//! These have the same layout as the raw bindings in [`sys`](crate::sys), but use the types of this crate where
+//! the kernel uses a code or event type.
+
+use core::fmt;
+
+use crate::{sys, BusType, EventType, InputIdField, KeyCode};
+
+/// The identity of an input device, matching `struct input_id` of the kernel.
+#[doc(alias = "input_id")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InputId {
+    /// The bus the device is connected through, such as [`BusType::USB`].
+    pub bustype: BusType,
+
+    /// The vendor of the device.
+    pub vendor: u16,
+
+    /// The product of the device.
+    pub product: u16,
+
+    /// The version of the device.
+    pub version: u16,
+}
+
+impl InputId {
+    /// Returns a field of the id by index, as used by the kernel when reading the id as an array of `u16`.
+    ///
+    /// Returns [`None`] if the index is not a field of the id.
+    pub const fn field(&self, field: InputIdField) -> Option<u16> {
+        match field {
+            InputIdField::BUS => Some(self.bustype.into_inner()),
+            InputIdField::VENDOR => Some(self.vendor),
+            InputIdField::PRODUCT => Some(self.product),
+            InputIdField::VERSION => Some(self.version),
+            _ => None,
+        }
+    }
+}
+
+impl Default for InputId {
+    fn default() -> Self {
+        Self {
+            bustype: BusType::new(0),
+            vendor: 0,
+            product: 0,
+            version: 0,
+        }
+    }
+}
+
+impl From<sys::input_id> for InputId {
+    fn from(id: sys::input_id) -> Self {
+        Self {
+            bustype: BusType::new(id.bustype),
+            vendor: id.vendor,
+            product: id.product,
+            version: id.version,
+        }
+    }
+}
+
+impl From<InputId> for sys::input_id {
+    fn from(id: InputId) -> Self {
+        Self {
+            bustype: id.bustype.into_inner(),
+            vendor: id.vendor,
+            product: id.product,
+            version: id.version,
+        }
+    }
+}
+
+/// The state and limits of an absolute axis, matching `struct input_absinfo` of the kernel.
+#[doc(alias = "input_absinfo")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct InputAbsInfo {
+    /// The latest value reported by the axis.
+    pub value: i32,
+
+    /// The smallest value the axis may report.
+    pub minimum: i32,
+
+    /// The largest value the axis may report.
+    pub maximum: i32,
+
+    /// The amount of noise the kernel filters out of the reported values.
+    pub fuzz: i32,
+
+    /// The range around the center of the axis which is treated as the center.
+    pub flat: i32,
+
+    /// The resolution of the axis, in units per millimeter or units per radian.
+    pub resolution: i32,
+}
+
+impl From<sys::input_absinfo> for InputAbsInfo {
+    fn from(info: sys::input_absinfo) -> Self {
+        Self {
+            value: info.value,
+            minimum: info.minimum,
+            maximum: info.maximum,
+            fuzz: info.fuzz,
+            flat: info.flat,
+            resolution: info.resolution,
+        }
+    }
+}
+
+impl From<InputAbsInfo> for sys::input_absinfo {
+    fn from(info: InputAbsInfo) -> Self {
+        Self {
+            value: info.value,
+            minimum: info.minimum,
+            maximum: info.maximum,
+            fuzz: info.fuzz,
+            flat: info.flat,
+            resolution: info.resolution,
+        }
+    }
+}
+
+/// An entry of the keymap of a device, matching `struct input_keymap_entry` of the kernel.
+///
+/// The entry is looked up either by scancode or, if [`BY_INDEX`](Self::BY_INDEX) is set in the flags, by its
+/// index in the keymap.
+#[doc(alias = "input_keymap_entry")]
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct InputKeymapEntry {
+    /// Flags describing how the entry is looked up.
+    pub flags: u8,
+    len: u8,
+
+    /// The index of the entry in the keymap.
+    pub index: u16,
+    keycode: u32,
+    scancode: [u8; 32],
+}
+
+impl InputKeymapEntry {
+    /// Look up the entry by index instead of scancode.
+    #[doc(alias = "INPUT_KEYMAP_BY_INDEX")]
+    pub const BY_INDEX: u8 = 1 << 0;
+
+    /// The largest scancode an entry may contain, in bytes.
+    pub const MAX_SCANCODE_LEN: usize = 32;
+
+    /// Creates an entry which is looked up by scancode.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scancode is longer than [`MAX_SCANCODE_LEN`](Self::MAX_SCANCODE_LEN).
+    pub fn by_scancode(scancode: &[u8], keycode: KeyCode) -> Self {
+        assert!(
+            scancode.len() <= Self::MAX_SCANCODE_LEN,
+            "scancode is longer than {} bytes",
+            Self::MAX_SCANCODE_LEN
+        );
+
+        let mut entry = Self {
+            len: scancode.len() as u8,
+            keycode: keycode.into_inner(),
+            ..Self::default()
+        };
+        entry.scancode[..scancode.len()].copy_from_slice(scancode);
+        entry
+    }
+
+    /// Creates an entry which is looked up by its index in the keymap.
+    pub const fn by_index(index: u16) -> Self {
+        Self {
+            flags: Self::BY_INDEX,
+            len: 0,
+            index,
+            keycode: 0,
+            scancode: [0; 32],
+        }
+    }
+
+    /// Returns the key or button the scancode is mapped to.
+    pub const fn keycode(&self) -> KeyCode {
+        KeyCode::new(self.keycode)
+    }
+
+    /// Sets the key or button the scancode is mapped to.
+    pub fn set_keycode(&mut self, keycode: KeyCode) {
+        self.keycode = keycode.into_inner();
+    }
+
+    /// Returns the scancode of the entry.
+    pub fn scancode(&self) -> &[u8] {
+        let len = usize::from(self.len).min(Self::MAX_SCANCODE_LEN);
+        &self.scancode[..len]
+    }
+}
+
+impl fmt::Debug for InputKeymapEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InputKeymapEntry")
+            .field("flags", &self.flags)
+            .field("index", &self.index)
+            .field("keycode", &self.keycode())
+            .field("scancode", &self.scancode())
+            .finish()
+    }
+}
+
+impl From<sys::input_keymap_entry> for InputKeymapEntry {
+    fn from(entry: sys::input_keymap_entry) -> Self {
+        Self {
+            flags: entry.flags,
+            len: entry.len,
+            index: entry.index,
+            keycode: entry.keycode,
+            scancode: entry.scancode,
+        }
+    }
+}
+
+impl From<InputKeymapEntry> for sys::input_keymap_entry {
+    fn from(entry: InputKeymapEntry) -> Self {
+        Self {
+            flags: entry.flags,
+            len: entry.len,
+            index: entry.index,
+            keycode: entry.keycode,
+            scancode: entry.scancode,
+        }
+    }
+}
+
+/// The event mask of an event type, matching `struct input_mask` of the kernel.
+///
+/// The mask is a bitmap of the codes of the event type, stored in a buffer outside of the structure.
+#[doc(alias = "input_mask")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InputMask {
+    /// The event type the mask applies to.
+    pub type_: EventType,
+
+    /// The size of the buffer, in bytes.
+    pub codes_size: u32,
+
+    /// The address of the buffer.
+    pub codes_ptr: u64,
+}
+
+impl From<sys::input_mask> for InputMask {
+    fn from(mask: sys::input_mask) -> Self {
+        Self {
+            type_: EventType::new(mask.type_),
+            codes_size: mask.codes_size,
+            codes_ptr: mask.codes_ptr,
+        }
+    }
+}
+
+impl From<InputMask> for sys::input_mask {
+    fn from(mask: InputMask) -> Self {
+        Self {
+            type_: mask.type_.into_inner(),
+            codes_size: mask.codes_size,
+            codes_ptr: mask.codes_ptr,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::mem::{align_of, offset_of, size_of};
+
+    use std::string::ToString;
+
+    use crate::{
+        sys, BusType, EventType, InputAbsInfo, InputId, InputIdField, InputKeymapEntry, InputMask,
+        Key, KeyCode,
+    };
+
+    macro_rules! assert_layout {
+        ($ty:ty, $sys:ty, [$($field:ident),*]) => {
+            assert_eq!(size_of::<$ty>(), size_of::<$sys>());
+            assert_eq!(align_of::<$ty>(), align_of::<$sys>());
+            $(assert_eq!(offset_of!($ty, $field), offset_of!($sys, $field));)*
+        };
+    }
+
+    #[test]
+    fn layout() {
+        assert_layout!(InputId, sys::input_id, [bustype, vendor, product, version]);
+        assert_layout!(
+            InputAbsInfo,
+            sys::input_absinfo,
+            [value, minimum, maximum, fuzz, flat, resolution]
+        );
+        assert_layout!(
+            InputKeymapEntry,
+            sys::input_keymap_entry,
+            [flags, len, index, keycode, scancode]
+        );
+        assert_layout!(InputMask, sys::input_mask, [type_, codes_size, codes_ptr]);
+    }
+
+    #[test]
+    fn id() {
+        let id = InputId {
+            bustype: BusType::USB,
+            vendor: 0x046d,
+            product: 0xc52b,
+            version: 0x0111,
+        };
+
+        assert_eq!(id.field(InputIdField::BUS), Some(3));
+        assert_eq!(id.field(InputIdField::PRODUCT), Some(0xc52b));
+        assert_eq!(id.field(InputIdField::new(4)), None);
+
+        let raw = sys::input_id::from(id);
+        assert_eq!(raw.bustype, 3);
+        assert_eq!(InputId::from(raw), id);
+    }
+
+    #[test]
+    fn bus_type() {
+        assert_eq!(BusType::BLUETOOTH.to_string(), "BUS_BLUETOOTH");
+        assert_eq!(BusType::new(0x42).to_string(), "BUS_0x42");
+        assert_eq!("BUS_USB".parse::<BusType>(), Ok(BusType::USB));
+        assert_eq!(BusType::from_name_ignore_case("i2c"), Ok(BusType::I2C));
+        assert!(BusType::from_name("BUS_FOO").is_err());
+    }
+
+    #[test]
+    fn keymap_entry() {
+        let entry = InputKeymapEntry::by_scancode(&[0x1e, 0x00, 0x07, 0x00], Key::A.into());
+        assert_eq!(entry.flags, 0);
+        assert_eq!(entry.scancode(), &[0x1e, 0x00, 0x07, 0x00]);
+        assert_eq!(entry.keycode(), KeyCode::Key(Key::A));
+
+        let raw = sys::input_keymap_entry::from(entry);
+        assert_eq!((raw.len, raw.keycode), (4, 30));
+        assert_eq!(InputKeymapEntry::from(raw), entry);
+
+        let entry = InputKeymapEntry::by_index(3);
+        assert_eq!(entry.flags, InputKeymapEntry::BY_INDEX);
+        assert_eq!(entry.scancode(), &[]);
+    }
+
+    #[test]
+    fn mask() {
+        let mask = InputMask {
+            type_: EventType::KEY,
+            codes_size: 96,
+            codes_ptr: 0x1000,
+        };
+
+        let raw = sys::input_mask::from(mask);
+        assert_eq!(raw.type_, 1);
+        assert_eq!(InputMask::from(raw), mask);
+    }
+}
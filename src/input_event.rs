@@ -0,0 +1,179 @@
+use core::{ffi::c_long, mem};
+
+use crate::{EventCode, EventType, SynchronizationEvent};
+
+/// The time an input event was generated.
+///
+/// This matches `struct timeval` as used by `struct input_event`. On 32-bit targets using a 64-bit `time_t`, the
+/// kernel replaces the `timeval` with two `__kernel_ulong_t` fields of the same size, so the layout is the same
+/// on every target.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct EventTime {
+    sec: c_long,
+
+    // suseconds_t is an int on sparc64.
+    #[cfg(target_arch = "sparc64")]
+    usec: core::ffi::c_int,
+    #[cfg(target_arch = "sparc64")]
+    _pad: core::ffi::c_int,
+
+    #[cfg(not(target_arch = "sparc64"))]
+    usec: c_long,
+}
+
+// c_long is only an i64 on some targets.
+#[allow(clippy::unnecessary_cast)]
+impl EventTime {
+    /// Creates an event time from seconds and microseconds.
+    ///
+    /// The values are truncated if they do not fit in the fields of the kernel structure.
+    pub const fn new(sec: i64, usec: i64) -> Self {
+        Self {
+            sec: sec as c_long,
+            #[cfg(target_arch = "sparc64")]
+            usec: usec as core::ffi::c_int,
+            #[cfg(target_arch = "sparc64")]
+            _pad: 0,
+            #[cfg(not(target_arch = "sparc64"))]
+            usec: usec as c_long,
+        }
+    }
+
+    /// The seconds part of the time.
+    #[doc(alias = "input_event_sec")]
+    pub const fn sec(&self) -> i64 {
+        self.sec as i64
+    }
+
+    /// The microseconds part of the time.
+    #[doc(alias = "input_event_usec")]
+    pub const fn usec(&self) -> i64 {
+        self.usec as i64
+    }
+}
+
+/// An input event, matching `struct input_event` of the kernel.
+///
+/// This is the record read from and written to evdev and uinput devices.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct InputEvent {
+    /// The time the event was generated.
+    pub time: EventTime,
+
+    /// The type of the event, see [`EventType`].
+    pub type_: u16,
+
+    /// The code of the event, the meaning of which depends on the type of the event.
+    pub code: u16,
+
+    /// The value of the event.
+    pub value: i32,
+}
+
+impl InputEvent {
+    /// The size of an input event on the current target.
+    pub const SIZE: usize = mem::size_of::<Self>();
+
+    /// Creates an input event with the code and value.
+    pub const fn new(time: EventTime, code: EventCode, value: i32) -> Self {
+        let (type_, code) = code.into_raw();
+
+        Self {
+            time,
+            type_,
+            code,
+            value,
+        }
+    }
+
+    /// Returns the type of the event.
+    pub const fn event_type(&self) -> EventType {
+        EventType::new(self.type_ as u32)
+    }
+
+    /// Returns the code of the event, tagged by the type of the event.
+    pub const fn event_code(&self) -> EventCode {
+        EventCode::from_raw(self.type_, self.code)
+    }
+
+    /// Returns whether the event is a `SYN_REPORT`, which ends a frame of events.
+    pub const fn is_report(&self) -> bool {
+        self.type_ == EventType::SYN.into_inner() as u16
+            && self.code == SynchronizationEvent::REPORT.into_inner() as u16
+    }
+
+    /// Creates an input event from the bytes of a `struct input_event`, in the byte order of the target.
+    pub const fn from_bytes(bytes: [u8; Self::SIZE]) -> Self {
+        // SAFETY: Every field is an integer and the structure has no padding, so any bytes are a valid event.
+        unsafe { mem::transmute(bytes) }
+    }
+
+    /// Returns the bytes of the event, as read from or written to a device.
+    pub const fn to_bytes(self) -> [u8; Self::SIZE] {
+        // SAFETY: The structure has no padding, so every byte is initialized.
+        unsafe { mem::transmute(self) }
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+const _: () = assert!(InputEvent::SIZE == 24);
+
+#[cfg(target_pointer_width = "32")]
+const _: () = assert!(InputEvent::SIZE == 16);
+
+#[cfg(test)]
+mod test {
+    use core::mem;
+
+    use crate::{
+        EventCode, EventTime, EventType, InputEvent, Key, RelativeAxis, SynchronizationEvent,
+    };
+
+    #[test]
+    fn layout() {
+        let word = mem::size_of::<core::ffi::c_long>();
+
+        assert_eq!(mem::align_of::<InputEvent>(), word);
+        assert_eq!(mem::size_of::<EventTime>(), 2 * word);
+        assert_eq!(mem::size_of::<InputEvent>(), 2 * word + 8);
+    }
+
+    #[test]
+    fn accessors() {
+        let event = InputEvent::new(
+            EventTime::new(12, 345),
+            EventCode::from(RelativeAxis::WHEEL),
+            -1,
+        );
+
+        assert_eq!(event.event_type(), EventType::REL);
+        assert_eq!(event.event_code(), EventCode::Rel(RelativeAxis::WHEEL));
+        assert_eq!((event.type_, event.code, event.value), (0x02, 0x08, -1));
+        assert_eq!((event.time.sec(), event.time.usec()), (12, 345));
+
+        let event = InputEvent::new(EventTime::default(), Key::A.into(), 1);
+        assert_eq!(event.event_code(), EventCode::from(Key::A));
+    }
+
+    #[test]
+    fn bytes() {
+        let event = InputEvent::new(EventTime::new(1, 2), Key::A.into(), 1);
+        let bytes = event.to_bytes();
+        let word = mem::size_of::<core::ffi::c_long>();
+
+        #[cfg(target_endian = "little")]
+        assert_eq!(
+            bytes[2 * word..],
+            [0x01, 0x00, 0x1e, 0x00, 0x01, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(InputEvent::from_bytes(bytes), event);
+
+        assert!(!event.is_report());
+        assert!(
+            InputEvent::new(EventTime::default(), SynchronizationEvent::REPORT.into(), 0)
+                .is_report()
+        );
+    }
+}
@@ -0,0 +1,111 @@
+//! Request codes of the evdev ioctls.
+//!
+//! The request codes are generated from `input.h`. Requests which take an argument, such as the length of the
+//! buffer for [`eviocgname`] or the event type for [`eviocgbit`], are const functions.
+//!
+//! With the `std` feature enabled, this module also provides safe wrappers which issue the ioctls on a file
+//! descriptor. The wrappers which read into a buffer let the kernel fill at most [`MAX_SIZE`] bytes of it.
+
+mod generated;
+#[cfg(feature = "std")]
+pub(crate) mod wrappers;
+
+pub use self::generated::*;
+#[cfg(feature = "std")]
+pub use self::wrappers::*;
+
+const NR_BITS: u32 = 8;
+const TYPE_BITS: u32 = 8;
+
+// A few architectures use a different layout for the request code than asm-generic/ioctl.h.
+#[cfg(any(
+    target_arch = "mips",
+    target_arch = "mips32r6",
+    target_arch = "mips64",
+    target_arch = "mips64r6",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+))]
+mod arch {
+    pub const SIZE_BITS: u32 = 13;
+    pub const NONE: u32 = 1;
+    pub const READ: u32 = 2;
+    pub const WRITE: u32 = 4;
+}
+
+#[cfg(not(any(
+    target_arch = "mips",
+    target_arch = "mips32r6",
+    target_arch = "mips64",
+    target_arch = "mips64r6",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+)))]
+mod arch {
+    pub const SIZE_BITS: u32 = 14;
+    pub const NONE: u32 = 0;
+    pub const READ: u32 = 2;
+    pub const WRITE: u32 = 1;
+}
+
+use self::arch::SIZE_BITS;
+pub(crate) use self::arch::{NONE, READ, WRITE};
+
+const NR_SHIFT: u32 = 0;
+const TYPE_SHIFT: u32 = NR_SHIFT + NR_BITS;
+const SIZE_SHIFT: u32 = TYPE_SHIFT + TYPE_BITS;
+const DIR_SHIFT: u32 = SIZE_SHIFT + SIZE_BITS;
+
+/// The largest size of the argument of a request, in bytes.
+pub const MAX_SIZE: usize = (1 << SIZE_BITS) - 1;
+
+/// Encodes a request code, the equivalent of the `_IOC` macro.
+///
+/// # Panics
+///
+/// Panics if the size does not fit in the size field of the request code.
+pub(crate) const fn ioc(direction: u32, kind: u8, number: u32, size: usize) -> u32 {
+    assert!(size <= MAX_SIZE, "ioctl argument size is too large");
+
+    (direction << DIR_SHIFT)
+        | ((kind as u32) << TYPE_SHIFT)
+        | ((number & ((1 << NR_BITS) - 1)) << NR_SHIFT)
+        | ((size as u32) << SIZE_SHIFT)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ioctl, AbsoluteAxis, EventType};
+
+    #[test]
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    fn request_codes() {
+        assert_eq!(ioctl::EVIOCGVERSION, 0x8004_4501);
+        assert_eq!(ioctl::EVIOCGID, 0x8008_4502);
+        assert_eq!(ioctl::EVIOCGREP, 0x8008_4503);
+        assert_eq!(ioctl::EVIOCSFF, 0x4030_4580);
+        assert_eq!(ioctl::EVIOCGRAB, 0x4004_4590);
+        assert_eq!(ioctl::EVIOCGMASK, 0x8010_4592);
+        assert_eq!(ioctl::eviocgname(256), 0x8100_4506);
+        assert_eq!(ioctl::eviocgbit(EventType::KEY, 96), 0x8060_4521);
+        assert_eq!(ioctl::eviocgabs(AbsoluteAxis::MT_SLOT), 0x8018_456f);
+        assert_eq!(ioctl::eviocsabs(AbsoluteAxis::X), 0x4018_45c0);
+        assert_eq!(ioctl::eviocgname(ioctl::MAX_SIZE), 0xbfff_4506);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn large_buffer() {
+        use std::{fs::File, os::unix::io::AsFd, vec};
+
+        // A buffer larger than the request code can describe is only partially filled, instead of panicking.
+        let file = File::open("/dev/null").unwrap();
+        let mut buffer = vec![0; 1 << 16];
+        assert!(ioctl::get_name(file.as_fd(), &mut buffer).is_err());
+        assert!(ioctl::get_bits(file.as_fd(), EventType::KEY, &mut buffer).is_err());
+    }
+}
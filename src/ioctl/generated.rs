@@ -0,0 +1,233 @@
+// This file is generated.
+//
+// Do NOT edit this file, instead modify the generator.
+
+#[doc = " get driver version "]
+pub const EVIOCGVERSION: u32 = crate::ioctl::ioc(
+    crate::ioctl::READ,
+    69u8,
+    1u32,
+    ::core::mem::size_of::<::core::ffi::c_int>(),
+);
+#[doc = " get device ID "]
+pub const EVIOCGID: u32 = crate::ioctl::ioc(
+    crate::ioctl::READ,
+    69u8,
+    2u32,
+    ::core::mem::size_of::<crate::InputId>(),
+);
+#[doc = " get repeat settings "]
+pub const EVIOCGREP: u32 = crate::ioctl::ioc(
+    crate::ioctl::READ,
+    69u8,
+    3u32,
+    ::core::mem::size_of::<[::core::ffi::c_uint; 2]>(),
+);
+#[doc = " set repeat settings "]
+pub const EVIOCSREP: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    69u8,
+    3u32,
+    ::core::mem::size_of::<[::core::ffi::c_uint; 2]>(),
+);
+#[doc = " get keycode "]
+pub const EVIOCGKEYCODE: u32 = crate::ioctl::ioc(
+    crate::ioctl::READ,
+    69u8,
+    4u32,
+    ::core::mem::size_of::<[::core::ffi::c_uint; 2]>(),
+);
+pub const EVIOCGKEYCODE_V2: u32 = crate::ioctl::ioc(
+    crate::ioctl::READ,
+    69u8,
+    4u32,
+    ::core::mem::size_of::<crate::InputKeymapEntry>(),
+);
+#[doc = " set keycode "]
+pub const EVIOCSKEYCODE: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    69u8,
+    4u32,
+    ::core::mem::size_of::<[::core::ffi::c_uint; 2]>(),
+);
+pub const EVIOCSKEYCODE_V2: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    69u8,
+    4u32,
+    ::core::mem::size_of::<crate::InputKeymapEntry>(),
+);
+#[doc = " get device name "]
+#[doc = r""]
+#[doc = r" # Panics"]
+#[doc = r""]
+#[doc = r" Panics if the length is larger than [`MAX_SIZE`](crate::ioctl::MAX_SIZE), the largest size"]
+#[doc = r" the request code can hold."]
+#[doc(alias = "EVIOCGNAME")]
+pub const fn eviocgname(len: usize) -> u32 {
+    crate::ioctl::ioc(crate::ioctl::READ, 69u8, 6u32, len)
+}
+#[doc = " get physical location "]
+#[doc = r""]
+#[doc = r" # Panics"]
+#[doc = r""]
+#[doc = r" Panics if the length is larger than [`MAX_SIZE`](crate::ioctl::MAX_SIZE), the largest size"]
+#[doc = r" the request code can hold."]
+#[doc(alias = "EVIOCGPHYS")]
+pub const fn eviocgphys(len: usize) -> u32 {
+    crate::ioctl::ioc(crate::ioctl::READ, 69u8, 7u32, len)
+}
+#[doc = " get unique identifier "]
+#[doc = r""]
+#[doc = r" # Panics"]
+#[doc = r""]
+#[doc = r" Panics if the length is larger than [`MAX_SIZE`](crate::ioctl::MAX_SIZE), the largest size"]
+#[doc = r" the request code can hold."]
+#[doc(alias = "EVIOCGUNIQ")]
+pub const fn eviocguniq(len: usize) -> u32 {
+    crate::ioctl::ioc(crate::ioctl::READ, 69u8, 8u32, len)
+}
+#[doc = " get device properties "]
+#[doc = r""]
+#[doc = r" # Panics"]
+#[doc = r""]
+#[doc = r" Panics if the length is larger than [`MAX_SIZE`](crate::ioctl::MAX_SIZE), the largest size"]
+#[doc = r" the request code can hold."]
+#[doc(alias = "EVIOCGPROP")]
+pub const fn eviocgprop(len: usize) -> u32 {
+    crate::ioctl::ioc(crate::ioctl::READ, 69u8, 9u32, len)
+}
+#[doc = r""]
+#[doc = r" # Panics"]
+#[doc = r""]
+#[doc = r" Panics if the length is larger than [`MAX_SIZE`](crate::ioctl::MAX_SIZE), the largest size"]
+#[doc = r" the request code can hold."]
+#[doc(alias = "EVIOCGMTSLOTS")]
+pub const fn eviocgmtslots(len: usize) -> u32 {
+    crate::ioctl::ioc(crate::ioctl::READ, 69u8, 10u32, len)
+}
+#[doc = " get global key state "]
+#[doc = r""]
+#[doc = r" # Panics"]
+#[doc = r""]
+#[doc = r" Panics if the length is larger than [`MAX_SIZE`](crate::ioctl::MAX_SIZE), the largest size"]
+#[doc = r" the request code can hold."]
+#[doc(alias = "EVIOCGKEY")]
+pub const fn eviocgkey(len: usize) -> u32 {
+    crate::ioctl::ioc(crate::ioctl::READ, 69u8, 24u32, len)
+}
+#[doc = " get all LEDs "]
+#[doc = r""]
+#[doc = r" # Panics"]
+#[doc = r""]
+#[doc = r" Panics if the length is larger than [`MAX_SIZE`](crate::ioctl::MAX_SIZE), the largest size"]
+#[doc = r" the request code can hold."]
+#[doc(alias = "EVIOCGLED")]
+pub const fn eviocgled(len: usize) -> u32 {
+    crate::ioctl::ioc(crate::ioctl::READ, 69u8, 25u32, len)
+}
+#[doc = " get all sounds status "]
+#[doc = r""]
+#[doc = r" # Panics"]
+#[doc = r""]
+#[doc = r" Panics if the length is larger than [`MAX_SIZE`](crate::ioctl::MAX_SIZE), the largest size"]
+#[doc = r" the request code can hold."]
+#[doc(alias = "EVIOCGSND")]
+pub const fn eviocgsnd(len: usize) -> u32 {
+    crate::ioctl::ioc(crate::ioctl::READ, 69u8, 26u32, len)
+}
+#[doc = " get all switch states "]
+#[doc = r""]
+#[doc = r" # Panics"]
+#[doc = r""]
+#[doc = r" Panics if the length is larger than [`MAX_SIZE`](crate::ioctl::MAX_SIZE), the largest size"]
+#[doc = r" the request code can hold."]
+#[doc(alias = "EVIOCGSW")]
+pub const fn eviocgsw(len: usize) -> u32 {
+    crate::ioctl::ioc(crate::ioctl::READ, 69u8, 27u32, len)
+}
+#[doc = " get event bits "]
+#[doc = r""]
+#[doc = r" # Panics"]
+#[doc = r""]
+#[doc = r" Panics if the length is larger than [`MAX_SIZE`](crate::ioctl::MAX_SIZE), the largest size"]
+#[doc = r" the request code can hold."]
+#[doc(alias = "EVIOCGBIT")]
+pub const fn eviocgbit(ev: crate::EventType, len: usize) -> u32 {
+    crate::ioctl::ioc(crate::ioctl::READ, 69u8, 32u32 + ev.into_inner(), len)
+}
+#[doc = " get abs value/limits "]
+#[doc(alias = "EVIOCGABS")]
+pub const fn eviocgabs(abs: crate::AbsoluteAxis) -> u32 {
+    crate::ioctl::ioc(
+        crate::ioctl::READ,
+        69u8,
+        64u32 + abs.into_inner(),
+        ::core::mem::size_of::<crate::InputAbsInfo>(),
+    )
+}
+#[doc = " set abs value/limits "]
+#[doc(alias = "EVIOCSABS")]
+pub const fn eviocsabs(abs: crate::AbsoluteAxis) -> u32 {
+    crate::ioctl::ioc(
+        crate::ioctl::WRITE,
+        69u8,
+        192u32 + abs.into_inner(),
+        ::core::mem::size_of::<crate::InputAbsInfo>(),
+    )
+}
+#[doc = " send a force effect to a force feedback device "]
+pub const EVIOCSFF: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    69u8,
+    128u32,
+    ::core::mem::size_of::<crate::sys::ff_effect>(),
+);
+#[doc = " Erase a force effect "]
+pub const EVIOCRMFF: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    69u8,
+    129u32,
+    ::core::mem::size_of::<::core::ffi::c_int>(),
+);
+#[doc = " Report number of effects playable at the same time "]
+pub const EVIOCGEFFECTS: u32 = crate::ioctl::ioc(
+    crate::ioctl::READ,
+    69u8,
+    132u32,
+    ::core::mem::size_of::<::core::ffi::c_int>(),
+);
+#[doc = " Grab/Release device "]
+pub const EVIOCGRAB: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    69u8,
+    144u32,
+    ::core::mem::size_of::<::core::ffi::c_int>(),
+);
+#[doc = " Revoke device access "]
+pub const EVIOCREVOKE: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    69u8,
+    145u32,
+    ::core::mem::size_of::<::core::ffi::c_int>(),
+);
+#[doc = " Get event-masks "]
+pub const EVIOCGMASK: u32 = crate::ioctl::ioc(
+    crate::ioctl::READ,
+    69u8,
+    146u32,
+    ::core::mem::size_of::<crate::InputMask>(),
+);
+#[doc = " Set event-masks "]
+pub const EVIOCSMASK: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    69u8,
+    147u32,
+    ::core::mem::size_of::<crate::InputMask>(),
+);
+#[doc = " Set clockid to be used for timestamps "]
+pub const EVIOCSCLOCKID: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    69u8,
+    160u32,
+    ::core::mem::size_of::<::core::ffi::c_int>(),
+);
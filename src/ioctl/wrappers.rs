@@ -0,0 +1,245 @@
+use std::{
+    io,
+    mem::MaybeUninit,
+    os::{
+        raw::{c_int, c_uint},
+        unix::io::{AsRawFd, BorrowedFd},
+    },
+};
+
+use crate::{
+    ioctl, sys, AbsoluteAxis, EventType, FfEffect, InputAbsInfo, InputId, InputKeymapEntry,
+    InputMask,
+};
+
+/// Issues an ioctl on the file descriptor, returning the non-negative result of the ioctl.
+///
+/// # Safety
+///
+/// The argument must be valid for the request.
+pub(crate) unsafe fn ioctl<T>(fd: BorrowedFd<'_>, request: u32, argument: T) -> io::Result<c_int> {
+    let result = libc::ioctl(fd.as_raw_fd(), request as libc::Ioctl, argument);
+
+    if result < 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(result)
+    }
+}
+
+/// Issues an ioctl which reads a value of type `T`.
+///
+/// # Safety
+///
+/// The request must read a value of type `T`.
+pub(crate) unsafe fn ioctl_read<T>(fd: BorrowedFd<'_>, request: u32) -> io::Result<T> {
+    let mut value = MaybeUninit::<T>::uninit();
+    ioctl(fd, request, value.as_mut_ptr())?;
+    Ok(value.assume_init())
+}
+
+/// Returns the version of the evdev protocol used by the device.
+#[doc(alias = "EVIOCGVERSION")]
+pub fn get_version(fd: BorrowedFd<'_>) -> io::Result<c_int> {
+    unsafe { ioctl_read(fd, ioctl::EVIOCGVERSION) }
+}
+
+/// Returns the id of the device.
+#[doc(alias = "EVIOCGID")]
+pub fn get_id(fd: BorrowedFd<'_>) -> io::Result<InputId> {
+    unsafe { ioctl_read(fd, ioctl::EVIOCGID) }
+}
+
+/// Returns the key repeat delay and period of the device, in milliseconds.
+#[doc(alias = "EVIOCGREP")]
+pub fn get_repeat(fd: BorrowedFd<'_>) -> io::Result<[c_uint; 2]> {
+    unsafe { ioctl_read(fd, ioctl::EVIOCGREP) }
+}
+
+/// Sets the key repeat delay and period of the device, in milliseconds.
+#[doc(alias = "EVIOCSREP")]
+pub fn set_repeat(fd: BorrowedFd<'_>, repeat: [c_uint; 2]) -> io::Result<()> {
+    unsafe { ioctl(fd, ioctl::EVIOCSREP, repeat.as_ptr()) }.map(drop)
+}
+
+/// Reads the name of the device into the buffer, returning the number of bytes written.
+///
+/// The name is nul terminated if it fits in the buffer.
+#[doc(alias = "EVIOCGNAME")]
+pub fn get_name(fd: BorrowedFd<'_>, buffer: &mut [u8]) -> io::Result<usize> {
+    get_buffer(fd, ioctl::eviocgname(buffer_len(buffer)), buffer)
+}
+
+/// Reads the physical location of the device into the buffer, returning the number of bytes written.
+///
+/// The location is nul terminated if it fits in the buffer.
+#[doc(alias = "EVIOCGPHYS")]
+pub fn get_phys(fd: BorrowedFd<'_>, buffer: &mut [u8]) -> io::Result<usize> {
+    get_buffer(fd, ioctl::eviocgphys(buffer_len(buffer)), buffer)
+}
+
+/// Reads the unique identifier of the device into the buffer, returning the number of bytes written.
+///
+/// The identifier is nul terminated if it fits in the buffer.
+#[doc(alias = "EVIOCGUNIQ")]
+pub fn get_uniq(fd: BorrowedFd<'_>, buffer: &mut [u8]) -> io::Result<usize> {
+    get_buffer(fd, ioctl::eviocguniq(buffer_len(buffer)), buffer)
+}
+
+/// Reads the bitmap of the device properties into the buffer, returning the number of bytes written.
+#[doc(alias = "EVIOCGPROP")]
+pub fn get_properties(fd: BorrowedFd<'_>, buffer: &mut [u8]) -> io::Result<usize> {
+    get_buffer(fd, ioctl::eviocgprop(buffer_len(buffer)), buffer)
+}
+
+/// Reads the bitmap of the codes the device supports for the event type into the buffer, returning the number
+/// of bytes written.
+///
+/// Passing [`EventType::SYN`] reads the bitmap of the event types the device supports.
+#[doc(alias = "EVIOCGBIT")]
+pub fn get_bits(fd: BorrowedFd<'_>, event_type: EventType, buffer: &mut [u8]) -> io::Result<usize> {
+    get_buffer(fd, ioctl::eviocgbit(event_type, buffer_len(buffer)), buffer)
+}
+
+/// Reads the bitmap of the keys and buttons which are currently pressed into the buffer, returning the number
+/// of bytes written.
+#[doc(alias = "EVIOCGKEY")]
+pub fn get_key_state(fd: BorrowedFd<'_>, buffer: &mut [u8]) -> io::Result<usize> {
+    get_buffer(fd, ioctl::eviocgkey(buffer_len(buffer)), buffer)
+}
+
+/// Reads the bitmap of the LEDs which are currently lit into the buffer, returning the number of bytes written.
+#[doc(alias = "EVIOCGLED")]
+pub fn get_led_state(fd: BorrowedFd<'_>, buffer: &mut [u8]) -> io::Result<usize> {
+    get_buffer(fd, ioctl::eviocgled(buffer_len(buffer)), buffer)
+}
+
+/// Reads the bitmap of the sounds which are currently playing into the buffer, returning the number of bytes
+/// written.
+#[doc(alias = "EVIOCGSND")]
+pub fn get_sound_state(fd: BorrowedFd<'_>, buffer: &mut [u8]) -> io::Result<usize> {
+    get_buffer(fd, ioctl::eviocgsnd(buffer_len(buffer)), buffer)
+}
+
+/// Reads the bitmap of the switches which are currently set into the buffer, returning the number of bytes
+/// written.
+#[doc(alias = "EVIOCGSW")]
+pub fn get_switch_state(fd: BorrowedFd<'_>, buffer: &mut [u8]) -> io::Result<usize> {
+    get_buffer(fd, ioctl::eviocgsw(buffer_len(buffer)), buffer)
+}
+
+/// Returns the state and limits of an absolute axis.
+#[doc(alias = "EVIOCGABS")]
+pub fn get_abs_info(fd: BorrowedFd<'_>, axis: AbsoluteAxis) -> io::Result<InputAbsInfo> {
+    unsafe { ioctl_read(fd, ioctl::eviocgabs(axis)) }
+}
+
+/// Sets the state and limits of an absolute axis.
+#[doc(alias = "EVIOCSABS")]
+pub fn set_abs_info(
+    fd: BorrowedFd<'_>,
+    axis: AbsoluteAxis,
+    abs_info: &InputAbsInfo,
+) -> io::Result<()> {
+    unsafe { ioctl(fd, ioctl::eviocsabs(axis), abs_info as *const InputAbsInfo) }.map(drop)
+}
+
+/// Looks up an entry of the keymap of the device.
+///
+/// The entry is looked up by the scancode or index of `entry`, and is then filled in with the keymap entry.
+#[doc(alias = "EVIOCGKEYCODE_V2")]
+pub fn get_keycode(fd: BorrowedFd<'_>, entry: &mut InputKeymapEntry) -> io::Result<()> {
+    unsafe { ioctl(fd, ioctl::EVIOCGKEYCODE_V2, entry as *mut InputKeymapEntry) }.map(drop)
+}
+
+/// Sets an entry of the keymap of the device.
+#[doc(alias = "EVIOCSKEYCODE_V2")]
+pub fn set_keycode(fd: BorrowedFd<'_>, entry: &InputKeymapEntry) -> io::Result<()> {
+    unsafe {
+        ioctl(
+            fd,
+            ioctl::EVIOCSKEYCODE_V2,
+            entry as *const InputKeymapEntry,
+        )
+    }
+    .map(drop)
+}
+
+/// Reads the event mask of the event type into the buffer.
+///
+/// The event mask is the bitmap of the codes of the event type which are delivered to the file descriptor.
+#[doc(alias = "EVIOCGMASK")]
+pub fn get_mask(fd: BorrowedFd<'_>, event_type: EventType, buffer: &mut [u8]) -> io::Result<()> {
+    let mask = InputMask {
+        type_: event_type,
+        codes_size: buffer.len() as u32,
+        codes_ptr: buffer.as_mut_ptr() as u64,
+    };
+
+    unsafe { ioctl(fd, ioctl::EVIOCGMASK, &mask as *const InputMask) }.map(drop)
+}
+
+/// Sets the event mask of the event type.
+#[doc(alias = "EVIOCSMASK")]
+pub fn set_mask(fd: BorrowedFd<'_>, event_type: EventType, codes: &[u8]) -> io::Result<()> {
+    let mask = InputMask {
+        type_: event_type,
+        codes_size: codes.len() as u32,
+        codes_ptr: codes.as_ptr() as u64,
+    };
+
+    unsafe { ioctl(fd, ioctl::EVIOCSMASK, &mask as *const InputMask) }.map(drop)
+}
+
+/// Returns the number of force feedback effects the device can play at the same time.
+#[doc(alias = "EVIOCGEFFECTS")]
+pub fn get_effects(fd: BorrowedFd<'_>) -> io::Result<c_int> {
+    unsafe { ioctl_read(fd, ioctl::EVIOCGEFFECTS) }
+}
+
+/// Uploads a force feedback effect to the device, or updates an uploaded effect.
+///
+/// If the id of the effect is [`FfEffect::NEW_ID`], the id the kernel assigns to the effect is written to the
+/// effect.
+#[doc(alias = "EVIOCSFF")]
+pub fn upload_effect(fd: BorrowedFd<'_>, effect: &mut FfEffect) -> io::Result<()> {
+    let mut raw = sys::ff_effect::from(*effect);
+    unsafe { ioctl(fd, ioctl::EVIOCSFF, &mut raw as *mut sys::ff_effect) }?;
+    effect.id = raw.id;
+    Ok(())
+}
+
+/// Erases an uploaded force feedback effect.
+#[doc(alias = "EVIOCRMFF")]
+pub fn remove_effect(fd: BorrowedFd<'_>, id: i16) -> io::Result<()> {
+    unsafe { ioctl(fd, ioctl::EVIOCRMFF, c_int::from(id)) }.map(drop)
+}
+
+/// Grabs or releases the device.
+///
+/// While grabbed, events of the device are only delivered to the file descriptor which grabbed the device.
+#[doc(alias = "EVIOCGRAB")]
+pub fn grab(fd: BorrowedFd<'_>, grab: bool) -> io::Result<()> {
+    unsafe { ioctl(fd, ioctl::EVIOCGRAB, c_int::from(grab)) }.map(drop)
+}
+
+/// Revokes access to the device through the file descriptor.
+#[doc(alias = "EVIOCREVOKE")]
+pub fn revoke(fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe { ioctl(fd, ioctl::EVIOCREVOKE, 0 as c_int) }.map(drop)
+}
+
+/// Sets the clock used for the timestamps of events, such as `CLOCK_MONOTONIC`.
+#[doc(alias = "EVIOCSCLOCKID")]
+pub fn set_clock_id(fd: BorrowedFd<'_>, clock_id: c_int) -> io::Result<()> {
+    unsafe { ioctl(fd, ioctl::EVIOCSCLOCKID, &clock_id as *const c_int) }.map(drop)
+}
+
+pub(crate) fn get_buffer(fd: BorrowedFd<'_>, request: u32, buffer: &mut [u8]) -> io::Result<usize> {
+    unsafe { ioctl(fd, request, buffer.as_mut_ptr()) }.map(|length| length as usize)
+}
+
+/// The length of the buffer passed in a request code, limited to the largest size the request code can hold.
+pub(crate) fn buffer_len(buffer: &[u8]) -> usize {
+    buffer.len().min(ioctl::MAX_SIZE)
+}
@@ -0,0 +1,241 @@
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use crate::{Button, Key, ParseCodeError};
+
+/// The code of an [`EventType::KEY`](crate::EventType::KEY) event.
+///
+/// Keys and buttons share the same code space, so the code of a key event may be either. Creating a key code
+/// from a raw value with [`KeyCode::new`] classifies the value using the ranges the kernel reserves for buttons,
+/// see [`ButtonGroup`](crate::ButtonGroup).
+///
+/// Key codes are compared and hashed by their raw value, so a button wrapped in [`KeyCode::Key`] is equal to the
+/// same button wrapped in [`KeyCode::Button`].
+#[derive(Debug, Clone, Copy)]
+pub enum KeyCode {
+    /// A keyboard key.
+    Key(Key),
+
+    /// A button.
+    Button(Button),
+}
+
+impl KeyCode {
+    /// Creates a key code from a raw value, classifying it as a key or a button.
+    pub const fn new(value: u32) -> Self {
+        if Button::new(value).group().is_some() {
+            Self::Button(Button::new(value))
+        } else {
+            Self::Key(Key::new(value))
+        }
+    }
+
+    pub const fn into_inner(self) -> u32 {
+        match self {
+            Self::Key(key) => key.into_inner(),
+            Self::Button(button) => button.into_inner(),
+        }
+    }
+
+    /// Whether the code is a key.
+    pub const fn is_key(self) -> bool {
+        matches!(self, Self::Key(_))
+    }
+
+    /// Whether the code is a button.
+    pub const fn is_button(self) -> bool {
+        matches!(self, Self::Button(_))
+    }
+
+    /// Returns the name of the code as defined in the kernel headers.
+    ///
+    /// Returns [`None`] if the value is not a known code.
+    pub const fn name(self) -> Option<&'static str> {
+        match self {
+            Self::Key(key) => key.name(),
+            Self::Button(button) => button.name(),
+        }
+    }
+
+    /// Looks up a key or button by name.
+    ///
+    /// This accepts the names accepted by [`Key::from_name`] and [`Button::from_name`].
+    pub fn from_name(name: &str) -> Result<Self, ParseCodeError> {
+        Key::from_name(name)
+            .map(Self::Key)
+            .or_else(|_| Button::from_name(name).map(Self::Button))
+            .map_err(|_| ParseCodeError::new("KeyCode"))
+    }
+
+    /// Looks up a key or button by name, ignoring ASCII case.
+    ///
+    /// This accepts the same names as [`from_name`](Self::from_name).
+    pub fn from_name_ignore_case(name: &str) -> Result<Self, ParseCodeError> {
+        Key::from_name_ignore_case(name)
+            .map(Self::Key)
+            .or_else(|_| Button::from_name_ignore_case(name).map(Self::Button))
+            .map_err(|_| ParseCodeError::new("KeyCode"))
+    }
+}
+
+/// Classifies the value of the key like [`KeyCode::new`], so a value in the ranges of the buttons is a button.
+impl From<Key> for KeyCode {
+    fn from(key: Key) -> Self {
+        Self::new(key.into_inner())
+    }
+}
+
+/// Classifies the value of the button like [`KeyCode::new`], so a value outside the ranges of the buttons is a
+/// key.
+impl From<Button> for KeyCode {
+    fn from(button: Button) -> Self {
+        Self::new(button.into_inner())
+    }
+}
+
+impl From<u32> for KeyCode {
+    fn from(value: u32) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<KeyCode> for u32 {
+    fn from(code: KeyCode) -> u32 {
+        code.into_inner()
+    }
+}
+
+impl TryFrom<KeyCode> for Key {
+    /// The code is a button.
+    type Error = Button;
+
+    fn try_from(code: KeyCode) -> Result<Self, Self::Error> {
+        match code {
+            KeyCode::Key(key) => Ok(key),
+            KeyCode::Button(button) => Err(button),
+        }
+    }
+}
+
+impl TryFrom<KeyCode> for Button {
+    /// The code is a key.
+    type Error = Key;
+
+    fn try_from(code: KeyCode) -> Result<Self, Self::Error> {
+        match code {
+            KeyCode::Button(button) => Ok(button),
+            KeyCode::Key(key) => Err(key),
+        }
+    }
+}
+
+impl PartialEq for KeyCode {
+    fn eq(&self, other: &Self) -> bool {
+        self.into_inner() == other.into_inner()
+    }
+}
+
+impl Eq for KeyCode {}
+
+impl PartialEq<u32> for KeyCode {
+    fn eq(&self, other: &u32) -> bool {
+        self.into_inner() == *other
+    }
+}
+
+impl PartialOrd for KeyCode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for KeyCode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.into_inner().cmp(&other.into_inner())
+    }
+}
+
+impl Hash for KeyCode {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.into_inner().hash(state)
+    }
+}
+
+impl fmt::Display for KeyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Key(key) => key.fmt(f),
+            Self::Button(button) => button.fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::convert::TryFrom;
+
+    use crate::{Button, Key, KeyCode};
+
+    #[test]
+    fn classify() {
+        assert_eq!(KeyCode::new(30), KeyCode::Key(Key::A));
+        assert!(KeyCode::new(0x110).is_button());
+        assert!(KeyCode::new(0x10a).is_button());
+        assert!(KeyCode::new(0x160).is_key());
+        assert!(KeyCode::new(0x221).is_button());
+        assert!(KeyCode::new(0x230).is_key());
+        assert!(KeyCode::new(0x2e7).is_button());
+        assert!(KeyCode::new(0x2e8).is_key());
+
+        for &key in Key::ALL {
+            assert_eq!(KeyCode::new(key.into_inner()), KeyCode::Key(key));
+            assert!(KeyCode::new(key.into_inner()).is_key());
+        }
+
+        for &button in Button::ALL {
+            assert!(KeyCode::new(button.into_inner()).is_button());
+        }
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Key::try_from(KeyCode::from(Key::A)), Ok(Key::A));
+        assert_eq!(
+            Key::try_from(KeyCode::from(Button::LEFT)),
+            Err(Button::LEFT)
+        );
+        assert_eq!(
+            Button::try_from(KeyCode::from(Button::LEFT)),
+            Ok(Button::LEFT)
+        );
+        assert_eq!(u32::from(KeyCode::from(Button::LEFT)), 0x110);
+        assert_eq!(KeyCode::from(Key::new(0x110)), KeyCode::from(Button::LEFT));
+
+        // Converting a key or a button classifies the value the same way as a raw value.
+        for value in [30, 0x110, 0x160, 0x2e7, 0x1000] {
+            let code = KeyCode::new(value);
+            assert_eq!(KeyCode::from(Key::new(value)).is_button(), code.is_button());
+            assert_eq!(
+                KeyCode::from(Button::new(value)).is_button(),
+                code.is_button()
+            );
+        }
+
+        assert!(KeyCode::from(Key::new(0x110)).is_button());
+        assert!(KeyCode::from(Button::new(30)).is_key());
+    }
+
+    #[test]
+    fn from_name() {
+        assert_eq!(KeyCode::from_name("KEY_A"), Ok(KeyCode::Key(Key::A)));
+        assert_eq!(
+            KeyCode::from_name_ignore_case("btn_side"),
+            Ok(KeyCode::Button(Button::SIDE))
+        );
+        assert!(KeyCode::from_name("REL_X").is_err());
+    }
+}
@@ -0,0 +1,279 @@
+use core::convert::TryFrom;
+
+use crate::{Button, EventCode, InputEvent, Key, KeyCode, KeySet, KeyStateError};
+
+/// The value of an [`EventType::KEY`](crate::EventType::KEY) event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyValue {
+    /// The key was released, a value of 0.
+    Released,
+
+    /// The key was pressed, a value of 1.
+    Pressed,
+
+    /// The key is held and was repeated by the autorepeat of the kernel, a value of 2.
+    Repeat,
+}
+
+impl KeyValue {
+    /// Creates a key value from the value of an event.
+    ///
+    /// Returns [`None`] if the value is not 0, 1 or 2.
+    pub const fn new(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Released),
+            1 => Some(Self::Pressed),
+            2 => Some(Self::Repeat),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of an event with the key value.
+    pub const fn into_inner(self) -> i32 {
+        match self {
+            Self::Released => 0,
+            Self::Pressed => 1,
+            Self::Repeat => 2,
+        }
+    }
+
+    /// Whether the key is held down.
+    pub const fn is_down(self) -> bool {
+        !matches!(self, Self::Released)
+    }
+}
+
+impl TryFrom<i32> for KeyValue {
+    /// The value, if it is not 0, 1 or 2.
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, i32> {
+        Self::new(value).ok_or(value)
+    }
+}
+
+impl From<KeyValue> for i32 {
+    fn from(value: KeyValue) -> Self {
+        value.into_inner()
+    }
+}
+
+/// A change of the keys which are pressed, returned by [`KeyboardState::update`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyEdge {
+    /// The key was pressed.
+    Pressed(KeyCode),
+
+    /// The key was released.
+    Released(KeyCode),
+}
+
+/// Tracks the keys and buttons which are pressed on a device.
+///
+/// The state is updated from the [`EventType::KEY`](crate::EventType::KEY) events of the device. Events which do
+/// not match the state, such as the release of a key which is not pressed, are reported as errors, but still
+/// update the state so it follows the device.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeyboardState {
+    pressed: KeySet,
+}
+
+impl KeyboardState {
+    /// Creates a state where no key is pressed.
+    pub const fn new() -> Self {
+        Self {
+            pressed: KeySet::EMPTY,
+        }
+    }
+
+    /// Creates a state where the keys are pressed.
+    ///
+    /// The state of a device is read into a [`KeySet`] with `EVIOCGKEY`, such as with `ioctl::get_key_state` when
+    /// the `std` feature is enabled.
+    pub const fn from_keys(pressed: KeySet) -> Self {
+        Self { pressed }
+    }
+
+    /// The keys and buttons which are pressed.
+    pub const fn pressed(&self) -> &KeySet {
+        &self.pressed
+    }
+
+    /// Returns whether the key or button is pressed.
+    pub const fn is_pressed(&self, code: KeyCode) -> bool {
+        self.pressed.contains(code)
+    }
+
+    /// Returns an iterator over the keys which are pressed, excluding buttons.
+    pub fn keys(&self) -> impl Iterator<Item = Key> + '_ {
+        self.pressed.iter().filter_map(|code| match code {
+            KeyCode::Key(key) => Some(key),
+            KeyCode::Button(_) => None,
+        })
+    }
+
+    /// Returns an iterator over the buttons which are pressed.
+    pub fn buttons(&self) -> impl Iterator<Item = Button> + '_ {
+        self.pressed.iter().filter_map(|code| match code {
+            KeyCode::Key(_) => None,
+            KeyCode::Button(button) => Some(button),
+        })
+    }
+
+    /// Updates the state with an event read from the device.
+    ///
+    /// Events of other types are ignored.
+    pub fn process(&mut self, event: &InputEvent) -> Result<Option<KeyEdge>, KeyStateError> {
+        match event.event_code() {
+            EventCode::Key(code) => {
+                let value = KeyValue::new(event.value)
+                    .ok_or(KeyStateError::InvalidValue(code, event.value))?;
+
+                self.update(code, value)
+            }
+
+            _ => Ok(None),
+        }
+    }
+
+    /// Updates the state of a key, returning the edge if the key was pressed or released.
+    ///
+    /// A repeat of a key which is pressed is not an edge. Codes larger than `KEY_MAX` are reported as errors and
+    /// do not change the state.
+    pub fn update(
+        &mut self,
+        code: KeyCode,
+        value: KeyValue,
+    ) -> Result<Option<KeyEdge>, KeyStateError> {
+        if code.into_inner() as usize >= Key::COUNT {
+            return Err(KeyStateError::InvalidCode(code));
+        }
+
+        let changed = if value.is_down() {
+            self.pressed.insert(code)
+        } else {
+            self.pressed.remove(code)
+        };
+
+        match (value, changed) {
+            (KeyValue::Pressed, true) => Ok(Some(KeyEdge::Pressed(code))),
+            (KeyValue::Released, true) => Ok(Some(KeyEdge::Released(code))),
+            (KeyValue::Repeat, false) => Ok(None),
+            (value, _) => Err(KeyStateError::Inconsistent(code, value)),
+        }
+    }
+
+    /// Releases every key, returning the keys which were pressed.
+    pub fn clear(&mut self) -> KeySet {
+        core::mem::take(&mut self.pressed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::convert::TryFrom;
+    use std::{string::ToString, vec::Vec};
+
+    use crate::{
+        Button, EventTime, InputEvent, Key, KeyCode, KeyEdge, KeySet, KeyStateError, KeyValue,
+        KeyboardState, RelativeAxis,
+    };
+
+    fn event(code: impl Into<KeyCode>, value: i32) -> InputEvent {
+        InputEvent::new(EventTime::default(), code.into().into(), value)
+    }
+
+    #[test]
+    fn key_value() {
+        assert_eq!(KeyValue::try_from(2), Ok(KeyValue::Repeat));
+        assert_eq!(KeyValue::try_from(3), Err(3));
+        assert_eq!(i32::from(KeyValue::Pressed), 1);
+        assert!(!KeyValue::Released.is_down());
+    }
+
+    #[test]
+    fn edges() {
+        let mut state = KeyboardState::new();
+        let a = KeyCode::Key(Key::A);
+
+        assert_eq!(state.process(&event(a, 1)), Ok(Some(KeyEdge::Pressed(a))));
+        assert_eq!(state.process(&event(a, 2)), Ok(None));
+        assert_eq!(state.process(&event(a, 2)), Ok(None));
+        assert_eq!(
+            state.process(&event(Button::LEFT, 1)),
+            Ok(Some(KeyEdge::Pressed(KeyCode::Button(Button::LEFT))))
+        );
+        assert_eq!(state.keys().collect::<Vec<_>>(), [Key::A]);
+        assert_eq!(state.buttons().collect::<Vec<_>>(), [Button::LEFT]);
+
+        assert_eq!(state.process(&event(a, 0)), Ok(Some(KeyEdge::Released(a))));
+        assert!(!state.is_pressed(a));
+
+        let motion = InputEvent::new(EventTime::default(), RelativeAxis::X.into(), 5);
+        assert_eq!(state.process(&motion), Ok(None));
+    }
+
+    #[test]
+    fn inconsistent() {
+        let mut state = KeyboardState::new();
+        let a = KeyCode::Key(Key::A);
+
+        let error = state.process(&event(a, 0)).unwrap_err();
+        assert_eq!(error, KeyStateError::Inconsistent(a, KeyValue::Released));
+        assert_eq!(error.to_string(), "KEY_A released while not pressed");
+
+        // A repeat without a press still marks the key as pressed.
+        assert_eq!(
+            state.process(&event(a, 2)),
+            Err(KeyStateError::Inconsistent(a, KeyValue::Repeat))
+        );
+        assert!(state.is_pressed(a));
+        assert_eq!(
+            state.process(&event(a, 1)),
+            Err(KeyStateError::Inconsistent(a, KeyValue::Pressed))
+        );
+
+        assert_eq!(
+            state.process(&event(a, 7)),
+            Err(KeyStateError::InvalidValue(a, 7))
+        );
+        assert!(state.is_pressed(a));
+    }
+
+    #[test]
+    fn invalid_code() {
+        let mut state = KeyboardState::new();
+        let code = KeyCode::new(0x300);
+
+        let error = state.process(&event(code, 1)).unwrap_err();
+        assert_eq!(error, KeyStateError::InvalidCode(code));
+        assert_eq!(error.to_string(), "invalid key code KEY_0x300");
+        assert_eq!(
+            state.update(code, KeyValue::Released),
+            Err(KeyStateError::InvalidCode(code))
+        );
+        assert!(state.pressed().is_empty());
+    }
+
+    #[test]
+    fn seeded() {
+        let mut snapshot = KeySet::new();
+        snapshot.insert(Key::LEFTSHIFT.into());
+
+        let mut state = KeyboardState::from_keys(snapshot);
+        let shift = KeyCode::Key(Key::LEFTSHIFT);
+
+        assert!(state.is_pressed(shift));
+        assert_eq!(
+            state.process(&event(shift, 0)),
+            Ok(Some(KeyEdge::Released(shift)))
+        );
+
+        state.process(&event(Key::B, 1)).unwrap();
+        assert_eq!(
+            state.clear().iter().collect::<Vec<_>>(),
+            [KeyCode::Key(Key::B)]
+        );
+        assert!(state.pressed().is_empty());
+    }
+}
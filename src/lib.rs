@@ -11,6 +11,57 @@
 #![no_std]
 #![warn(future_incompatible)]
 
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod bitset;
+mod button_group;
+mod classify;
+mod error;
+mod event_code;
+mod event_stream;
+mod force_feedback;
 mod generated;
+#[cfg(test)]
+mod generated_test;
+mod input;
+mod input_event;
+pub mod ioctl;
+mod key_code;
+mod keyboard;
+mod multitouch;
+mod phf;
+mod proc_devices;
+mod sync;
+#[allow(non_camel_case_types)]
+pub mod sys;
+#[cfg(feature = "std")]
+pub mod sysfs;
+pub mod uinput;
+mod wheel;
 
+pub use self::bitset::{
+    AbsSet, EventTypeSet, ForceFeedbackSet, KeySet, LedSet, MiscSet, PropertySet, RelSet, SetIter,
+    SoundSet, SwitchSet,
+};
+pub use self::button_group::ButtonGroup;
+pub use self::classify::{classify, DeviceCapabilities, DeviceClasses};
+pub use self::error::{EventStreamError, KeyStateError, ParseCodeError, ParseDevicesError};
+pub use self::event_code::EventCode;
+pub use self::event_stream::{
+    decode_events, DecodeEvents, EventDecoder, EventEncoder, Events, FrameBuffer,
+};
+pub use self::force_feedback::{
+    FfCondition, FfEffect, FfEffectKind, FfEnvelope, FfPeriodic, FfReplay, FfTrigger,
+};
 pub use self::generated::*;
+pub use self::input::{InputAbsInfo, InputId, InputKeymapEntry, InputMask};
+pub use self::input_event::{EventTime, InputEvent};
+pub use self::key_code::KeyCode;
+pub use self::keyboard::{KeyEdge, KeyValue, KeyboardState};
+pub use self::multitouch::{
+    ConvertedEvents, MtConverter, MtSlot, MtTracker, Touch, TouchChange, TouchChanges, TouchPhase,
+};
+pub use self::proc_devices::{ProcDevice, ProcDevices};
+pub use self::sync::{DeviceState, SyncEvents, SyncFilter};
+pub use self::wheel::{WheelAccumulator, WheelFrame, WheelScroll};
@@ -0,0 +1,829 @@
+//! State of multitouch devices using the slots of the type B protocol.
+//!
+//! A device using the type B protocol tracks each contact in a slot. The `ABS_MT_SLOT` event selects a slot, and
+//! the following `ABS_MT_*` events update the axes of the contact in that slot. A slot is empty when its
+//! `ABS_MT_TRACKING_ID` is -1.
+//!
+//! [`MtTracker`] follows the slots of a device and reports the contacts which began, moved or ended in each frame.
+
+use core::convert::TryFrom;
+
+use crate::{AbsoluteAxis, EventCode, EventTime, InputAbsInfo, InputEvent, SynchronizationEvent};
+
+/// The values of the multitouch axes of a slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MtSlot {
+    values: [i32; Self::AXES],
+}
+
+impl MtSlot {
+    /// The number of multitouch axes stored in a slot, from `ABS_MT_TOUCH_MAJOR` to `ABS_MT_TOOL_Y`.
+    pub const AXES: usize = (AbsoluteAxis::MT_TOOL_Y.into_inner() - Self::FIRST_AXIS + 1) as usize;
+
+    /// An empty slot, with a tracking id of -1 and every other axis set to 0.
+    pub const EMPTY: Self = {
+        let mut values = [0; Self::AXES];
+        values[Self::TRACKING_ID] = -1;
+        Self { values }
+    };
+
+    const FIRST_AXIS: u32 = AbsoluteAxis::MT_TOUCH_MAJOR.into_inner();
+    const TRACKING_ID: usize =
+        (AbsoluteAxis::MT_TRACKING_ID.into_inner() - Self::FIRST_AXIS) as usize;
+
+    /// Returns whether the axis is stored in a slot.
+    ///
+    /// This is true for the `ABS_MT_*` axes, except for `ABS_MT_SLOT`.
+    pub const fn is_slot_axis(axis: AbsoluteAxis) -> bool {
+        Self::index(axis).is_some()
+    }
+
+    /// Returns the multitouch axis stored at the index of a slot.
+    const fn axis(index: usize) -> AbsoluteAxis {
+        AbsoluteAxis::new(Self::FIRST_AXIS + index as u32)
+    }
+
+    /// Returns the axes of a slot in the order changes are reported in. The tracking id comes first, so a new
+    /// contact starts before its position is updated.
+    pub(crate) const fn axis_in_order(step: usize) -> AbsoluteAxis {
+        match step {
+            0 => Self::axis(Self::TRACKING_ID),
+            step if step <= Self::TRACKING_ID => Self::axis(step - 1),
+            step => Self::axis(step),
+        }
+    }
+
+    const fn index(axis: AbsoluteAxis) -> Option<usize> {
+        let index = axis.into_inner().wrapping_sub(Self::FIRST_AXIS) as usize;
+
+        if index < Self::AXES {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value of a multitouch axis, or [`None`] if the axis is not stored in a slot.
+    pub const fn get(&self, axis: AbsoluteAxis) -> Option<i32> {
+        match Self::index(axis) {
+            Some(index) => Some(self.values[index]),
+            None => None,
+        }
+    }
+
+    /// Sets the value of a multitouch axis.
+    ///
+    /// Returns `false` and ignores the value if the axis is not stored in a slot.
+    pub fn set(&mut self, axis: AbsoluteAxis, value: i32) -> bool {
+        match Self::index(axis) {
+            Some(index) => {
+                self.values[index] = value;
+                true
+            }
+
+            None => false,
+        }
+    }
+
+    /// The tracking id of the contact in the slot, or -1 if the slot is empty.
+    pub const fn tracking_id(&self) -> i32 {
+        self.values[Self::TRACKING_ID]
+    }
+
+    /// Returns whether the slot holds a contact.
+    pub const fn is_active(&self) -> bool {
+        self.tracking_id() != -1
+    }
+}
+
+impl Default for MtSlot {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+/// A contact on a multitouch device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Touch {
+    /// The slot the contact is tracked in.
+    pub slot: usize,
+
+    /// The tracking id of the contact, which is unique among the contacts of the device.
+    pub tracking_id: i32,
+
+    /// The horizontal position of the center of the contact, from `ABS_MT_POSITION_X`.
+    pub x: i32,
+
+    /// The vertical position of the center of the contact, from `ABS_MT_POSITION_Y`.
+    pub y: i32,
+
+    /// The pressure of the contact, from `ABS_MT_PRESSURE`.
+    pub pressure: i32,
+
+    /// The length of the major axis of the contact, from `ABS_MT_TOUCH_MAJOR`.
+    pub touch_major: i32,
+
+    /// The type of tool making the contact, from `ABS_MT_TOOL_TYPE`, such as `MT_TOOL_FINGER`.
+    pub tool_type: i32,
+}
+
+impl Touch {
+    fn new(slot: usize, state: &MtSlot) -> Self {
+        let value = |axis| state.get(axis).unwrap_or_default();
+
+        Self {
+            slot,
+            tracking_id: state.tracking_id(),
+            x: value(AbsoluteAxis::MT_POSITION_X),
+            y: value(AbsoluteAxis::MT_POSITION_Y),
+            pressure: value(AbsoluteAxis::MT_PRESSURE),
+            touch_major: value(AbsoluteAxis::MT_TOUCH_MAJOR),
+            tool_type: value(AbsoluteAxis::MT_TOOL_TYPE),
+        }
+    }
+}
+
+/// How a contact changed in a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TouchPhase {
+    /// The contact began.
+    Begin,
+
+    /// One or more axes of the contact changed.
+    Update,
+
+    /// The contact ended. The touch holds the last values of the contact.
+    End,
+}
+
+/// A change of a contact in a frame, returned by [`MtTracker::push`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TouchChange {
+    /// How the contact changed.
+    pub phase: TouchPhase,
+
+    /// The contact.
+    pub touch: Touch,
+}
+
+/// Tracks the contacts of a device using the type B multitouch protocol.
+///
+/// The tracker holds up to `N` slots. The number of slots used is set when the tracker is created, usually from the
+/// maximum of the `ABS_MT_SLOT` axis with [`MtTracker::from_abs_info`].
+///
+/// The tracker does not handle `SYN_DROPPED`, so the events should be passed through a
+/// [`SyncFilter`](crate::SyncFilter) first.
+#[derive(Debug, Clone)]
+pub struct MtTracker<const N: usize> {
+    pending: [MtSlot; N],
+    reported: [MtSlot; N],
+    slots: usize,
+    slot: i32,
+}
+
+impl<const N: usize> MtTracker<N> {
+    /// Creates a tracker using the number of slots, or `N` slots if there are more.
+    pub const fn new(slots: usize) -> Self {
+        Self {
+            pending: [MtSlot::EMPTY; N],
+            reported: [MtSlot::EMPTY; N],
+            slots: if slots < N { slots } else { N },
+            slot: 0,
+        }
+    }
+
+    /// Creates a tracker for a device, using the range of its `ABS_MT_SLOT` axis.
+    pub const fn from_abs_info(slot_info: &InputAbsInfo) -> Self {
+        let slots = if slot_info.maximum < 0 {
+            0
+        } else {
+            slot_info.maximum as usize + 1
+        };
+
+        Self::new(slots)
+    }
+
+    /// The number of slots used.
+    pub const fn slots(&self) -> usize {
+        self.slots
+    }
+
+    /// The selected slot.
+    pub const fn current_slot(&self) -> i32 {
+        self.slot
+    }
+
+    /// The state of a slot at the last `SYN_REPORT`.
+    pub fn slot(&self, slot: usize) -> Option<&MtSlot> {
+        self.reported[..self.slots].get(slot)
+    }
+
+    /// Returns an iterator over the contacts at the last `SYN_REPORT`.
+    pub fn touches(&self) -> impl Iterator<Item = Touch> + '_ {
+        self.reported[..self.slots]
+            .iter()
+            .enumerate()
+            .filter(|(_, state)| state.is_active())
+            .map(|(slot, state)| Touch::new(slot, state))
+    }
+
+    /// Updates the tracker with an event read from the device.
+    ///
+    /// Returns the changes of the contacts when the event is a `SYN_REPORT`, which ends the frame. Events for
+    /// slots beyond the number of slots used are ignored.
+    pub fn push(&mut self, event: &InputEvent) -> Option<TouchChanges<'_, N>> {
+        match event.event_code() {
+            EventCode::Abs(AbsoluteAxis::MT_SLOT) => self.slot = event.value,
+
+            EventCode::Abs(axis) => {
+                if let Some(state) = usize::try_from(self.slot)
+                    .ok()
+                    .and_then(|slot| self.pending[..self.slots].get_mut(slot))
+                {
+                    state.set(axis, event.value);
+                }
+            }
+
+            _ if event.is_report() => {
+                let previous = self.reported;
+                self.reported = self.pending;
+
+                return Some(TouchChanges {
+                    previous,
+                    current: &self.reported[..self.slots],
+                    slot: 0,
+                    ended: false,
+                });
+            }
+
+            _ => {}
+        }
+
+        None
+    }
+}
+
+impl<const N: usize> Default for MtTracker<N> {
+    /// Creates a tracker using all `N` slots.
+    fn default() -> Self {
+        Self::new(N)
+    }
+}
+
+/// An iterator over the changes of the contacts in a frame, ordered by slot.
+///
+/// When a slot changes from one contact to another within a frame, the end of the old contact comes before the
+/// beginning of the new contact.
+#[derive(Debug)]
+pub struct TouchChanges<'a, const N: usize> {
+    previous: [MtSlot; N],
+    current: &'a [MtSlot],
+    slot: usize,
+    ended: bool,
+}
+
+impl<const N: usize> Iterator for TouchChanges<'_, N> {
+    type Item = TouchChange;
+
+    fn next(&mut self) -> Option<TouchChange> {
+        while let Some(current) = self.current.get(self.slot) {
+            let slot = self.slot;
+            let previous = &self.previous[slot];
+
+            let change = |phase, state| TouchChange {
+                phase,
+                touch: Touch::new(slot, state),
+            };
+
+            if previous.is_active()
+                && previous.tracking_id() != current.tracking_id()
+                && !self.ended
+            {
+                self.ended = true;
+                return Some(change(TouchPhase::End, previous));
+            }
+
+            self.slot += 1;
+            self.ended = false;
+
+            if !current.is_active() {
+                continue;
+            }
+
+            if previous.tracking_id() != current.tracking_id() {
+                return Some(change(TouchPhase::Begin, current));
+            }
+
+            if previous != current {
+                return Some(change(TouchPhase::Update, current));
+            }
+        }
+
+        None
+    }
+}
+
+impl<const N: usize> core::iter::FusedIterator for TouchChanges<'_, N> {}
+
+/// Converts a stream using the type A multitouch protocol into a stream using the type B protocol.
+///
+/// Devices using the type A protocol send the axes of every contact in each frame, separating the contacts with
+/// `SYN_MT_REPORT` events, and do not identify the contacts. The converter matches each contact with the nearest
+/// contact of the previous frame to keep its tracking id, and places the contacts in up to `N` slots. Contacts
+/// beyond `N` in a frame are discarded.
+///
+/// Events other than the multitouch axes pass through unchanged.
+#[derive(Debug, Clone)]
+pub struct MtConverter<const N: usize> {
+    slots: [MtSlot; N],
+    emitted: [MtSlot; N],
+    contacts: [MtSlot; N],
+    len: usize,
+    contact: MtSlot,
+    in_contact: bool,
+    selected: i32,
+    next_tracking_id: i32,
+    max_distance: i64,
+}
+
+impl<const N: usize> MtConverter<N> {
+    /// Creates a converter.
+    pub const fn new() -> Self {
+        Self {
+            slots: [MtSlot::EMPTY; N],
+            emitted: [MtSlot::EMPTY; N],
+            contacts: [MtSlot::EMPTY; N],
+            len: 0,
+            contact: MtSlot::EMPTY,
+            in_contact: false,
+            selected: 0,
+            next_tracking_id: 0,
+            max_distance: i64::MAX,
+        }
+    }
+
+    /// Sets the largest distance a contact may move between two frames and keep its tracking id.
+    ///
+    /// A contact further than this from every contact of the previous frame is treated as a new contact. The
+    /// distance is in the units of `ABS_MT_POSITION_X` and `ABS_MT_POSITION_Y`, and is unlimited by default.
+    pub const fn max_distance(mut self, distance: u32) -> Self {
+        self.max_distance = distance as i64 * distance as i64;
+        self
+    }
+
+    /// The slots at the last `SYN_REPORT`.
+    pub const fn slots(&self) -> &[MtSlot; N] {
+        &self.slots
+    }
+
+    /// Converts an event, returning the events of the type B stream.
+    ///
+    /// The multitouch axes are held until the `SYN_REPORT` which ends the frame, when the changes of the slots are
+    /// returned, followed by the `SYN_REPORT`. Changes which are not taken from the iterator are returned again at
+    /// the next `SYN_REPORT`.
+    pub fn push(&mut self, event: &InputEvent) -> ConvertedEvents<'_, N> {
+        let mut trailing = Some(*event);
+        let mut report = false;
+
+        match event.event_code() {
+            EventCode::Abs(axis) if MtSlot::is_slot_axis(axis) => {
+                self.contact.set(axis, event.value);
+                self.in_contact = true;
+                trailing = None;
+            }
+
+            EventCode::Syn(SynchronizationEvent::MT_REPORT) => {
+                self.end_contact();
+                trailing = None;
+            }
+
+            _ if event.is_report() => {
+                // Drivers are expected to end the last contact with SYN_MT_REPORT, but accept a frame without it.
+                self.end_contact();
+                self.match_contacts();
+                report = true;
+            }
+
+            _ => {}
+        }
+
+        ConvertedEvents {
+            slot: if report { 0 } else { N },
+            step: 0,
+            current: &self.slots,
+            emitted: &mut self.emitted,
+            selected: &mut self.selected,
+            time: event.time,
+            trailing,
+        }
+    }
+
+    fn end_contact(&mut self) {
+        if self.in_contact && self.len < N {
+            self.contacts[self.len] = self.contact;
+            self.len += 1;
+        }
+
+        self.contact = MtSlot::EMPTY;
+        self.in_contact = false;
+    }
+
+    fn match_contacts(&mut self) {
+        let previous = self.slots;
+        let mut matched = [false; N];
+        let mut taken = [false; N];
+
+        // A slot which becomes empty keeps the values of its last contact, as a client of the type B stream does, so
+        // the next contact in the slot is compared with them.
+        for slot in &mut self.slots {
+            slot.set(AbsoluteAxis::MT_TRACKING_ID, -1);
+        }
+
+        // Repeatedly match the closest pair of a previous contact and a new contact.
+        loop {
+            let mut closest: Option<(i64, usize, usize)> = None;
+
+            for (slot, old) in previous.iter().enumerate() {
+                if !old.is_active() || taken[slot] {
+                    continue;
+                }
+
+                for (index, new) in self.contacts[..self.len].iter().enumerate() {
+                    let distance = distance(old, new);
+
+                    if !matched[index]
+                        && distance <= self.max_distance
+                        && closest.is_none_or(|(closest, ..)| distance < closest)
+                    {
+                        closest = Some((distance, slot, index));
+                    }
+                }
+            }
+
+            let (_, slot, index) = match closest {
+                Some(closest) => closest,
+                None => break,
+            };
+
+            matched[index] = true;
+            taken[slot] = true;
+            self.slots[slot] = self.contacts[index];
+            self.slots[slot].set(AbsoluteAxis::MT_TRACKING_ID, previous[slot].tracking_id());
+        }
+
+        // The remaining contacts are new. Slots which were empty are used first, so a slot does not change from one
+        // contact to another unless every slot is in use.
+        for (contact, _) in self.contacts[..self.len]
+            .iter()
+            .zip(matched)
+            .filter(|(_, matched)| !matched)
+        {
+            let slot = (0..N)
+                .find(|&slot| !taken[slot] && !previous[slot].is_active())
+                .or_else(|| (0..N).find(|&slot| !taken[slot]));
+
+            if let Some(slot) = slot {
+                taken[slot] = true;
+                self.slots[slot] = *contact;
+                self.slots[slot].set(AbsoluteAxis::MT_TRACKING_ID, self.next_tracking_id);
+
+                // The kernel limits tracking ids to 16 bits.
+                self.next_tracking_id = (self.next_tracking_id + 1) & 0xffff;
+            }
+        }
+
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Default for MtConverter<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn distance(a: &MtSlot, b: &MtSlot) -> i64 {
+    let delta = |axis| {
+        let delta = a.get(axis).unwrap_or_default() as i64 - b.get(axis).unwrap_or_default() as i64;
+        delta * delta
+    };
+
+    delta(AbsoluteAxis::MT_POSITION_X).saturating_add(delta(AbsoluteAxis::MT_POSITION_Y))
+}
+
+/// An iterator over the events of the type B stream, returned by [`MtConverter::push`].
+#[derive(Debug)]
+pub struct ConvertedEvents<'a, const N: usize> {
+    current: &'a [MtSlot; N],
+    // The slots and the selected slot as described by the events which were taken from the iterator.
+    emitted: &'a mut [MtSlot; N],
+    selected: &'a mut i32,
+    slot: usize,
+    step: usize,
+    time: EventTime,
+    trailing: Option<InputEvent>,
+}
+
+impl<const N: usize> Iterator for ConvertedEvents<'_, N> {
+    type Item = InputEvent;
+
+    fn next(&mut self) -> Option<InputEvent> {
+        while self.slot < N {
+            let (emitted, current) = (&mut self.emitted[self.slot], &self.current[self.slot]);
+
+            // Only the tracking id of a slot which became empty is reported.
+            let steps = if current.is_active() { MtSlot::AXES } else { 1 };
+
+            if self.step >= steps {
+                self.slot += 1;
+                self.step = 0;
+                continue;
+            }
+
+            let axis = MtSlot::axis_in_order(self.step);
+            let value = current.get(axis).unwrap_or_default();
+
+            if emitted.get(axis) == Some(value) {
+                self.step += 1;
+                continue;
+            }
+
+            if *self.selected != self.slot as i32 {
+                *self.selected = self.slot as i32;
+                return Some(InputEvent::new(
+                    self.time,
+                    EventCode::Abs(AbsoluteAxis::MT_SLOT),
+                    self.slot as i32,
+                ));
+            }
+
+            emitted.set(axis, value);
+            self.step += 1;
+            return Some(InputEvent::new(self.time, EventCode::Abs(axis), value));
+        }
+
+        self.trailing.take()
+    }
+}
+
+impl<const N: usize> core::iter::FusedIterator for ConvertedEvents<'_, N> {}
+
+#[cfg(test)]
+mod test {
+    use std::vec::Vec;
+
+    use crate::{
+        AbsoluteAxis, Button, EventCode, EventTime, InputAbsInfo, InputEvent, KeyCode, MtConverter,
+        MtTracker, SynchronizationEvent, Touch, TouchChange, TouchPhase,
+    };
+
+    fn frame<const N: usize>(
+        tracker: &mut MtTracker<N>,
+        events: &[(AbsoluteAxis, i32)],
+    ) -> Vec<(TouchPhase, usize, i32, i32, i32)> {
+        for &(axis, value) in events {
+            let event = InputEvent::new(EventTime::default(), axis.into(), value);
+            assert!(tracker.push(&event).is_none());
+        }
+
+        let report = InputEvent::new(
+            EventTime::default(),
+            EventCode::Syn(SynchronizationEvent::REPORT),
+            0,
+        );
+
+        tracker
+            .push(&report)
+            .unwrap()
+            .map(|TouchChange { phase, touch }| {
+                (phase, touch.slot, touch.tracking_id, touch.x, touch.y)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn transitions() {
+        use AbsoluteAxis as Abs;
+        use TouchPhase::*;
+
+        let mut tracker = MtTracker::<4>::from_abs_info(&InputAbsInfo {
+            maximum: 1,
+            ..Default::default()
+        });
+        assert_eq!(tracker.slots(), 2);
+
+        // Two fingers touch down.
+        assert_eq!(
+            frame(
+                &mut tracker,
+                &[
+                    (Abs::MT_TRACKING_ID, 10),
+                    (Abs::MT_POSITION_X, 100),
+                    (Abs::MT_POSITION_Y, 200),
+                    (Abs::MT_SLOT, 1),
+                    (Abs::MT_TRACKING_ID, 11),
+                    (Abs::MT_POSITION_X, 300),
+                    (Abs::MT_PRESSURE, 40),
+                ],
+            ),
+            [(Begin, 0, 10, 100, 200), (Begin, 1, 11, 300, 0)]
+        );
+        assert_eq!(
+            tracker.touches().collect::<Vec<_>>()[1],
+            Touch {
+                slot: 1,
+                tracking_id: 11,
+                x: 300,
+                y: 0,
+                pressure: 40,
+                touch_major: 0,
+                tool_type: 0,
+            }
+        );
+
+        // The second finger moves, and a frame without changes reports nothing.
+        assert_eq!(
+            frame(&mut tracker, &[(Abs::MT_POSITION_Y, 50)]),
+            [(Update, 1, 11, 300, 50)]
+        );
+        assert_eq!(frame(&mut tracker, &[]), []);
+
+        // The first finger lifts and another finger lands in its slot within the same frame.
+        assert_eq!(
+            frame(
+                &mut tracker,
+                &[
+                    (Abs::MT_SLOT, 0),
+                    (Abs::MT_TRACKING_ID, -1),
+                    (Abs::MT_TRACKING_ID, 12),
+                    (Abs::MT_POSITION_X, 150),
+                ],
+            ),
+            [(End, 0, 10, 100, 200), (Begin, 0, 12, 150, 200)]
+        );
+
+        // Both fingers lift, and events for slots beyond the number of slots are ignored.
+        assert_eq!(
+            frame(
+                &mut tracker,
+                &[
+                    (Abs::MT_TRACKING_ID, -1),
+                    (Abs::MT_SLOT, 1),
+                    (Abs::MT_TRACKING_ID, -1),
+                    (Abs::MT_SLOT, 3),
+                    (Abs::MT_TRACKING_ID, 13),
+                ],
+            ),
+            [(End, 0, 12, 150, 200), (End, 1, 11, 300, 50)]
+        );
+        assert_eq!(tracker.touches().count(), 0);
+        assert_eq!(tracker.current_slot(), 3);
+    }
+
+    #[test]
+    fn protocol_a() {
+        use AbsoluteAxis as Abs;
+
+        fn event(code: impl Into<EventCode>, value: i32) -> InputEvent {
+            InputEvent::new(EventTime::default(), code.into(), value)
+        }
+
+        fn convert<const N: usize>(
+            converter: &mut MtConverter<N>,
+            contacts: &[(i32, i32)],
+        ) -> Vec<InputEvent> {
+            let mut frame = Vec::new();
+
+            for &(x, y) in contacts {
+                frame.push(event(Abs::MT_POSITION_X, x));
+                frame.push(event(Abs::MT_POSITION_Y, y));
+                frame.push(event(SynchronizationEvent::MT_REPORT, 0));
+            }
+
+            frame.push(event(
+                KeyCode::Button(Button::TOUCH),
+                !contacts.is_empty() as i32,
+            ));
+            frame.push(event(SynchronizationEvent::REPORT, 0));
+
+            frame
+                .iter()
+                .flat_map(|event| converter.push(event).collect::<Vec<_>>())
+                .collect()
+        }
+
+        let touch = |value| event(KeyCode::Button(Button::TOUCH), value);
+        let report = event(SynchronizationEvent::REPORT, 0);
+        let mut converter = MtConverter::<3>::new().max_distance(50);
+
+        // Recorded frames of a device using the type A protocol, with two fingers which move, swap their order in
+        // the frame and lift one at a time.
+        assert_eq!(
+            convert(&mut converter, &[(100, 100), (500, 500)]),
+            [
+                touch(1),
+                event(Abs::MT_TRACKING_ID, 0),
+                event(Abs::MT_POSITION_X, 100),
+                event(Abs::MT_POSITION_Y, 100),
+                event(Abs::MT_SLOT, 1),
+                event(Abs::MT_TRACKING_ID, 1),
+                event(Abs::MT_POSITION_X, 500),
+                event(Abs::MT_POSITION_Y, 500),
+                report,
+            ]
+        );
+
+        assert_eq!(
+            convert(&mut converter, &[(510, 505), (110, 100)]),
+            [
+                touch(1),
+                event(Abs::MT_SLOT, 0),
+                event(Abs::MT_POSITION_X, 110),
+                event(Abs::MT_SLOT, 1),
+                event(Abs::MT_POSITION_X, 510),
+                event(Abs::MT_POSITION_Y, 505),
+                report,
+            ]
+        );
+
+        // The first finger lifts, and a contact far from the second finger is a new contact.
+        assert_eq!(
+            convert(&mut converter, &[(900, 900), (515, 505)]),
+            [
+                touch(1),
+                event(Abs::MT_SLOT, 0),
+                event(Abs::MT_TRACKING_ID, -1),
+                event(Abs::MT_SLOT, 1),
+                event(Abs::MT_POSITION_X, 515),
+                event(Abs::MT_SLOT, 2),
+                event(Abs::MT_TRACKING_ID, 2),
+                event(Abs::MT_POSITION_X, 900),
+                event(Abs::MT_POSITION_Y, 900),
+                report,
+            ]
+        );
+        assert_eq!(
+            converter
+                .slots()
+                .iter()
+                .map(|slot| slot.tracking_id())
+                .collect::<Vec<_>>(),
+            [-1, 1, 2]
+        );
+
+        assert_eq!(
+            convert(&mut converter, &[]),
+            [
+                touch(0),
+                event(Abs::MT_SLOT, 1),
+                event(Abs::MT_TRACKING_ID, -1),
+                event(Abs::MT_SLOT, 2),
+                event(Abs::MT_TRACKING_ID, -1),
+                report,
+            ]
+        );
+
+        // A contact at the left edge reuses the first slot, which still holds the position of the first finger.
+        assert_eq!(
+            convert(&mut converter, &[(0, 100)]),
+            [
+                touch(1),
+                event(Abs::MT_SLOT, 0),
+                event(Abs::MT_TRACKING_ID, 3),
+                event(Abs::MT_POSITION_X, 0),
+                report,
+            ]
+        );
+
+        // Changes which are not taken before the iterator is dropped are returned at the next report, after
+        // selecting the slot again.
+        for &(x, y) in &[(10, 100), (600, 600)] {
+            assert_eq!(converter.push(&event(Abs::MT_POSITION_X, x)).count(), 0);
+            assert_eq!(converter.push(&event(Abs::MT_POSITION_Y, y)).count(), 0);
+            assert_eq!(
+                converter
+                    .push(&event(SynchronizationEvent::MT_REPORT, 0))
+                    .count(),
+                0
+            );
+        }
+
+        assert_eq!(
+            converter.push(&report).take(2).collect::<Vec<_>>(),
+            [event(Abs::MT_POSITION_X, 10), event(Abs::MT_SLOT, 1)]
+        );
+        assert_eq!(
+            convert(&mut converter, &[(20, 100), (600, 600)]),
+            [
+                touch(1),
+                event(Abs::MT_SLOT, 0),
+                event(Abs::MT_POSITION_X, 20),
+                event(Abs::MT_SLOT, 1),
+                event(Abs::MT_TRACKING_ID, 4),
+                event(Abs::MT_POSITION_X, 600),
+                event(Abs::MT_POSITION_Y, 600),
+                report,
+            ]
+        );
+    }
+}
@@ -0,0 +1,56 @@
+//! Perfect hash tables used to look up codes by name.
+//!
+//! The tables are built by the generator. The hash function must be kept identical to the one used in the
+//! generator.
+
+/// A minimal perfect hash table mapping names to codes.
+pub(crate) struct NameMap<T: 'static> {
+    /// The seed used to hash the keys of each bucket.
+    pub seeds: &'static [u32],
+
+    /// The entries of the table, placed in the slot their name hashes to.
+    pub entries: &'static [(&'static str, T)],
+}
+
+impl<T: Copy> NameMap<T> {
+    pub fn get(&self, name: &str, ignore_case: bool) -> Option<T> {
+        let bytes = name.as_bytes();
+        let bucket = hash(bytes, 0) as usize % self.seeds.len();
+        let slot = hash(bytes, self.seeds[bucket]) as usize % self.entries.len();
+        let (entry, value) = self.entries[slot];
+
+        let matches = if ignore_case {
+            entry.eq_ignore_ascii_case(name)
+        } else {
+            entry == name
+        };
+
+        if matches {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+/// FNV-1a with the seed mixed into the offset basis, followed by the MurmurHash3 finalizer.
+///
+/// ASCII letters are hashed as if they were uppercase so the same table may be used for case insensitive
+/// lookups.
+const fn hash(bytes: &[u8], seed: u32) -> u32 {
+    let mut hash = 0x811c_9dc5 ^ seed;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        hash ^= bytes[index].to_ascii_uppercase() as u32;
+        hash = hash.wrapping_mul(0x0100_0193);
+        index += 1;
+    }
+
+    hash ^= hash >> 16;
+    hash = hash.wrapping_mul(0x85eb_ca6b);
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(0xc2b2_ae35);
+    hash ^= hash >> 16;
+    hash
+}
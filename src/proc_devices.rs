@@ -0,0 +1,377 @@
+//! A parser for `/proc/bus/input/devices`, which lists the input devices known to the kernel.
+//!
+//! Each device is described by a block of lines separated by an empty line:
+//!
+//! ```text
+//! I: Bus=0011 Vendor=0001 Product=0001 Version=ab83
+//! N: Name="AT Translated Set 2 keyboard"
+//! P: Phys=isa0060/serio0/input0
+//! S: Sysfs=/devices/platform/i8042/serio0/input/input3
+//! U: Uniq=
+//! H: Handlers=sysrq kbd leds event3
+//! B: PROP=0
+//! B: EV=120013
+//! B: KEY=402000000 3803078f800d001 feffffdfffefffff fffffffffffffffe
+//! B: MSC=10
+//! B: LED=7
+//! ```
+//!
+//! The `B:` lines are bitmaps, printed as hexadecimal `unsigned long` words of the kernel with the most
+//! significant word first.
+
+use crate::{
+    bitset, AbsSet, BusType, Button, EventTypeSet, ForceFeedbackSet, InputId, Key, KeyCode, KeySet,
+    LedSet, MiscSet, ParseDevicesError, PropertySet, RelSet, SoundSet, SwitchSet,
+};
+
+/// A device listed in `/proc/bus/input/devices`.
+///
+/// The strings are borrowed from the parsed text. Lines which are missing from the block are left empty.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ProcDevice<'a> {
+    /// The id of the device, from the `I:` line.
+    pub id: InputId,
+
+    /// The name of the device, from the `N:` line, without the quotes.
+    pub name: &'a str,
+
+    /// The physical location of the device, from the `P:` line.
+    pub phys: &'a str,
+
+    /// The path of the device in sysfs, from the `S:` line.
+    pub sysfs: &'a str,
+
+    /// The unique identifier of the device, from the `U:` line.
+    pub uniq: &'a str,
+
+    /// The handlers bound to the device, such as `kbd event3`, from the `H:` line.
+    pub handlers: &'a str,
+
+    /// The properties of the device, from the `B: PROP=` line.
+    pub properties: PropertySet,
+
+    /// The event types the device supports, from the `B: EV=` line.
+    pub event_types: EventTypeSet,
+
+    /// The keys and buttons the device supports, from the `B: KEY=` line.
+    pub key_codes: KeySet,
+
+    /// The relative axes the device supports, from the `B: REL=` line.
+    pub relative_axes: RelSet,
+
+    /// The absolute axes the device supports, from the `B: ABS=` line.
+    pub absolute_axes: AbsSet,
+
+    /// The miscellaneous events the device supports, from the `B: MSC=` line.
+    pub misc_events: MiscSet,
+
+    /// The LEDs of the device, from the `B: LED=` line.
+    pub leds: LedSet,
+
+    /// The sounds the device supports, from the `B: SND=` line.
+    pub sounds: SoundSet,
+
+    /// The force feedback effects the device supports, from the `B: FF=` line.
+    pub force_feedback: ForceFeedbackSet,
+
+    /// The switches the device supports, from the `B: SW=` line.
+    pub switches: SwitchSet,
+}
+
+impl<'a> ProcDevice<'a> {
+    /// Returns an iterator over the handlers bound to the device, such as `kbd` and `event3`.
+    pub fn handlers(&self) -> impl Iterator<Item = &'a str> {
+        self.handlers.split_whitespace()
+    }
+
+    /// Returns the name of the event device of the device, such as `event3`.
+    pub fn event_handler(&self) -> Option<&'a str> {
+        self.handlers().find(|handler| {
+            handler
+                .strip_prefix("event")
+                .is_some_and(|number| number.bytes().all(|byte| byte.is_ascii_digit()))
+        })
+    }
+
+    /// Returns an iterator over the keys the device supports, excluding buttons.
+    pub fn keys(&self) -> impl Iterator<Item = Key> + '_ {
+        self.key_codes.iter().filter_map(|code| match code {
+            KeyCode::Key(key) => Some(key),
+            KeyCode::Button(_) => None,
+        })
+    }
+
+    /// Returns an iterator over the buttons the device supports.
+    pub fn buttons(&self) -> impl Iterator<Item = Button> + '_ {
+        self.key_codes.iter().filter_map(|code| match code {
+            KeyCode::Key(_) => None,
+            KeyCode::Button(button) => Some(button),
+        })
+    }
+}
+
+/// An iterator over the devices listed in the text of `/proc/bus/input/devices`.
+#[derive(Debug, Clone)]
+pub struct ProcDevices<'a> {
+    lines: core::iter::Enumerate<core::str::Lines<'a>>,
+    word_bits: usize,
+}
+
+impl<'a> ProcDevices<'a> {
+    /// Parses the text of `/proc/bus/input/devices`, written by a kernel with the same word size as the target.
+    pub fn new(text: &'a str) -> Self {
+        Self::with_word_bits(text, usize::BITS as usize)
+    }
+
+    /// Parses the text of `/proc/bus/input/devices`, written by a kernel whose `unsigned long` has the number of
+    /// bits.
+    ///
+    /// This is needed to read the bitmaps written by a 64-bit kernel on a 32-bit target, or the reverse.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of bits is not 32 or 64.
+    pub fn with_word_bits(text: &'a str, word_bits: usize) -> Self {
+        assert!(
+            word_bits == 32 || word_bits == 64,
+            "word size must be 32 or 64 bits"
+        );
+
+        Self {
+            lines: text.lines().enumerate(),
+            word_bits,
+        }
+    }
+
+    fn parse_line(
+        device: &mut ProcDevice<'a>,
+        line: &'a str,
+        word_bits: usize,
+    ) -> Result<(), &'static str> {
+        let (kind, content) = line.split_once(": ").ok_or("missing line type")?;
+
+        match kind {
+            "I" => device.id = parse_id(content).ok_or("invalid id")?,
+            "N" => device.name = parse_value(content, "Name=").ok_or("invalid name")?,
+            "P" => device.phys = parse_value(content, "Phys=").ok_or("invalid phys")?,
+            "S" => device.sysfs = parse_value(content, "Sysfs=").ok_or("invalid sysfs path")?,
+            "U" => device.uniq = parse_value(content, "Uniq=").ok_or("invalid uniq")?,
+            "H" => {
+                device.handlers = parse_value(content, "Handlers=")
+                    .ok_or("invalid handlers")?
+                    .trim_end()
+            }
+
+            "B" => {
+                let (category, words) = content.split_once('=').ok_or("invalid bitmap")?;
+
+                let bytes = match category {
+                    "PROP" => device.properties.as_mut(),
+                    "EV" => device.event_types.as_mut(),
+                    "KEY" => device.key_codes.as_mut(),
+                    "REL" => device.relative_axes.as_mut(),
+                    "ABS" => device.absolute_axes.as_mut(),
+                    "MSC" => device.misc_events.as_mut(),
+                    "LED" => device.leds.as_mut(),
+                    "SND" => device.sounds.as_mut(),
+                    "FF" => device.force_feedback.as_mut(),
+                    "SW" => device.switches.as_mut(),
+                    // Bitmaps added by newer kernels are skipped.
+                    _ => return Ok(()),
+                };
+
+                bitset::decode_hex_words(words, word_bits, bytes).ok_or("invalid bitmap")?;
+            }
+
+            // Lines added by newer kernels are skipped.
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Iterator for ProcDevices<'a> {
+    type Item = Result<ProcDevice<'a>, ParseDevicesError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut device = None;
+
+        while let Some((index, line)) = self.lines.next() {
+            if line.trim().is_empty() {
+                if device.is_some() {
+                    break;
+                }
+
+                continue;
+            }
+
+            let device = device.get_or_insert_with(ProcDevice::default);
+
+            if let Err(reason) = Self::parse_line(device, line, self.word_bits) {
+                // Skip the rest of the block, so the next device may still be parsed.
+                for (_, line) in self.lines.by_ref() {
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                }
+
+                return Some(Err(ParseDevicesError::new(index + 1, reason)));
+            }
+        }
+
+        device.map(Ok)
+    }
+}
+
+/// Parses `Bus=0011 Vendor=0001 Product=0001 Version=ab83`.
+fn parse_id(content: &str) -> Option<InputId> {
+    let mut id = InputId::default();
+
+    for field in content.split_whitespace() {
+        let (name, value) = field.split_once('=')?;
+        let value = u16::from_str_radix(value, 16).ok()?;
+
+        match name {
+            "Bus" => id.bustype = BusType::new(value),
+            "Vendor" => id.vendor = value,
+            "Product" => id.product = value,
+            "Version" => id.version = value,
+            _ => {}
+        }
+    }
+
+    Some(id)
+}
+
+/// Parses `Name="value"`, removing the quotes if present.
+fn parse_value<'a>(content: &'a str, prefix: &str) -> Option<&'a str> {
+    let value = content.strip_prefix(prefix)?;
+
+    Some(
+        value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+            .unwrap_or(value),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::vec::Vec;
+
+    use crate::{
+        AbsoluteAxis, BusType, Button, EventType, InputQuirk, Key, ProcDevices, RelativeAxis,
+        SwitchEvent,
+    };
+
+    const FIXTURE: &str = include_str!("../fixtures/proc_bus_input_devices.txt");
+
+    #[test]
+    fn parse_fixture() {
+        let devices = ProcDevices::with_word_bits(FIXTURE, 64)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(devices.len(), 4);
+
+        let lid = &devices[0];
+        assert_eq!(lid.name, "Lid Switch");
+        assert_eq!(lid.id.bustype, BusType::HOST);
+        assert_eq!(lid.event_handler(), Some("event0"));
+        assert_eq!(
+            lid.event_types.iter().collect::<Vec<_>>(),
+            [EventType::SYN, EventType::SW]
+        );
+        assert_eq!(lid.switches.iter().collect::<Vec<_>>(), [SwitchEvent::LID]);
+
+        let keyboard = &devices[1];
+        assert_eq!(keyboard.name, "AT Translated Set 2 keyboard");
+        assert_eq!(keyboard.phys, "isa0060/serio0/input0");
+        assert_eq!(keyboard.uniq, "");
+        assert_eq!(
+            keyboard.handlers().collect::<Vec<_>>(),
+            ["sysrq", "kbd", "leds", "event3"]
+        );
+        assert_eq!((keyboard.id.vendor, keyboard.id.version), (0x0001, 0xab83));
+        assert_eq!(keyboard.keys().next(), Some(Key::ESC));
+        assert!(keyboard.key_codes.contains(Key::A.into()));
+        assert!(keyboard.key_codes.contains(Key::SLEEP.into()));
+        assert_eq!(keyboard.buttons().count(), 0);
+        assert_eq!(keyboard.leds.len(), 3);
+
+        let touchpad = &devices[2];
+        assert_eq!(touchpad.id.bustype, BusType::I2C);
+        assert_eq!(
+            touchpad.properties.iter().collect::<Vec<_>>(),
+            [InputQuirk::PROP_POINTER, InputQuirk::PROP_BUTTONPAD]
+        );
+        assert_eq!(
+            touchpad.buttons().collect::<Vec<_>>(),
+            [
+                Button::LEFT,
+                Button::TOOL_FINGER,
+                Button::TOOL_QUINTTAP,
+                Button::TOUCH,
+                Button::TOOL_DOUBLETAP,
+                Button::TOOL_TRIPLETAP,
+                Button::TOOL_QUADTAP,
+            ]
+        );
+        assert_eq!(
+            touchpad.absolute_axes.iter().collect::<Vec<_>>(),
+            [
+                AbsoluteAxis::X,
+                AbsoluteAxis::Y,
+                AbsoluteAxis::MT_SLOT,
+                AbsoluteAxis::MT_POSITION_X,
+                AbsoluteAxis::MT_POSITION_Y,
+                AbsoluteAxis::MT_TOOL_TYPE,
+                AbsoluteAxis::MT_TRACKING_ID,
+            ]
+        );
+
+        let mouse = &devices[3];
+        assert_eq!(mouse.id.bustype, BusType::USB);
+        assert_eq!(mouse.buttons().count(), 16);
+        assert_eq!(
+            mouse.relative_axes.iter().collect::<Vec<_>>(),
+            [
+                RelativeAxis::X,
+                RelativeAxis::Y,
+                RelativeAxis::HWHEEL,
+                RelativeAxis::WHEEL,
+                RelativeAxis::WHEEL_HI_RES,
+                RelativeAxis::HWHEEL_HI_RES,
+            ]
+        );
+    }
+
+    #[test]
+    fn word_size() {
+        // A 32-bit kernel prints the same bitmap as twice as many words.
+        let text = "B: KEY=ffff0000 0 0 0 0 0 0 0 0\n";
+        let device = ProcDevices::with_word_bits(text, 32)
+            .next()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(device.buttons().next(), Some(Button::LEFT));
+        assert_eq!(device.buttons().count(), 16);
+
+        // Words larger than the word size are rejected.
+        let mut devices = ProcDevices::with_word_bits("B: KEY=1ffffffff\n", 32);
+        assert!(devices.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn invalid_block() {
+        let text = "I: Bus=0003 Vendor=zzzz\nN: Name=\"Broken\"\n\nN: Name=\"Working\"\n";
+        let mut devices = ProcDevices::new(text);
+
+        let error = devices.next().unwrap().unwrap_err();
+        assert_eq!(error.line(), 1);
+        assert_eq!(devices.next().unwrap().unwrap().name, "Working");
+        assert!(devices.next().is_none());
+    }
+}
@@ -0,0 +1,586 @@
+//! Resynchronization after the kernel drops events.
+//!
+//! When the buffer of an evdev client overflows, the kernel discards events and sends a `SYN_DROPPED` event. The
+//! client must then discard every event up to and including the next `SYN_REPORT`, query the state of the device
+//! and compare it with the state it knew, since the dropped events may have pressed keys or moved contacts.
+//!
+//! [`SyncFilter`] implements these steps. It tracks the state of the device from the events which pass through
+//! it, and once given the queried state, produces the events which bring the tracked state up to date.
+
+use core::convert::TryFrom;
+
+use crate::{
+    AbsoluteAxis, EventCode, EventTime, InputEvent, Key, KeyCode, KeySet, Led, LedSet, MtSlot,
+    SwitchEvent, SwitchSet, SynchronizationEvent,
+};
+
+/// The state of a device which is restored after events are dropped.
+///
+/// `SLOTS` is the number of multitouch slots of the device, which is the maximum of `ABS_MT_SLOT` plus one, or 0
+/// for devices without slots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceState<const SLOTS: usize> {
+    /// The keys and buttons which are pressed, as returned by `EVIOCGKEY`.
+    pub keys: KeySet,
+
+    /// The switches which are set, as returned by `EVIOCGSW`.
+    pub switches: SwitchSet,
+
+    /// The LEDs which are lit, as returned by `EVIOCGLED`.
+    pub leds: LedSet,
+
+    /// The values of the absolute axes, as returned by `EVIOCGABS`.
+    ///
+    /// The value of `ABS_MT_SLOT` is the selected slot. The values of the other multitouch axes are stored in the
+    /// slots instead.
+    pub absolute_axes: [i32; AbsoluteAxis::COUNT],
+
+    /// The multitouch slots, as returned by `EVIOCGMTSLOTS`.
+    pub slots: [MtSlot; SLOTS],
+}
+
+impl<const SLOTS: usize> DeviceState<SLOTS> {
+    /// Creates a state where no key is pressed, every axis is 0 and every slot is empty.
+    pub const fn new() -> Self {
+        Self {
+            keys: KeySet::EMPTY,
+            switches: SwitchSet::EMPTY,
+            leds: LedSet::EMPTY,
+            absolute_axes: [0; AbsoluteAxis::COUNT],
+            slots: [MtSlot::EMPTY; SLOTS],
+        }
+    }
+
+    /// The selected multitouch slot.
+    pub const fn current_slot(&self) -> i32 {
+        self.absolute_axes[AbsoluteAxis::MT_SLOT.into_inner() as usize]
+    }
+
+    /// Updates the state with an event read from the device.
+    pub fn apply(&mut self, event: &InputEvent) {
+        match event.event_code() {
+            // Autorepeat events have a value of 2, so any value other than 0 is a press.
+            EventCode::Key(key) if event.value == 0 => {
+                self.keys.remove(key);
+            }
+            EventCode::Key(key) => {
+                self.keys.insert(key);
+            }
+            EventCode::Sw(switch) if event.value == 0 => {
+                self.switches.remove(switch);
+            }
+            EventCode::Sw(switch) => {
+                self.switches.insert(switch);
+            }
+            EventCode::Led(led) if event.value == 0 => {
+                self.leds.remove(led);
+            }
+            EventCode::Led(led) => {
+                self.leds.insert(led);
+            }
+
+            EventCode::Abs(axis) if MtSlot::is_slot_axis(axis) => {
+                if let Some(slot) = usize::try_from(self.current_slot())
+                    .ok()
+                    .and_then(|slot| self.slots.get_mut(slot))
+                {
+                    slot.set(axis, event.value);
+                }
+            }
+
+            EventCode::Abs(axis) => {
+                if let Some(value) = self.absolute_axes.get_mut(axis.into_inner() as usize) {
+                    *value = event.value;
+                }
+            }
+
+            _ => {}
+        }
+    }
+}
+
+impl<const SLOTS: usize> Default for DeviceState<SLOTS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Synced,
+    Dropping,
+    NeedsResync,
+    DroppingResynced,
+}
+
+/// Filters the events of a device, discarding the events made invalid by a `SYN_DROPPED` event.
+#[derive(Debug, Clone)]
+pub struct SyncFilter<const SLOTS: usize> {
+    state: DeviceState<SLOTS>,
+    status: Status,
+}
+
+impl<const SLOTS: usize> SyncFilter<SLOTS> {
+    /// Creates a filter, starting from the state of the device queried when it was opened.
+    pub const fn new(state: DeviceState<SLOTS>) -> Self {
+        Self {
+            state,
+            status: Status::Synced,
+        }
+    }
+
+    /// The state of the device, as described by the events which passed through the filter.
+    pub const fn state(&self) -> &DeviceState<SLOTS> {
+        &self.state
+    }
+
+    /// Returns whether the filter is discarding the events up to the `SYN_REPORT` following a `SYN_DROPPED`.
+    pub const fn is_dropping(&self) -> bool {
+        matches!(self.status, Status::Dropping | Status::DroppingResynced)
+    }
+
+    /// Returns whether the state of the device must be queried and passed to [`SyncFilter::resync`].
+    ///
+    /// Events are discarded until then.
+    pub const fn needs_resync(&self) -> bool {
+        matches!(self.status, Status::Dropping | Status::NeedsResync)
+    }
+
+    /// Filters an event read from the device.
+    ///
+    /// Returns `true` if the event should be passed on, in which case the tracked state is updated with it.
+    pub fn filter(&mut self, event: &InputEvent) -> bool {
+        // Events may be dropped again while discarding or before resynchronizing, and a state queried before the
+        // drop is then out of date, so the resynchronization starts over.
+        if event.event_code() == EventCode::Syn(SynchronizationEvent::DROPPED) {
+            self.status = Status::Dropping;
+            return false;
+        }
+
+        match self.status {
+            Status::Synced => {
+                self.state.apply(event);
+                true
+            }
+
+            Status::Dropping if event.is_report() => {
+                self.status = Status::NeedsResync;
+                false
+            }
+
+            Status::DroppingResynced if event.is_report() => {
+                self.status = Status::Synced;
+                false
+            }
+
+            Status::Dropping | Status::DroppingResynced | Status::NeedsResync => false,
+        }
+    }
+
+    /// Replaces the tracked state with the state queried from the device, returning the events which describe the
+    /// changes.
+    ///
+    /// The returned events are frames ending with a `SYN_REPORT` event, and are meant to be passed on in place of
+    /// the dropped events. Contacts which ended are released in a frame of their own, before the frame with the
+    /// other changes, so a slot never changes from one contact to another within a frame.
+    ///
+    /// If this is called before the `SYN_REPORT` which follows the `SYN_DROPPED`, the events up to that
+    /// `SYN_REPORT` are still discarded.
+    pub fn resync(&mut self, state: DeviceState<SLOTS>, time: EventTime) -> SyncEvents<'_, SLOTS> {
+        let old = core::mem::replace(&mut self.state, state);
+
+        self.status = match self.status {
+            Status::Dropping | Status::DroppingResynced => Status::DroppingResynced,
+            Status::Synced | Status::NeedsResync => Status::Synced,
+        };
+
+        SyncEvents {
+            slot: old.current_slot(),
+            old,
+            new: &self.state,
+            time,
+            cursor: Cursor::Release(0),
+            released: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cursor {
+    Release(usize),
+    ReleaseReport,
+    Key(usize),
+    Switch(usize),
+    Led(usize),
+    Abs(usize),
+    Slot(usize, usize),
+    RestoreSlot,
+    Report,
+    Done,
+}
+
+/// An iterator over the events which bring the state of a device up to date, returned by [`SyncFilter::resync`].
+#[derive(Debug)]
+pub struct SyncEvents<'a, const SLOTS: usize> {
+    old: DeviceState<SLOTS>,
+    new: &'a DeviceState<SLOTS>,
+    time: EventTime,
+    cursor: Cursor,
+    slot: i32,
+    released: bool,
+}
+
+impl<const SLOTS: usize> SyncEvents<'_, SLOTS> {
+    fn event(&self, code: EventCode, value: i32) -> InputEvent {
+        InputEvent::new(self.time, code, value)
+    }
+
+    /// Selects the slot, returning the `ABS_MT_SLOT` event if another slot was selected.
+    fn select_slot(&mut self, slot: usize) -> Option<InputEvent> {
+        if self.slot == slot as i32 {
+            return None;
+        }
+
+        self.slot = slot as i32;
+        Some(self.event(EventCode::Abs(AbsoluteAxis::MT_SLOT), slot as i32))
+    }
+}
+
+impl<const SLOTS: usize> Iterator for SyncEvents<'_, SLOTS> {
+    type Item = InputEvent;
+
+    fn next(&mut self) -> Option<InputEvent> {
+        loop {
+            match self.cursor {
+                Cursor::Release(slot) if slot < SLOTS => {
+                    let (old, new) = (self.old.slots[slot], self.new.slots[slot]);
+
+                    if !old.is_active() || old.tracking_id() == new.tracking_id() {
+                        self.cursor = Cursor::Release(slot + 1);
+                        continue;
+                    }
+
+                    if let Some(event) = self.select_slot(slot) {
+                        return Some(event);
+                    }
+
+                    // Like the kernel, clients keep the other axes of a slot when its contact ends, so a new contact is
+                    // compared with the values of the old contact.
+                    self.old.slots[slot].set(AbsoluteAxis::MT_TRACKING_ID, -1);
+                    self.released = true;
+                    self.cursor = Cursor::Release(slot + 1);
+                    return Some(self.event(EventCode::Abs(AbsoluteAxis::MT_TRACKING_ID), -1));
+                }
+
+                Cursor::Release(_) => self.cursor = Cursor::ReleaseReport,
+
+                Cursor::ReleaseReport => {
+                    self.cursor = Cursor::Key(0);
+
+                    if self.released {
+                        return Some(self.event(EventCode::Syn(SynchronizationEvent::REPORT), 0));
+                    }
+                }
+
+                Cursor::Key(index) if index < Key::COUNT => {
+                    self.cursor = Cursor::Key(index + 1);
+                    let key = KeyCode::new(index as u32);
+                    let pressed = self.new.keys.contains(key);
+
+                    if pressed != self.old.keys.contains(key) {
+                        return Some(self.event(EventCode::Key(key), pressed as i32));
+                    }
+                }
+
+                Cursor::Key(_) => self.cursor = Cursor::Switch(0),
+
+                Cursor::Switch(index) if index < SwitchEvent::COUNT => {
+                    self.cursor = Cursor::Switch(index + 1);
+                    let switch = SwitchEvent::new(index as u32);
+                    let set = self.new.switches.contains(switch);
+
+                    if set != self.old.switches.contains(switch) {
+                        return Some(self.event(EventCode::Sw(switch), set as i32));
+                    }
+                }
+
+                Cursor::Switch(_) => self.cursor = Cursor::Led(0),
+
+                Cursor::Led(index) if index < Led::COUNT => {
+                    self.cursor = Cursor::Led(index + 1);
+                    let led = Led::new(index as u32);
+                    let lit = self.new.leds.contains(led);
+
+                    if lit != self.old.leds.contains(led) {
+                        return Some(self.event(EventCode::Led(led), lit as i32));
+                    }
+                }
+
+                Cursor::Led(_) => self.cursor = Cursor::Abs(0),
+
+                Cursor::Abs(index) if index < AbsoluteAxis::COUNT => {
+                    self.cursor = Cursor::Abs(index + 1);
+                    let axis = AbsoluteAxis::new(index as u32);
+                    let value = self.new.absolute_axes[index];
+
+                    // The multitouch axes are restored slot by slot.
+                    if axis == AbsoluteAxis::MT_SLOT || MtSlot::is_slot_axis(axis) {
+                        continue;
+                    }
+
+                    if value != self.old.absolute_axes[index] {
+                        return Some(self.event(EventCode::Abs(axis), value));
+                    }
+                }
+
+                Cursor::Abs(_) => self.cursor = Cursor::Slot(0, 0),
+
+                Cursor::Slot(slot, step) if slot < SLOTS && step < MtSlot::AXES => {
+                    // Only the tracking id of a slot which is empty is reported.
+                    if step > 0 && !self.new.slots[slot].is_active() {
+                        self.cursor = Cursor::Slot(slot + 1, 0);
+                        continue;
+                    }
+
+                    let axis = MtSlot::axis_in_order(step);
+                    let value = self.new.slots[slot].get(axis);
+
+                    if value == self.old.slots[slot].get(axis) {
+                        self.cursor = Cursor::Slot(slot, step + 1);
+                        continue;
+                    }
+
+                    if let Some(event) = self.select_slot(slot) {
+                        return Some(event);
+                    }
+
+                    self.cursor = Cursor::Slot(slot, step + 1);
+                    return Some(self.event(EventCode::Abs(axis), value.unwrap_or_default()));
+                }
+
+                Cursor::Slot(slot, _) if slot < SLOTS => self.cursor = Cursor::Slot(slot + 1, 0),
+
+                Cursor::Slot(..) => self.cursor = Cursor::RestoreSlot,
+
+                Cursor::RestoreSlot => {
+                    self.cursor = Cursor::Report;
+
+                    if SLOTS > 0 && self.slot != self.new.current_slot() {
+                        self.slot = self.new.current_slot();
+                        return Some(self.event(EventCode::Abs(AbsoluteAxis::MT_SLOT), self.slot));
+                    }
+                }
+
+                Cursor::Report => {
+                    self.cursor = Cursor::Done;
+                    return Some(self.event(EventCode::Syn(SynchronizationEvent::REPORT), 0));
+                }
+
+                Cursor::Done => return None,
+            }
+        }
+    }
+}
+
+impl<const SLOTS: usize> core::iter::FusedIterator for SyncEvents<'_, SLOTS> {}
+
+#[cfg(test)]
+mod test {
+    use std::vec::Vec;
+
+    use crate::{
+        AbsoluteAxis, DeviceState, EventCode, EventTime, InputEvent, Key, KeyCode, Led, MtSlot,
+        RelativeAxis, SwitchEvent, SyncFilter, SynchronizationEvent,
+    };
+
+    fn event(code: impl Into<EventCode>, value: i32) -> InputEvent {
+        InputEvent::new(EventTime::default(), code.into(), value)
+    }
+
+    fn report() -> InputEvent {
+        event(SynchronizationEvent::REPORT, 0)
+    }
+
+    #[test]
+    fn drop_and_resync() {
+        let mut filter = SyncFilter::<0>::new(DeviceState::new());
+
+        assert!(filter.filter(&event(Key::A, 1)));
+        assert!(filter.filter(&event(AbsoluteAxis::X, 10)));
+        assert!(filter.filter(&report()));
+        assert!(filter.state().keys.contains(Key::A.into()));
+
+        // Everything up to the report following SYN_DROPPED is discarded.
+        assert!(!filter.filter(&event(SynchronizationEvent::DROPPED, 0)));
+        assert!(filter.is_dropping());
+        assert!(!filter.filter(&event(Key::A, 0)));
+        assert!(!filter.filter(&report()));
+        assert!(!filter.is_dropping());
+        assert!(filter.needs_resync());
+        assert!(!filter.filter(&event(RelativeAxis::X, 1)));
+
+        let mut fresh = DeviceState::new();
+        fresh.keys.insert(Key::B.into());
+        fresh.switches.insert(SwitchEvent::LID);
+        fresh.leds.insert(Led::CAPSL);
+        fresh.absolute_axes[AbsoluteAxis::X.into_inner() as usize] = 10;
+        fresh.absolute_axes[AbsoluteAxis::Y.into_inner() as usize] = 20;
+
+        let events = filter
+            .resync(fresh.clone(), EventTime::default())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            events,
+            [
+                event(Key::A, 0),
+                event(Key::B, 1),
+                event(SwitchEvent::LID, 1),
+                event(Led::CAPSL, 1),
+                event(AbsoluteAxis::Y, 20),
+                report(),
+            ]
+        );
+        assert_eq!(filter.state(), &fresh);
+        assert!(!filter.needs_resync());
+        assert!(filter.filter(&event(RelativeAxis::X, 1)));
+
+        // Resynchronizing with the same state only ends the frame.
+        assert_eq!(
+            filter
+                .resync(fresh, EventTime::default())
+                .collect::<Vec<_>>(),
+            [report()]
+        );
+    }
+
+    #[test]
+    fn resync_before_report() {
+        let mut filter = SyncFilter::<0>::new(DeviceState::new());
+
+        assert!(!filter.filter(&event(SynchronizationEvent::DROPPED, 0)));
+        assert_eq!(
+            filter
+                .resync(DeviceState::new(), EventTime::default())
+                .count(),
+            1
+        );
+
+        // The rest of the dropped frame is still discarded.
+        assert!(!filter.filter(&event(Key::A, 1)));
+        assert!(!filter.filter(&report()));
+        assert!(filter.filter(&event(Key::A, 1)));
+    }
+
+    #[test]
+    fn dropped_again() {
+        let mut filter = SyncFilter::<0>::new(DeviceState::new());
+
+        // A SYN_DROPPED while discarding the dropped frame extends it to the next report.
+        assert!(!filter.filter(&event(SynchronizationEvent::DROPPED, 0)));
+        assert!(!filter.filter(&event(SynchronizationEvent::DROPPED, 0)));
+        assert!(!filter.filter(&report()));
+        assert!(!filter.is_dropping());
+        assert!(filter.needs_resync());
+
+        // A SYN_DROPPED before resynchronizing is discarded along with its frame.
+        assert!(!filter.filter(&event(SynchronizationEvent::DROPPED, 0)));
+        assert!(filter.is_dropping());
+        assert!(!filter.filter(&event(Key::A, 1)));
+        assert!(!filter.filter(&report()));
+        assert!(filter.needs_resync());
+
+        // A SYN_DROPPED after resynchronizing but before the end of the dropped frame makes the queried state out
+        // of date, so the state must be queried again.
+        assert!(!filter.filter(&event(SynchronizationEvent::DROPPED, 0)));
+        assert_eq!(
+            filter
+                .resync(DeviceState::new(), EventTime::default())
+                .count(),
+            1
+        );
+        assert!(!filter.needs_resync());
+        assert!(!filter.filter(&event(SynchronizationEvent::DROPPED, 0)));
+        assert!(filter.needs_resync());
+        assert!(!filter.filter(&report()));
+        assert!(!filter.filter(&event(Key::A, 1)));
+        assert!(filter.needs_resync());
+
+        assert_eq!(
+            filter
+                .resync(DeviceState::new(), EventTime::default())
+                .count(),
+            1
+        );
+        assert!(filter.filter(&event(Key::A, 1)));
+    }
+
+    #[test]
+    fn invalid_codes() {
+        let mut filter = SyncFilter::<0>::new(DeviceState::new());
+
+        // Codes past the end of the sets pass through without changing the state.
+        assert!(filter.filter(&event(KeyCode::new(0x300), 1)));
+        assert!(filter.filter(&event(SwitchEvent::new(0x20), 1)));
+        assert!(filter.filter(&event(Led::new(0x10), 1)));
+        assert!(filter.filter(&event(SwitchEvent::new(0x20), 0)));
+        assert_eq!(filter.state(), &DeviceState::new());
+    }
+
+    #[test]
+    fn slots() {
+        let mut state = DeviceState::<3>::new();
+
+        for event in [
+            event(AbsoluteAxis::MT_SLOT, 0),
+            event(AbsoluteAxis::MT_TRACKING_ID, 5),
+            event(AbsoluteAxis::MT_POSITION_X, 100),
+            event(AbsoluteAxis::MT_SLOT, 1),
+            event(AbsoluteAxis::MT_TRACKING_ID, 6),
+            event(AbsoluteAxis::MT_POSITION_X, 200),
+            event(AbsoluteAxis::MT_SLOT, 9),
+            event(AbsoluteAxis::MT_POSITION_X, 300),
+            event(AbsoluteAxis::MT_SLOT, 1),
+        ] {
+            state.apply(&event);
+        }
+
+        assert_eq!(state.slots[0].tracking_id(), 5);
+        assert_eq!(state.slots[1].get(AbsoluteAxis::MT_POSITION_X), Some(200));
+        assert_eq!(state.slots[2], MtSlot::EMPTY);
+        assert_eq!(state.current_slot(), 1);
+
+        let mut filter = SyncFilter::new(state);
+        assert!(!filter.filter(&event(SynchronizationEvent::DROPPED, 0)));
+        assert!(!filter.filter(&report()));
+
+        // Slot 0 has a new contact, the contact in slot 1 ended and slot 2 has a new contact. The new contact in
+        // slot 0 is compared with the values of the old contact, so its position of 0 is still reported.
+        let mut fresh = DeviceState::<3>::new();
+        fresh.absolute_axes[AbsoluteAxis::MT_SLOT.into_inner() as usize] = 2;
+        fresh.slots[0].set(AbsoluteAxis::MT_TRACKING_ID, 7);
+        fresh.slots[0].set(AbsoluteAxis::MT_POSITION_X, 0);
+        fresh.slots[2].set(AbsoluteAxis::MT_TRACKING_ID, 8);
+        fresh.slots[2].set(AbsoluteAxis::MT_POSITION_Y, 50);
+
+        assert_eq!(
+            filter
+                .resync(fresh, EventTime::default())
+                .collect::<Vec<_>>(),
+            [
+                event(AbsoluteAxis::MT_SLOT, 0),
+                event(AbsoluteAxis::MT_TRACKING_ID, -1),
+                event(AbsoluteAxis::MT_SLOT, 1),
+                event(AbsoluteAxis::MT_TRACKING_ID, -1),
+                report(),
+                event(AbsoluteAxis::MT_SLOT, 0),
+                event(AbsoluteAxis::MT_TRACKING_ID, 7),
+                event(AbsoluteAxis::MT_POSITION_X, 0),
+                event(AbsoluteAxis::MT_SLOT, 2),
+                event(AbsoluteAxis::MT_TRACKING_ID, 8),
+                event(AbsoluteAxis::MT_POSITION_Y, 50),
+                report(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,197 @@
+/* automatically generated by rust-bindgen 0.59.2 */
+
+pub type __u8 = ::core::ffi::c_uchar;
+pub type __s16 = ::core::ffi::c_short;
+pub type __u16 = ::core::ffi::c_ushort;
+pub type __s32 = ::core::ffi::c_int;
+pub type __u32 = ::core::ffi::c_uint;
+pub type __u64 = ::core::ffi::c_ulonglong;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct input_id {
+    pub bustype: __u16,
+    pub vendor: __u16,
+    pub product: __u16,
+    pub version: __u16,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct input_absinfo {
+    pub value: __s32,
+    pub minimum: __s32,
+    pub maximum: __s32,
+    pub fuzz: __s32,
+    pub flat: __s32,
+    pub resolution: __s32,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct input_keymap_entry {
+    pub flags: __u8,
+    pub len: __u8,
+    pub index: __u16,
+    pub keycode: __u32,
+    pub scancode: [__u8; 32usize],
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct input_mask {
+    pub type_: __u32,
+    pub codes_size: __u32,
+    pub codes_ptr: __u64,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ff_replay {
+    pub length: __u16,
+    pub delay: __u16,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ff_trigger {
+    pub button: __u16,
+    pub interval: __u16,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ff_envelope {
+    pub attack_length: __u16,
+    pub attack_level: __u16,
+    pub fade_length: __u16,
+    pub fade_level: __u16,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ff_constant_effect {
+    pub level: __s16,
+    pub envelope: ff_envelope,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ff_ramp_effect {
+    pub start_level: __s16,
+    pub end_level: __s16,
+    pub envelope: ff_envelope,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ff_condition_effect {
+    pub right_saturation: __u16,
+    pub left_saturation: __u16,
+    pub right_coeff: __s16,
+    pub left_coeff: __s16,
+    pub deadband: __u16,
+    pub center: __s16,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ff_periodic_effect {
+    pub waveform: __u16,
+    pub period: __u16,
+    pub magnitude: __s16,
+    pub offset: __s16,
+    pub phase: __u16,
+    pub envelope: ff_envelope,
+    pub custom_len: __u32,
+    pub custom_data: *mut __s16,
+}
+impl Default for ff_periodic_effect {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ff_rumble_effect {
+    pub strong_magnitude: __u16,
+    pub weak_magnitude: __u16,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ff_effect {
+    pub type_: __u16,
+    pub id: __s16,
+    pub direction: __u16,
+    pub trigger: ff_trigger,
+    pub replay: ff_replay,
+    pub u: ff_effect__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union ff_effect__bindgen_ty_1 {
+    pub constant: ff_constant_effect,
+    pub ramp: ff_ramp_effect,
+    pub periodic: ff_periodic_effect,
+    pub condition: [ff_condition_effect; 2usize],
+    pub rumble: ff_rumble_effect,
+}
+impl Default for ff_effect__bindgen_ty_1 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+impl Default for ff_effect {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub const UINPUT_VERSION: u32 = 5;
+pub const UINPUT_MAX_NAME_SIZE: u32 = 80;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct uinput_ff_upload {
+    pub request_id: __u32,
+    pub retval: __s32,
+    pub effect: ff_effect,
+    pub old: ff_effect,
+}
+impl Default for uinput_ff_upload {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct uinput_ff_erase {
+    pub request_id: __u32,
+    pub retval: __s32,
+    pub effect_id: __u32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct uinput_setup {
+    pub id: input_id,
+    pub name: [::core::ffi::c_char; 80usize],
+    pub ff_effects_max: __u32,
+}
+impl Default for uinput_setup {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct uinput_abs_setup {
+    pub code: __u16,
+    pub absinfo: input_absinfo,
+}
@@ -0,0 +1,414 @@
+//! Enumeration of the event devices described in sysfs.
+//!
+//! Each event device has a directory at `/sys/class/input/eventN`, where `device` links to the input device:
+//!
+//! ```text
+//! /sys/class/input/event3/device/name
+//! /sys/class/input/event3/device/phys
+//! /sys/class/input/event3/device/uniq
+//! /sys/class/input/event3/device/id/{bustype,vendor,product,version}
+//! /sys/class/input/event3/device/properties
+//! /sys/class/input/event3/device/capabilities/{ev,key,rel,abs,msc,sw,led,snd,ff}
+//! ```
+//!
+//! The capability files use the same bitmap format as the `B:` lines of `/proc/bus/input/devices`. The sysfs root is
+//! configurable, which allows reading a copy of the tree.
+//!
+//! This module requires the `std` feature.
+
+use std::{
+    format, fs, io,
+    path::{Path, PathBuf},
+    string::String,
+    vec::Vec,
+};
+
+use crate::{
+    bitset, AbsSet, BusType, EventTypeSet, ForceFeedbackSet, InputId, KeySet, LedSet, MiscSet,
+    PropertySet, RelSet, SoundSet, SwitchSet,
+};
+
+/// An event device described in sysfs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SysfsDevice {
+    /// The name of the event device, such as `event3`.
+    pub event: String,
+
+    /// The path of the input device directory, such as `/sys/class/input/event3/device`.
+    pub path: PathBuf,
+
+    /// The id of the device.
+    pub id: InputId,
+
+    /// The name of the device.
+    pub name: String,
+
+    /// The physical location of the device.
+    pub phys: String,
+
+    /// The unique identifier of the device.
+    pub uniq: String,
+
+    /// The properties of the device.
+    pub properties: PropertySet,
+
+    /// The event types the device supports.
+    pub event_types: EventTypeSet,
+
+    /// The keys and buttons the device supports.
+    pub key_codes: KeySet,
+
+    /// The relative axes the device supports.
+    pub relative_axes: RelSet,
+
+    /// The absolute axes the device supports.
+    pub absolute_axes: AbsSet,
+
+    /// The miscellaneous events the device supports.
+    pub misc_events: MiscSet,
+
+    /// The switches the device supports.
+    pub switches: SwitchSet,
+
+    /// The LEDs of the device.
+    pub leds: LedSet,
+
+    /// The sounds the device supports.
+    pub sounds: SoundSet,
+
+    /// The force feedback effects the device supports.
+    pub force_feedback: ForceFeedbackSet,
+}
+
+impl SysfsDevice {
+    /// Returns the path of the device node of the event device, such as `/dev/input/event3`.
+    pub fn devnode(&self) -> PathBuf {
+        Path::new("/dev/input").join(&self.event)
+    }
+}
+
+/// Enumerates the event devices described in sysfs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sysfs {
+    root: PathBuf,
+    word_bits: usize,
+}
+
+impl Sysfs {
+    /// Enumerates the devices described in `/sys`.
+    pub fn new() -> Self {
+        Self::with_root("/sys")
+    }
+
+    /// Enumerates the devices described in a sysfs tree mounted or copied at the root.
+    pub fn with_root(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            word_bits: usize::BITS as usize,
+        }
+    }
+
+    /// Sets the number of bits in an `unsigned long` of the kernel which wrote the capability files.
+    ///
+    /// This defaults to the word size of the target, and only needs to be changed to read a tree copied from a
+    /// kernel with another word size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of bits is not 32 or 64.
+    pub fn word_bits(mut self, word_bits: usize) -> Self {
+        assert!(
+            word_bits == 32 || word_bits == 64,
+            "word size must be 32 or 64 bits"
+        );
+
+        self.word_bits = word_bits;
+        self
+    }
+
+    /// The root of the sysfs tree.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Reads all event devices, ordered by the number of the event device.
+    ///
+    /// Devices which are removed while they are read are skipped.
+    pub fn devices(&self) -> io::Result<Vec<SysfsDevice>> {
+        let mut events = Vec::new();
+
+        for entry in fs::read_dir(self.root.join("class/input"))? {
+            let entry = entry?;
+
+            if let Some(number) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.strip_prefix("event"))
+                .and_then(|number| number.parse::<u32>().ok())
+            {
+                events.push(number);
+            }
+        }
+
+        events.sort_unstable();
+
+        let mut devices = Vec::with_capacity(events.len());
+
+        for number in events {
+            match self.device(&format!("event{}", number)) {
+                Ok(device) => devices.push(device),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(devices)
+    }
+
+    /// Reads the event device with the name, such as `event3`.
+    pub fn device(&self, event: &str) -> io::Result<SysfsDevice> {
+        let path = self.root.join("class/input").join(event).join("device");
+
+        let id = InputId {
+            bustype: BusType::new(read_hex(&path.join("id/bustype"))?),
+            vendor: read_hex(&path.join("id/vendor"))?,
+            product: read_hex(&path.join("id/product"))?,
+            version: read_hex(&path.join("id/version"))?,
+        };
+
+        let mut device = SysfsDevice {
+            event: event.into(),
+            id,
+            name: read_string(&path.join("name"))?,
+            phys: read_string(&path.join("phys"))?,
+            uniq: read_string(&path.join("uniq"))?,
+            properties: PropertySet::new(),
+            event_types: EventTypeSet::new(),
+            key_codes: KeySet::new(),
+            relative_axes: RelSet::new(),
+            absolute_axes: AbsSet::new(),
+            misc_events: MiscSet::new(),
+            switches: SwitchSet::new(),
+            leds: LedSet::new(),
+            sounds: SoundSet::new(),
+            force_feedback: ForceFeedbackSet::new(),
+            path,
+        };
+
+        let capabilities = device.path.join("capabilities");
+
+        self.read_bitmap(&device.path.join("properties"), device.properties.as_mut())?;
+        self.read_bitmap(&capabilities.join("ev"), device.event_types.as_mut())?;
+        self.read_bitmap(&capabilities.join("key"), device.key_codes.as_mut())?;
+        self.read_bitmap(&capabilities.join("rel"), device.relative_axes.as_mut())?;
+        self.read_bitmap(&capabilities.join("abs"), device.absolute_axes.as_mut())?;
+        self.read_bitmap(&capabilities.join("msc"), device.misc_events.as_mut())?;
+        self.read_bitmap(&capabilities.join("sw"), device.switches.as_mut())?;
+        self.read_bitmap(&capabilities.join("led"), device.leds.as_mut())?;
+        self.read_bitmap(&capabilities.join("snd"), device.sounds.as_mut())?;
+        self.read_bitmap(&capabilities.join("ff"), device.force_feedback.as_mut())?;
+
+        Ok(device)
+    }
+
+    fn read_bitmap(&self, path: &Path, bytes: &mut [u8]) -> io::Result<()> {
+        let words = fs::read_to_string(path)?;
+
+        bitset::decode_hex_words(&words, self.word_bits, bytes)
+            .ok_or_else(|| invalid_data(path, "invalid bitmap"))
+    }
+}
+
+impl Default for Sysfs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn read_string(path: &Path) -> io::Result<String> {
+    let mut value = fs::read_to_string(path)?;
+
+    if value.ends_with('\n') {
+        value.pop();
+    }
+
+    Ok(value)
+}
+
+fn read_hex(path: &Path) -> io::Result<u16> {
+    let value = fs::read_to_string(path)?;
+
+    u16::from_str_radix(value.trim_end(), 16).map_err(|_| invalid_data(path, "invalid id"))
+}
+
+fn invalid_data(path: &Path, reason: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("{}: {}", path.display(), reason),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::{format, fs, path::PathBuf, process, vec::Vec};
+
+    use crate::{
+        sysfs::Sysfs, AbsoluteAxis, BusType, Button, EventType, InputQuirk, Key, KeyCode, Led,
+    };
+
+    /// A fake sysfs tree in a temporary directory, removed when dropped.
+    struct FakeTree(PathBuf);
+
+    impl FakeTree {
+        fn new(name: &str) -> Self {
+            let root =
+                std::env::temp_dir().join(format!("input_event_codes-{}-{}", name, process::id()));
+            let _ = fs::remove_dir_all(&root);
+            fs::create_dir_all(root.join("class/input")).unwrap();
+            Self(root)
+        }
+
+        fn add(&self, event: &str, name: &str, id: [&str; 4], capabilities: &[(&str, &str)]) {
+            let device = self.0.join("class/input").join(event).join("device");
+            fs::create_dir_all(device.join("id")).unwrap();
+            fs::create_dir_all(device.join("capabilities")).unwrap();
+
+            fs::write(device.join("name"), format!("{}\n", name)).unwrap();
+            fs::write(device.join("phys"), "usb-0000:00:14.0-1/input0\n").unwrap();
+            fs::write(device.join("uniq"), "\n").unwrap();
+
+            for (file, value) in ["bustype", "vendor", "product", "version"].iter().zip(id) {
+                fs::write(device.join("id").join(file), format!("{}\n", value)).unwrap();
+            }
+
+            fs::write(device.join("properties"), "0\n").unwrap();
+
+            for file in ["ev", "key", "rel", "abs", "msc", "sw", "led", "snd", "ff"] {
+                let value = capabilities
+                    .iter()
+                    .find(|(name, _)| *name == file)
+                    .map_or("0", |(_, value)| value);
+
+                fs::write(
+                    device.join("capabilities").join(file),
+                    format!("{}\n", value),
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    impl Drop for FakeTree {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn enumerate() {
+        let tree = FakeTree::new("enumerate");
+
+        tree.add(
+            "event10",
+            "Logitech USB Receiver",
+            ["0003", "046d", "c52b", "0111"],
+            &[
+                ("ev", "17"),
+                ("key", "ffff0000 0 0 0 0"),
+                ("rel", "1943"),
+                ("msc", "10"),
+            ],
+        );
+        tree.add(
+            "event2",
+            "AT Translated Set 2 keyboard",
+            ["0011", "0001", "0001", "ab83"],
+            &[
+                ("ev", "120013"),
+                (
+                    "key",
+                    "402000000 3803078f800d001 feffffdfffefffff fffffffffffffffe",
+                ),
+                ("msc", "10"),
+                ("led", "7"),
+            ],
+        );
+        fs::create_dir_all(tree.0.join("class/input/input3")).unwrap();
+        fs::create_dir_all(tree.0.join("class/input/mouse0")).unwrap();
+
+        let devices = Sysfs::with_root(&tree.0).word_bits(64).devices().unwrap();
+
+        assert_eq!(
+            devices
+                .iter()
+                .map(|device| device.event.as_str())
+                .collect::<Vec<_>>(),
+            ["event2", "event10"]
+        );
+
+        let keyboard = &devices[0];
+        assert_eq!(keyboard.name, "AT Translated Set 2 keyboard");
+        assert_eq!(keyboard.phys, "usb-0000:00:14.0-1/input0");
+        assert_eq!(keyboard.uniq, "");
+        assert_eq!(keyboard.id.bustype, BusType::I8042);
+        assert_eq!(keyboard.id.version, 0xab83);
+        assert_eq!(keyboard.devnode(), PathBuf::from("/dev/input/event2"));
+        assert!(keyboard.event_types.contains(EventType::REP));
+        assert!(keyboard.key_codes.contains(KeyCode::Key(Key::A)));
+        assert!(keyboard.properties.is_empty());
+        assert_eq!(
+            keyboard.leds.iter().collect::<Vec<_>>(),
+            [Led::NUML, Led::CAPSL, Led::SCROLLL]
+        );
+
+        let mouse = &devices[1];
+        assert_eq!(mouse.id.bustype, BusType::USB);
+        assert_eq!(mouse.id.product, 0xc52b);
+        assert_eq!(
+            mouse.key_codes.iter().next(),
+            Some(KeyCode::Button(Button::LEFT))
+        );
+        assert_eq!(mouse.relative_axes.len(), 6);
+        assert!(mouse.absolute_axes.is_empty());
+    }
+
+    #[test]
+    fn single_device() {
+        let tree = FakeTree::new("single-device");
+
+        tree.add(
+            "event5",
+            "Synaptics TM3096-001",
+            ["0018", "06cb", "cd50", "0100"],
+            &[("ev", "b"), ("abs", "2e0800000000003")],
+        );
+        fs::write(tree.0.join("class/input/event5/device/properties"), "5\n").unwrap();
+
+        let device = Sysfs::with_root(&tree.0)
+            .word_bits(64)
+            .device("event5")
+            .unwrap();
+
+        assert_eq!(
+            device.properties.iter().collect::<Vec<_>>(),
+            [InputQuirk::PROP_POINTER, InputQuirk::PROP_BUTTONPAD]
+        );
+        assert!(device.absolute_axes.contains(AbsoluteAxis::MT_SLOT));
+        assert!(device.absolute_axes.contains(AbsoluteAxis::MT_TRACKING_ID));
+        assert_eq!(
+            device.path,
+            tree.0.join("class/input/event5/device").as_path()
+        );
+
+        // Missing devices and malformed files are errors.
+        assert!(Sysfs::with_root(&tree.0).device("event6").is_err());
+
+        fs::write(
+            tree.0.join("class/input/event5/device/capabilities/key"),
+            "xyz\n",
+        )
+        .unwrap();
+        let error = Sysfs::with_root(&tree.0).device("event5").unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
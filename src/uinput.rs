@@ -0,0 +1,504 @@
+//! Structures and request codes of the uinput ioctls, used to create virtual input devices.
+//!
+//! The request codes are generated from `uinput.h`. A virtual device is described using a
+//! [`VirtualDeviceBuilder`], which produces the sequence of ioctls which create the device. The sequence does not
+//! depend on the kernel, so it may be inspected without creating a device.
+//!
+//! With the `std` feature enabled, this module also provides safe wrappers which issue the ioctls on a file
+//! descriptor of `/dev/uinput`.
+
+mod generated;
+#[cfg(feature = "std")]
+mod wrappers;
+
+pub use self::generated::*;
+#[cfg(feature = "std")]
+pub use self::wrappers::*;
+
+use core::{ffi::CStr, fmt};
+
+use crate::{
+    sys, AbsoluteAxis, Button, EventType, ForceFeedback, InputAbsInfo, InputId, InputQuirk, Key,
+    KeyCode, Led, MiscEvent, RelativeAxis, Sound, SwitchEvent,
+};
+
+/// The parameters of a virtual device, matching `struct uinput_setup` of the kernel.
+#[doc(alias = "uinput_setup")]
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UinputSetup {
+    /// The id of the device.
+    pub id: InputId,
+    name: [u8; 80],
+
+    /// The largest number of force feedback effects the device may have uploaded at once.
+    pub ff_effects_max: u32,
+}
+
+impl UinputSetup {
+    /// The size of the name of a device, including the nul terminator.
+    #[doc(alias = "UINPUT_MAX_NAME_SIZE")]
+    pub const MAX_NAME_SIZE: usize = 80;
+
+    /// Creates the parameters of a device with the id and name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name does not fit in [`MAX_NAME_SIZE`](Self::MAX_NAME_SIZE) bytes with the nul
+    /// terminator.
+    pub fn new(id: InputId, name: &str) -> Self {
+        assert!(
+            name.len() < Self::MAX_NAME_SIZE,
+            "name is longer than {} bytes",
+            Self::MAX_NAME_SIZE - 1
+        );
+
+        let mut setup = Self {
+            id,
+            name: [0; 80],
+            ff_effects_max: 0,
+        };
+        setup.name[..name.len()].copy_from_slice(name.as_bytes());
+        setup
+    }
+
+    /// Returns the name of the device, without the nul terminator.
+    pub fn name(&self) -> &[u8] {
+        let len = self
+            .name
+            .iter()
+            .position(|&byte| byte == 0)
+            .unwrap_or(Self::MAX_NAME_SIZE);
+
+        &self.name[..len]
+    }
+}
+
+impl fmt::Debug for UinputSetup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UinputSetup")
+            .field("id", &self.id)
+            .field("name", &self.name())
+            .field("ff_effects_max", &self.ff_effects_max)
+            .finish()
+    }
+}
+
+/// The state and limits of an absolute axis of a virtual device, matching `struct uinput_abs_setup` of the
+/// kernel.
+#[doc(alias = "uinput_abs_setup")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UinputAbsSetup {
+    code: u16,
+
+    /// The state and limits of the axis.
+    pub absinfo: InputAbsInfo,
+}
+
+impl UinputAbsSetup {
+    /// Creates the setup of an axis.
+    pub const fn new(axis: AbsoluteAxis, absinfo: InputAbsInfo) -> Self {
+        Self {
+            code: axis.into_inner() as u16,
+            absinfo,
+        }
+    }
+
+    /// Returns the axis being set up.
+    pub const fn axis(&self) -> AbsoluteAxis {
+        AbsoluteAxis::new(self.code as u32)
+    }
+}
+
+// The typed structures are passed to the kernel in place of the bindings.
+const _: () =
+    assert!(core::mem::size_of::<UinputSetup>() == core::mem::size_of::<sys::uinput_setup>());
+const _: () = assert!(
+    core::mem::size_of::<UinputAbsSetup>() == core::mem::size_of::<sys::uinput_abs_setup>()
+);
+const _: () = assert!(UinputSetup::MAX_NAME_SIZE == sys::UINPUT_MAX_NAME_SIZE as usize);
+
+/// An ioctl used to create a virtual device, with its argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UinputCall<'a> {
+    /// [`UI_SET_EVBIT`]
+    SetEventBit(EventType),
+
+    /// [`UI_SET_KEYBIT`]
+    SetKeyBit(KeyCode),
+
+    /// [`UI_SET_RELBIT`]
+    SetRelBit(RelativeAxis),
+
+    /// [`UI_SET_ABSBIT`]
+    SetAbsBit(AbsoluteAxis),
+
+    /// [`UI_SET_MSCBIT`]
+    SetMscBit(MiscEvent),
+
+    /// [`UI_SET_SWBIT`]
+    SetSwBit(SwitchEvent),
+
+    /// [`UI_SET_LEDBIT`]
+    SetLedBit(Led),
+
+    /// [`UI_SET_SNDBIT`]
+    SetSndBit(Sound),
+
+    /// [`UI_SET_FFBIT`]
+    SetFfBit(ForceFeedback),
+
+    /// [`UI_SET_PROPBIT`]
+    SetPropBit(InputQuirk),
+
+    /// [`UI_SET_PHYS`]
+    SetPhys(&'a CStr),
+
+    /// [`UI_ABS_SETUP`]
+    AbsSetup(UinputAbsSetup),
+
+    /// [`UI_DEV_SETUP`]
+    DevSetup(UinputSetup),
+
+    /// [`UI_DEV_CREATE`]
+    DevCreate,
+}
+
+impl UinputCall<'_> {
+    /// Returns the request code of the ioctl.
+    pub const fn request(&self) -> u32 {
+        match self {
+            Self::SetEventBit(_) => UI_SET_EVBIT,
+            Self::SetKeyBit(_) => UI_SET_KEYBIT,
+            Self::SetRelBit(_) => UI_SET_RELBIT,
+            Self::SetAbsBit(_) => UI_SET_ABSBIT,
+            Self::SetMscBit(_) => UI_SET_MSCBIT,
+            Self::SetSwBit(_) => UI_SET_SWBIT,
+            Self::SetLedBit(_) => UI_SET_LEDBIT,
+            Self::SetSndBit(_) => UI_SET_SNDBIT,
+            Self::SetFfBit(_) => UI_SET_FFBIT,
+            Self::SetPropBit(_) => UI_SET_PROPBIT,
+            Self::SetPhys(_) => UI_SET_PHYS,
+            Self::AbsSetup(_) => UI_ABS_SETUP,
+            Self::DevSetup(_) => UI_DEV_SETUP,
+            Self::DevCreate => UI_DEV_CREATE,
+        }
+    }
+}
+
+/// A description of a virtual device and its capabilities.
+///
+/// The capabilities are borrowed, so the builder does not allocate. [`calls`](Self::calls) returns the ioctls
+/// which create the device.
+#[derive(Debug, Clone, Copy)]
+pub struct VirtualDeviceBuilder<'a> {
+    setup: UinputSetup,
+    phys: Option<&'a CStr>,
+    properties: &'a [InputQuirk],
+    keys: &'a [Key],
+    buttons: &'a [Button],
+    relative_axes: &'a [RelativeAxis],
+    absolute_axes: &'a [(AbsoluteAxis, InputAbsInfo)],
+    misc_events: &'a [MiscEvent],
+    switches: &'a [SwitchEvent],
+    leds: &'a [Led],
+    sounds: &'a [Sound],
+    force_feedback: &'a [ForceFeedback],
+    auto_repeat: bool,
+}
+
+impl<'a> VirtualDeviceBuilder<'a> {
+    /// Creates a description of a device with the id and name and no capabilities.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name does not fit in [`UinputSetup::MAX_NAME_SIZE`] bytes with the nul terminator.
+    pub fn new(id: InputId, name: &str) -> Self {
+        Self {
+            setup: UinputSetup::new(id, name),
+            phys: None,
+            properties: &[],
+            keys: &[],
+            buttons: &[],
+            relative_axes: &[],
+            absolute_axes: &[],
+            misc_events: &[],
+            switches: &[],
+            leds: &[],
+            sounds: &[],
+            force_feedback: &[],
+            auto_repeat: false,
+        }
+    }
+
+    /// Sets the physical location of the device.
+    pub fn phys(mut self, phys: &'a CStr) -> Self {
+        self.phys = Some(phys);
+        self
+    }
+
+    /// Sets the properties of the device.
+    pub fn properties(mut self, properties: &'a [InputQuirk]) -> Self {
+        self.properties = properties;
+        self
+    }
+
+    /// Sets the keys the device reports.
+    pub fn keys(mut self, keys: &'a [Key]) -> Self {
+        self.keys = keys;
+        self
+    }
+
+    /// Sets the buttons the device reports.
+    pub fn buttons(mut self, buttons: &'a [Button]) -> Self {
+        self.buttons = buttons;
+        self
+    }
+
+    /// Sets the relative axes the device reports.
+    pub fn relative_axes(mut self, axes: &'a [RelativeAxis]) -> Self {
+        self.relative_axes = axes;
+        self
+    }
+
+    /// Sets the absolute axes the device reports, with the state and limits of each axis.
+    pub fn absolute_axes(mut self, axes: &'a [(AbsoluteAxis, InputAbsInfo)]) -> Self {
+        self.absolute_axes = axes;
+        self
+    }
+
+    /// Sets the miscellaneous events the device reports.
+    pub fn misc_events(mut self, events: &'a [MiscEvent]) -> Self {
+        self.misc_events = events;
+        self
+    }
+
+    /// Sets the switches the device reports.
+    pub fn switches(mut self, switches: &'a [SwitchEvent]) -> Self {
+        self.switches = switches;
+        self
+    }
+
+    /// Sets the LEDs of the device.
+    pub fn leds(mut self, leds: &'a [Led]) -> Self {
+        self.leds = leds;
+        self
+    }
+
+    /// Sets the sounds the device may play.
+    pub fn sounds(mut self, sounds: &'a [Sound]) -> Self {
+        self.sounds = sounds;
+        self
+    }
+
+    /// Sets the force feedback effects the device supports, and the number of effects which may be uploaded at
+    /// once.
+    pub fn force_feedback(mut self, effects: &'a [ForceFeedback], max_effects: u32) -> Self {
+        self.force_feedback = effects;
+        self.setup.ff_effects_max = max_effects;
+        self
+    }
+
+    /// Sets whether the kernel repeats the keys of the device while they are held.
+    pub fn auto_repeat(mut self, auto_repeat: bool) -> Self {
+        self.auto_repeat = auto_repeat;
+        self
+    }
+
+    /// Returns the parameters passed to [`UI_DEV_SETUP`].
+    pub fn setup(&self) -> &UinputSetup {
+        &self.setup
+    }
+
+    /// Returns the ioctls which create the device, in the order they must be issued.
+    ///
+    /// The properties and capabilities are set first, with the event type of each kind of capability enabled
+    /// before its codes. The device is then set up with [`UI_DEV_SETUP`] and created with [`UI_DEV_CREATE`].
+    pub fn calls(&self) -> impl Iterator<Item = UinputCall<'a>> + 'a {
+        fn bits<'a, T: Copy>(
+            event_type: EventType,
+            codes: &'a [T],
+            call: fn(T) -> UinputCall<'a>,
+        ) -> impl Iterator<Item = UinputCall<'a>> + 'a {
+            enable(event_type, !codes.is_empty()).chain(codes.iter().map(move |&code| call(code)))
+        }
+
+        fn enable<'a>(
+            event_type: EventType,
+            enabled: bool,
+        ) -> impl Iterator<Item = UinputCall<'a>> {
+            enabled
+                .then_some(UinputCall::SetEventBit(event_type))
+                .into_iter()
+        }
+
+        let has_keys = !self.keys.is_empty() || !self.buttons.is_empty();
+        let keys = self
+            .keys
+            .iter()
+            .map(|&key| UinputCall::SetKeyBit(key.into()));
+        let buttons = self
+            .buttons
+            .iter()
+            .map(|&button| UinputCall::SetKeyBit(button.into()));
+
+        let absolute_axes = self.absolute_axes.iter().flat_map(|&(axis, absinfo)| {
+            [
+                UinputCall::SetAbsBit(axis),
+                UinputCall::AbsSetup(UinputAbsSetup::new(axis, absinfo)),
+            ]
+        });
+
+        self.properties
+            .iter()
+            .map(|&property| UinputCall::SetPropBit(property))
+            .chain(enable(EventType::KEY, has_keys))
+            .chain(keys)
+            .chain(buttons)
+            .chain(bits(
+                EventType::REL,
+                self.relative_axes,
+                UinputCall::SetRelBit,
+            ))
+            .chain(enable(EventType::ABS, !self.absolute_axes.is_empty()))
+            .chain(absolute_axes)
+            .chain(bits(
+                EventType::MSC,
+                self.misc_events,
+                UinputCall::SetMscBit,
+            ))
+            .chain(bits(EventType::SW, self.switches, UinputCall::SetSwBit))
+            .chain(bits(EventType::LED, self.leds, UinputCall::SetLedBit))
+            .chain(bits(EventType::SND, self.sounds, UinputCall::SetSndBit))
+            .chain(bits(
+                EventType::FF,
+                self.force_feedback,
+                UinputCall::SetFfBit,
+            ))
+            .chain(enable(EventType::REP, self.auto_repeat))
+            .chain(self.phys.map(UinputCall::SetPhys))
+            .chain([UinputCall::DevSetup(self.setup), UinputCall::DevCreate])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::{
+        ffi::CStr,
+        mem::{offset_of, size_of},
+    };
+    use std::vec::Vec;
+
+    use crate::{
+        sys,
+        uinput::{self, UinputAbsSetup, UinputCall, UinputSetup, VirtualDeviceBuilder},
+        AbsoluteAxis, BusType, Button, EventType, InputAbsInfo, InputId, Key, KeyCode,
+    };
+
+    #[test]
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    fn request_codes() {
+        assert_eq!(uinput::UI_DEV_CREATE, 0x5501);
+        assert_eq!(uinput::UI_DEV_DESTROY, 0x5502);
+        assert_eq!(uinput::UI_DEV_SETUP, 0x405c_5503);
+        assert_eq!(uinput::UI_ABS_SETUP, 0x401c_5504);
+        assert_eq!(uinput::UI_SET_EVBIT, 0x4004_5564);
+        assert_eq!(uinput::UI_SET_PHYS, 0x4008_556c);
+        assert_eq!(uinput::UI_BEGIN_FF_UPLOAD, 0xc068_55c8);
+        assert_eq!(uinput::ui_get_sysname(64), 0x8040_552c);
+        assert_eq!(uinput::UI_GET_VERSION, 0x8004_552d);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn large_buffer() {
+        use std::{fs::File, os::unix::io::AsFd, vec};
+
+        // A buffer larger than the request code can describe is only partially filled, instead of panicking.
+        let file = File::open("/dev/null").unwrap();
+        let mut buffer = vec![0; 1 << 16];
+        assert!(uinput::get_sysname(file.as_fd(), &mut buffer).is_err());
+    }
+
+    #[test]
+    fn layout() {
+        assert_eq!(
+            offset_of!(UinputSetup, name),
+            offset_of!(sys::uinput_setup, name)
+        );
+        assert_eq!(
+            offset_of!(UinputSetup, ff_effects_max),
+            offset_of!(sys::uinput_setup, ff_effects_max)
+        );
+        assert_eq!(
+            offset_of!(UinputAbsSetup, absinfo),
+            offset_of!(sys::uinput_abs_setup, absinfo)
+        );
+        assert_eq!(size_of::<UinputAbsSetup>(), 28);
+    }
+
+    #[test]
+    fn setup_name() {
+        let setup = UinputSetup::new(InputId::default(), "Virtual Keyboard");
+        assert_eq!(setup.name(), b"Virtual Keyboard");
+    }
+
+    #[test]
+    fn calls() {
+        let id = InputId {
+            bustype: BusType::VIRTUAL,
+            vendor: 0x1234,
+            product: 0x5678,
+            version: 1,
+        };
+        let absinfo = InputAbsInfo {
+            minimum: -32768,
+            maximum: 32767,
+            ..InputAbsInfo::default()
+        };
+        let phys = CStr::from_bytes_with_nul(b"virtual/input0\0").unwrap();
+        let absolute_axes = [(AbsoluteAxis::X, absinfo)];
+
+        let device = VirtualDeviceBuilder::new(id, "Virtual Gamepad")
+            .keys(&[Key::A])
+            .buttons(&[Button::SOUTH, Button::EAST])
+            .absolute_axes(&absolute_axes)
+            .phys(phys);
+
+        let calls = device.calls().collect::<Vec<_>>();
+        let setup = *device.setup();
+
+        assert_eq!(
+            calls,
+            [
+                UinputCall::SetEventBit(EventType::KEY),
+                UinputCall::SetKeyBit(KeyCode::Key(Key::A)),
+                UinputCall::SetKeyBit(KeyCode::Button(Button::SOUTH)),
+                UinputCall::SetKeyBit(KeyCode::Button(Button::EAST)),
+                UinputCall::SetEventBit(EventType::ABS),
+                UinputCall::SetAbsBit(AbsoluteAxis::X),
+                UinputCall::AbsSetup(UinputAbsSetup::new(AbsoluteAxis::X, absinfo)),
+                UinputCall::SetPhys(phys),
+                UinputCall::DevSetup(setup),
+                UinputCall::DevCreate,
+            ]
+        );
+
+        assert_eq!(setup.id, id);
+        assert_eq!(setup.name(), b"Virtual Gamepad");
+        assert_eq!(calls[0].request(), uinput::UI_SET_EVBIT);
+        assert_eq!(calls[9].request(), uinput::UI_DEV_CREATE);
+    }
+
+    #[test]
+    fn empty_device() {
+        let device = VirtualDeviceBuilder::new(InputId::default(), "Empty");
+        let calls = device.calls().collect::<Vec<_>>();
+
+        assert_eq!(
+            calls,
+            [UinputCall::DevSetup(*device.setup()), UinputCall::DevCreate]
+        );
+    }
+}
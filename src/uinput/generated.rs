@@ -0,0 +1,123 @@
+// This file is generated.
+//
+// Do NOT edit this file, instead modify the generator.
+
+pub const UI_DEV_CREATE: u32 = crate::ioctl::ioc(crate::ioctl::NONE, 85u8, 1u32, 0);
+pub const UI_DEV_DESTROY: u32 = crate::ioctl::ioc(crate::ioctl::NONE, 85u8, 2u32, 0);
+pub const UI_DEV_SETUP: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    85u8,
+    3u32,
+    ::core::mem::size_of::<crate::uinput::UinputSetup>(),
+);
+pub const UI_ABS_SETUP: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    85u8,
+    4u32,
+    ::core::mem::size_of::<crate::uinput::UinputAbsSetup>(),
+);
+pub const UI_SET_EVBIT: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    85u8,
+    100u32,
+    ::core::mem::size_of::<::core::ffi::c_int>(),
+);
+pub const UI_SET_KEYBIT: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    85u8,
+    101u32,
+    ::core::mem::size_of::<::core::ffi::c_int>(),
+);
+pub const UI_SET_RELBIT: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    85u8,
+    102u32,
+    ::core::mem::size_of::<::core::ffi::c_int>(),
+);
+pub const UI_SET_ABSBIT: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    85u8,
+    103u32,
+    ::core::mem::size_of::<::core::ffi::c_int>(),
+);
+pub const UI_SET_MSCBIT: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    85u8,
+    104u32,
+    ::core::mem::size_of::<::core::ffi::c_int>(),
+);
+pub const UI_SET_LEDBIT: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    85u8,
+    105u32,
+    ::core::mem::size_of::<::core::ffi::c_int>(),
+);
+pub const UI_SET_SNDBIT: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    85u8,
+    106u32,
+    ::core::mem::size_of::<::core::ffi::c_int>(),
+);
+pub const UI_SET_FFBIT: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    85u8,
+    107u32,
+    ::core::mem::size_of::<::core::ffi::c_int>(),
+);
+pub const UI_SET_PHYS: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    85u8,
+    108u32,
+    ::core::mem::size_of::<*const ::core::ffi::c_char>(),
+);
+pub const UI_SET_SWBIT: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    85u8,
+    109u32,
+    ::core::mem::size_of::<::core::ffi::c_int>(),
+);
+pub const UI_SET_PROPBIT: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    85u8,
+    110u32,
+    ::core::mem::size_of::<::core::ffi::c_int>(),
+);
+pub const UI_BEGIN_FF_UPLOAD: u32 = crate::ioctl::ioc(
+    crate::ioctl::READ | crate::ioctl::WRITE,
+    85u8,
+    200u32,
+    ::core::mem::size_of::<crate::sys::uinput_ff_upload>(),
+);
+pub const UI_END_FF_UPLOAD: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    85u8,
+    201u32,
+    ::core::mem::size_of::<crate::sys::uinput_ff_upload>(),
+);
+pub const UI_BEGIN_FF_ERASE: u32 = crate::ioctl::ioc(
+    crate::ioctl::READ | crate::ioctl::WRITE,
+    85u8,
+    202u32,
+    ::core::mem::size_of::<crate::sys::uinput_ff_erase>(),
+);
+pub const UI_END_FF_ERASE: u32 = crate::ioctl::ioc(
+    crate::ioctl::WRITE,
+    85u8,
+    203u32,
+    ::core::mem::size_of::<crate::sys::uinput_ff_erase>(),
+);
+#[doc = r""]
+#[doc = r" # Panics"]
+#[doc = r""]
+#[doc = r" Panics if the length is larger than [`MAX_SIZE`](crate::ioctl::MAX_SIZE), the largest size"]
+#[doc = r" the request code can hold."]
+#[doc(alias = "UI_GET_SYSNAME")]
+pub const fn ui_get_sysname(len: usize) -> u32 {
+    crate::ioctl::ioc(crate::ioctl::READ, 85u8, 44u32, len)
+}
+pub const UI_GET_VERSION: u32 = crate::ioctl::ioc(
+    crate::ioctl::READ,
+    85u8,
+    45u32,
+    ::core::mem::size_of::<::core::ffi::c_uint>(),
+);
@@ -0,0 +1,67 @@
+use std::{
+    io,
+    os::{
+        raw::{c_int, c_uint},
+        unix::io::BorrowedFd,
+    },
+};
+
+use crate::{
+    ioctl::wrappers::{buffer_len, get_buffer, ioctl, ioctl_read},
+    uinput::{self, UinputAbsSetup, UinputCall, UinputSetup, VirtualDeviceBuilder},
+};
+
+/// Issues an ioctl used to create a virtual device.
+pub fn call(fd: BorrowedFd<'_>, call: &UinputCall<'_>) -> io::Result<()> {
+    let request = call.request();
+
+    let result = unsafe {
+        match *call {
+            UinputCall::SetEventBit(code) => ioctl(fd, request, code.into_inner() as c_int),
+            UinputCall::SetKeyBit(code) => ioctl(fd, request, code.into_inner() as c_int),
+            UinputCall::SetRelBit(code) => ioctl(fd, request, code.into_inner() as c_int),
+            UinputCall::SetAbsBit(code) => ioctl(fd, request, code.into_inner() as c_int),
+            UinputCall::SetMscBit(code) => ioctl(fd, request, code.into_inner() as c_int),
+            UinputCall::SetSwBit(code) => ioctl(fd, request, code.into_inner() as c_int),
+            UinputCall::SetLedBit(code) => ioctl(fd, request, code.into_inner() as c_int),
+            UinputCall::SetSndBit(code) => ioctl(fd, request, code.into_inner() as c_int),
+            UinputCall::SetFfBit(code) => ioctl(fd, request, code.into_inner() as c_int),
+            UinputCall::SetPropBit(code) => ioctl(fd, request, code.into_inner() as c_int),
+            UinputCall::SetPhys(phys) => ioctl(fd, request, phys.as_ptr()),
+            UinputCall::AbsSetup(ref setup) => ioctl(fd, request, setup as *const UinputAbsSetup),
+            UinputCall::DevSetup(ref setup) => ioctl(fd, request, setup as *const UinputSetup),
+            UinputCall::DevCreate => ioctl(fd, request, 0 as c_int),
+        }
+    };
+
+    result.map(drop)
+}
+
+/// Creates the virtual device described by the builder.
+///
+/// The file descriptor must be a newly opened file descriptor of `/dev/uinput`. If an ioctl fails, the device
+/// may have been partially set up.
+pub fn create_device(fd: BorrowedFd<'_>, device: &VirtualDeviceBuilder<'_>) -> io::Result<()> {
+    device
+        .calls()
+        .try_for_each(|device_call| call(fd, &device_call))
+}
+
+/// Destroys the virtual device created using the file descriptor.
+#[doc(alias = "UI_DEV_DESTROY")]
+pub fn destroy_device(fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe { ioctl(fd, uinput::UI_DEV_DESTROY, 0 as c_int) }.map(drop)
+}
+
+/// Reads the sysfs name of the virtual device into the buffer, such as `input12`, returning the number of bytes
+/// written.
+#[doc(alias = "UI_GET_SYSNAME")]
+pub fn get_sysname(fd: BorrowedFd<'_>, buffer: &mut [u8]) -> io::Result<usize> {
+    get_buffer(fd, uinput::ui_get_sysname(buffer_len(buffer)), buffer)
+}
+
+/// Returns the version of the uinput protocol implemented by the kernel.
+#[doc(alias = "UI_GET_VERSION")]
+pub fn get_version(fd: BorrowedFd<'_>) -> io::Result<c_uint> {
+    unsafe { ioctl_read(fd, uinput::UI_GET_VERSION) }
+}
@@ -0,0 +1,405 @@
+//! Accumulation of scroll wheel events.
+//!
+//! Devices with a high resolution wheel send `REL_WHEEL_HI_RES` and `REL_HWHEEL_HI_RES` events in fractions of
+//! 120 per detent, and also send `REL_WHEEL` and `REL_HWHEEL` events once the movement adds up to a whole detent.
+//! Other devices only send the latter. [`WheelAccumulator`] takes either stream, ignoring the low resolution
+//! events of a high resolution wheel, and reports the movement in 120ths of a detent, in detents and in clicks.
+//!
+//! Like libinput, the accumulator waits for the wheel to move half a detent before it reports any movement, and
+//! starts waiting again when the wheel changes direction or stops for a while. This hides the jitter of a wheel
+//! resting between two detents.
+
+use crate::{EventCode, InputEvent, RelSet, RelativeAxis};
+
+/// The movement of a wheel in a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct WheelScroll {
+    /// The movement in 120ths of a detent, positive when scrolling up or right.
+    pub v120: i32,
+
+    /// The number of whole detents the movement completed, for consumers which only scroll by detents.
+    pub clicks: i32,
+}
+
+impl WheelScroll {
+    /// Returns whether the wheel did not move.
+    pub const fn is_zero(&self) -> bool {
+        self.v120 == 0 && self.clicks == 0
+    }
+
+    /// The movement in detents, for smooth scrolling.
+    pub fn detents(&self) -> f64 {
+        f64::from(self.v120) / 120.0
+    }
+
+    /// The movement in degrees of rotation, for a wheel which rotates by the angle in each detent.
+    ///
+    /// Most wheels rotate by 15 degrees per detent.
+    pub fn degrees(&self, angle_per_detent: f64) -> f64 {
+        self.detents() * angle_per_detent
+    }
+}
+
+/// The movement of the wheels of a device in a frame, returned by [`WheelAccumulator::push`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct WheelFrame {
+    /// The movement of the vertical wheel.
+    pub vertical: WheelScroll,
+
+    /// The movement of the horizontal wheel.
+    pub horizontal: WheelScroll,
+}
+
+/// The state of one wheel.
+#[derive(Debug, Clone, Copy)]
+struct Wheel {
+    scrolling: bool,
+    positive: Option<bool>,
+    last_time: Option<i64>,
+    pending: i32,
+    remainder: i32,
+}
+
+impl Wheel {
+    const fn new() -> Self {
+        Self {
+            scrolling: false,
+            positive: None,
+            last_time: None,
+            pending: 0,
+            remainder: 0,
+        }
+    }
+
+    fn scroll(&mut self, v120: i32, now: i64) {
+        if v120 == 0 {
+            return;
+        }
+
+        if self
+            .last_time
+            .is_some_and(|last| now.saturating_sub(last) > WheelAccumulator::TIMEOUT)
+        {
+            *self = Self::new();
+        }
+
+        // A change of direction discards the movement towards the previous direction, so a wheel resting between
+        // two detents does not scroll back and forth.
+        let positive = v120 > 0;
+
+        if self.positive.is_some_and(|previous| previous != positive) {
+            *self = Self::new();
+        }
+
+        self.positive = Some(positive);
+        self.last_time = Some(now);
+        self.pending = self.pending.saturating_add(v120);
+
+        if self.pending.abs() >= WheelAccumulator::THRESHOLD {
+            self.scrolling = true;
+        }
+    }
+
+    fn flush(&mut self) -> WheelScroll {
+        if !self.scrolling {
+            return WheelScroll::default();
+        }
+
+        let v120 = core::mem::take(&mut self.pending);
+        let remainder = self.remainder.saturating_add(v120);
+        self.remainder = remainder % 120;
+
+        WheelScroll {
+            v120,
+            clicks: remainder / 120,
+        }
+    }
+}
+
+/// Accumulates the scroll wheel events of a device into frames.
+///
+/// The vertical and horizontal wheels are accumulated separately, so each wheel has its own threshold and
+/// direction.
+#[derive(Debug, Clone)]
+pub struct WheelAccumulator {
+    hi_res: bool,
+    vertical: Wheel,
+    horizontal: Wheel,
+}
+
+impl WheelAccumulator {
+    /// The movement, in 120ths of a detent, the wheel must move before the movement is reported.
+    pub const THRESHOLD: i32 = 60;
+
+    /// The time, in microseconds, after which a wheel which has not moved must move past the threshold again.
+    pub const TIMEOUT: i64 = 500_000;
+
+    /// Creates an accumulator for a device with a low resolution wheel.
+    ///
+    /// The accumulator switches to the high resolution events once it receives one.
+    pub const fn new() -> Self {
+        Self {
+            hi_res: false,
+            vertical: Wheel::new(),
+            horizontal: Wheel::new(),
+        }
+    }
+
+    /// Creates an accumulator for a device with the relative axes.
+    ///
+    /// The low resolution events are ignored from the start if the device has a high resolution wheel.
+    pub const fn for_axes(axes: &RelSet) -> Self {
+        let mut accumulator = Self::new();
+        accumulator.hi_res =
+            axes.contains(RelativeAxis::WHEEL_HI_RES) || axes.contains(RelativeAxis::HWHEEL_HI_RES);
+        accumulator
+    }
+
+    /// Returns whether the accumulator uses the high resolution events.
+    pub const fn is_hi_res(&self) -> bool {
+        self.hi_res
+    }
+
+    /// Updates the accumulator with an event read from the device.
+    ///
+    /// Returns the movement of the wheels when the event is a `SYN_REPORT` which ends a frame in which a wheel
+    /// moved past the threshold.
+    pub fn push(&mut self, event: &InputEvent) -> Option<WheelFrame> {
+        let now = event
+            .time
+            .sec()
+            .saturating_mul(1_000_000)
+            .saturating_add(event.time.usec());
+
+        match event.event_code() {
+            EventCode::Rel(RelativeAxis::WHEEL_HI_RES) => {
+                self.hi_res = true;
+                self.vertical.scroll(event.value, now);
+            }
+
+            EventCode::Rel(RelativeAxis::HWHEEL_HI_RES) => {
+                self.hi_res = true;
+                self.horizontal.scroll(event.value, now);
+            }
+
+            EventCode::Rel(RelativeAxis::WHEEL) if !self.hi_res => {
+                self.vertical.scroll(event.value.saturating_mul(120), now)
+            }
+
+            EventCode::Rel(RelativeAxis::HWHEEL) if !self.hi_res => {
+                self.horizontal.scroll(event.value.saturating_mul(120), now)
+            }
+
+            _ if event.is_report() => return self.flush(),
+
+            _ => {}
+        }
+
+        None
+    }
+
+    /// Discards the movement which has not been reported, so the wheels must move past the threshold again.
+    pub fn reset(&mut self) {
+        self.vertical = Wheel::new();
+        self.horizontal = Wheel::new();
+    }
+
+    fn flush(&mut self) -> Option<WheelFrame> {
+        let frame = WheelFrame {
+            vertical: self.vertical.flush(),
+            horizontal: self.horizontal.flush(),
+        };
+
+        if frame.vertical.is_zero() && frame.horizontal.is_zero() {
+            return None;
+        }
+
+        Some(frame)
+    }
+}
+
+impl Default for WheelAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::vec::Vec;
+
+    use crate::{
+        EventTime, InputEvent, RelSet, RelativeAxis, SynchronizationEvent, WheelAccumulator,
+        WheelScroll,
+    };
+
+    /// Sends a frame with the events at the time in milliseconds, returning the vertical and horizontal movement.
+    fn frame(
+        accumulator: &mut WheelAccumulator,
+        time: i64,
+        events: &[(RelativeAxis, i32)],
+    ) -> Option<((i32, i32), (i32, i32))> {
+        let time = EventTime::new(time / 1000, time % 1000 * 1000);
+
+        for &(axis, value) in events {
+            assert!(accumulator
+                .push(&InputEvent::new(time, axis.into(), value))
+                .is_none());
+        }
+
+        accumulator
+            .push(&InputEvent::new(
+                time,
+                SynchronizationEvent::REPORT.into(),
+                0,
+            ))
+            .map(|frame| {
+                (
+                    (frame.vertical.v120, frame.vertical.clicks),
+                    (frame.horizontal.v120, frame.horizontal.clicks),
+                )
+            })
+    }
+
+    #[test]
+    fn low_resolution() {
+        let mut accumulator = WheelAccumulator::new();
+
+        assert_eq!(
+            frame(&mut accumulator, 0, &[(RelativeAxis::WHEEL, 1)]),
+            Some(((120, 1), (0, 0)))
+        );
+        assert_eq!(
+            frame(&mut accumulator, 10, &[(RelativeAxis::HWHEEL, -2)]),
+            Some(((0, 0), (-240, -2)))
+        );
+        assert_eq!(frame(&mut accumulator, 20, &[(RelativeAxis::X, 5)]), None);
+        assert!(!accumulator.is_hi_res());
+
+        // A timestamp too large to be counted in microseconds does not overflow.
+        assert_eq!(
+            frame(&mut accumulator, i64::MAX, &[(RelativeAxis::WHEEL, 1)]),
+            Some(((120, 1), (0, 0)))
+        );
+    }
+
+    #[test]
+    fn high_resolution() {
+        let axes = [RelativeAxis::WHEEL, RelativeAxis::WHEEL_HI_RES]
+            .iter()
+            .copied()
+            .collect::<RelSet>();
+        let mut accumulator = WheelAccumulator::for_axes(&axes);
+        let detent = |accumulator: &mut WheelAccumulator, time: i64| {
+            (0..4i64)
+                .filter_map(|step| {
+                    let mut events = Vec::new();
+                    events.push((RelativeAxis::WHEEL_HI_RES, 30));
+
+                    // The kernel sends the low resolution event once the movement adds up to a detent.
+                    if step == 3 {
+                        events.push((RelativeAxis::WHEEL, 1));
+                    }
+
+                    frame(accumulator, time + step * 10, &events)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Nothing is reported until the wheel moves half a detent, and the low resolution event is ignored.
+        assert_eq!(
+            detent(&mut accumulator, 0),
+            [((60, 0), (0, 0)), ((30, 0), (0, 0)), ((30, 1), (0, 0))]
+        );
+        assert_eq!(
+            detent(&mut accumulator, 100),
+            [
+                ((30, 0), (0, 0)),
+                ((30, 0), (0, 0)),
+                ((30, 0), (0, 0)),
+                ((30, 1), (0, 0))
+            ]
+        );
+
+        let scroll = WheelScroll {
+            v120: 30,
+            clicks: 0,
+        };
+        assert_eq!(scroll.detents(), 0.25);
+        assert_eq!(scroll.degrees(15.0), 3.75);
+    }
+
+    #[test]
+    fn direction_change() {
+        let mut accumulator = WheelAccumulator::new();
+        let up = [(RelativeAxis::WHEEL_HI_RES, 40)];
+        let down = [(RelativeAxis::WHEEL_HI_RES, -40)];
+
+        assert_eq!(frame(&mut accumulator, 0, &up), None);
+        assert_eq!(frame(&mut accumulator, 10, &up), Some(((80, 0), (0, 0))));
+
+        // Reversing in the middle of a detent starts accumulating again, and the partial detent is not a click.
+        assert_eq!(frame(&mut accumulator, 20, &down), None);
+        assert_eq!(frame(&mut accumulator, 30, &down), Some(((-80, 0), (0, 0))));
+        assert_eq!(
+            frame(&mut accumulator, 40, &down),
+            Some(((-40, -1), (0, 0)))
+        );
+
+        // After a pause the wheel must move past the threshold again.
+        assert_eq!(frame(&mut accumulator, 1000, &down), None);
+        assert_eq!(
+            frame(&mut accumulator, 1010, &down),
+            Some(((-80, 0), (0, 0)))
+        );
+
+        accumulator.reset();
+        assert_eq!(frame(&mut accumulator, 1020, &down), None);
+    }
+
+    #[test]
+    fn both_wheels() {
+        let mut accumulator = WheelAccumulator::new();
+
+        // Each wheel has its own direction, so moving both wheels in a frame keeps the movement of both.
+        assert_eq!(
+            frame(
+                &mut accumulator,
+                0,
+                &[
+                    (RelativeAxis::WHEEL_HI_RES, 120),
+                    (RelativeAxis::HWHEEL_HI_RES, 120)
+                ]
+            ),
+            Some(((120, 1), (120, 1)))
+        );
+        assert_eq!(
+            frame(
+                &mut accumulator,
+                10,
+                &[
+                    (RelativeAxis::WHEEL_HI_RES, -120),
+                    (RelativeAxis::HWHEEL_HI_RES, 30)
+                ]
+            ),
+            Some(((-120, -1), (30, 0)))
+        );
+
+        // Each wheel has its own threshold.
+        assert_eq!(
+            frame(&mut accumulator, 20, &[(RelativeAxis::WHEEL_HI_RES, 30)]),
+            None
+        );
+        assert_eq!(
+            frame(
+                &mut accumulator,
+                30,
+                &[
+                    (RelativeAxis::WHEEL_HI_RES, 30),
+                    (RelativeAxis::HWHEEL_HI_RES, 30)
+                ]
+            ),
+            Some(((60, 0), (30, 0)))
+        );
+    }
+}